@@ -21,6 +21,18 @@ export function setPoolConfigCommand(program: Command): void {
     .option('-mn, --max-nfts <number>', 'Maximum NFTs allowed in this pool')
     .option('-mt, --max-tokens <number>', 'Maximum tokens allowed in this pool')
     .option('-mv, --max-value <number>', 'Maximum value allowed in this pool')
+    .option('--flexible', 'Let stakers choose their lock period')
+    .option('--min-lock-days <number>', 'Shortest lock for flexible pools')
+    .option('--max-lock-days <number>', 'Longest lock for flexible pools')
+    .option(
+      '--max-boost-bps <number>',
+      'Yield multiplier at the longest lock in bps (10000 = 1x)'
+    )
+    .option('--nft-boost-bps <number>', 'Extra token yield per staked NFT in bps')
+    .option('--max-nft-boost-bps <number>', 'Cap on the NFT holding boost in bps')
+    .option('--unbonding-days <number>', 'Unstake cooldown in days')
+    .option('--min-stake <number>', 'Minimum tokens per position')
+    .option('--max-stake <number>', 'Maximum tokens per position, 0 for none')
     .action(async (options) => {
       try {
         const spinner = ora('Updating pool configuration...').start();
@@ -67,6 +79,15 @@ export function setPoolConfigCommand(program: Command): void {
           maxNftsCap?: number;
           maxTokensCap?: BN;
           maxValueCap?: BN;
+          lockMode?: { fixed: {} } | { flexible: {} };
+          minLockDays?: number;
+          maxLockDays?: number;
+          maxBoostBps?: number;
+          nftBoostBpsPerNft?: number;
+          maxNftBoostBps?: number;
+          unbondingPeriodDays?: number;
+          minStakeAmount?: BN;
+          maxStakePerPosition?: BN;
         } = {};
 
         // Only set values that were provided, otherwise use current pool values
@@ -104,6 +125,34 @@ export function setPoolConfigCommand(program: Command): void {
           poolConfig.maxValueCap = pool.maxValueCap;
         }
 
+        poolConfig.lockMode = options.flexible
+          ? { flexible: {} }
+          : (pool.lockMode as { fixed: {} } | { flexible: {} });
+        poolConfig.minLockDays = options.minLockDays
+          ? parseInt(options.minLockDays)
+          : pool.minLockDays;
+        poolConfig.maxLockDays = options.maxLockDays
+          ? parseInt(options.maxLockDays)
+          : pool.maxLockDays;
+        poolConfig.maxBoostBps = options.maxBoostBps
+          ? parseInt(options.maxBoostBps)
+          : pool.maxBoostBps;
+        poolConfig.nftBoostBpsPerNft = options.nftBoostBps
+          ? parseInt(options.nftBoostBps)
+          : pool.nftBoostBpsPerNft;
+        poolConfig.maxNftBoostBps = options.maxNftBoostBps
+          ? parseInt(options.maxNftBoostBps)
+          : pool.maxNftBoostBps;
+        poolConfig.unbondingPeriodDays = options.unbondingDays
+          ? parseInt(options.unbondingDays)
+          : pool.unbondingPeriodDays;
+        poolConfig.minStakeAmount = options.minStake
+          ? new BN(parseInt(options.minStake) * 10 ** decimals)
+          : pool.minStakeAmount;
+        poolConfig.maxStakePerPosition = options.maxStake
+          ? new BN(parseInt(options.maxStake) * 10 ** decimals)
+          : pool.maxStakePerPosition;
+
         // Use the RPC method to directly execute the transaction
         spinner.text = 'Sending transaction to update pool configuration...';
 
@@ -15,6 +15,11 @@ export function claimNftCommand(program: Command): void {
     .option("-m, --token-mint <pubkey>", "Token mint address")
     .option("-id, --config-id <number>", "Config ID", "1")
     .option("-pos, --position-id <number>", "Position ID to claim", "0")
+    .option(
+      "--pool-index <number>",
+      "Pool the position belongs to, with --position-id",
+      "2"
+    )
     .option("-p, --position <pubkey>", "Position address (if you know it)")
     .option("-a, --asset <pubkey>", "NFT asset address (required)")
     .requiredOption(
@@ -36,6 +41,7 @@ export function claimNftCommand(program: Command): void {
         // Parse options
         const configId = parseInt(options.configId);
         const positionId = parseInt(options.positionId);
+        const positionPoolIndex = parseInt(options.poolIndex);
         const tokenMint = options.tokenMint
           ? new PublicKey(options.tokenMint)
           : new PublicKey(MINT);
@@ -58,10 +64,9 @@ export function claimNftCommand(program: Command): void {
         if (options.position) {
           positionPda = new PublicKey(options.position);
         } else {
-          [positionPda] = sdk.pda.findNftPositionPda(
+          [positionPda] = sdk.pda.findPositionPda(
             wallet.publicKey,
-            tokenMint,
-            asset,
+            sdk.pda.findPoolPda(configPda, positionPoolIndex)[0],
             positionId
           );
         }
@@ -140,6 +145,7 @@ export function claimNftCommand(program: Command): void {
           authority: wallet.publicKey,
           owner: wallet.publicKey,
           payer: wallet.publicKey,
+          positionPda,
           positionId,
           asset,
           tokenMint,
@@ -4,6 +4,7 @@ import { getConnection, getSDK, getWallet } from "../utils/connection";
 import ora from "ora";
 import { getMint, getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { MINT } from "../constants";
+import { BN } from "@coral-xyz/anchor";
 
 /**
  * Claim token position command implementation
@@ -15,7 +16,16 @@ export function claimTokenCommand(program: Command): void {
     .option("-m, --token-mint <pubkey>", "Token mint address")
     .option("-id, --config-id <number>", "Config ID", "1")
     .option("-pos, --position-id <number>", "Position ID to claim", "0")
+    .option(
+      "--pool-index <number>",
+      "Pool the position belongs to, with --position-id",
+      "2"
+    )
     .option("-p, --position <pubkey>", "Position address (if you know it)")
+    .option(
+      "-a, --amount <number>",
+      "Withdraw only this many tokens, leaving the rest staked"
+    )
     .action(async (options) => {
       try {
         const spinner = ora("Preparing to claim tokens...").start();
@@ -27,6 +37,7 @@ export function claimTokenCommand(program: Command): void {
         // Parse options
         const configId = parseInt(options.configId);
         const positionId = parseInt(options.positionId);
+        const positionPoolIndex = parseInt(options.poolIndex);
         const tokenMint = options.tokenMint
           ? new PublicKey(options.tokenMint)
           : new PublicKey(MINT);
@@ -42,7 +53,7 @@ export function claimTokenCommand(program: Command): void {
         } else {
           [positionPda] = sdk.pda.findPositionPda(
             wallet.publicKey,
-            tokenMint,
+            sdk.pda.findPoolPda(configPda, positionPoolIndex)[0],
             positionId
           );
         }
@@ -115,6 +126,14 @@ export function claimTokenCommand(program: Command): void {
         const poolIndex = (await sdk.fetchPoolByAddress(positionBefore?.pool!))
           ?.index;
 
+        // A partial withdrawal is given in whole tokens
+        const withdrawAmount = options.amount
+          ? new BN(
+              parseInt(options.amount) *
+                10 ** (await getMint(connection, tokenMint)).decimals
+            )
+          : undefined;
+
         // Execute claim transaction
         spinner.text = "Claiming tokens...";
 
@@ -128,6 +147,7 @@ export function claimTokenCommand(program: Command): void {
           vault: vaultAta,
           positionId,
           poolIndex: poolIndex!,
+          amount: withdrawAmount,
         });
 
         spinner.succeed(`Tokens claimed successfully. Tx: ${txid}`);
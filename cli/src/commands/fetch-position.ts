@@ -16,7 +16,8 @@ export function fetchPositionCommand(program: Command): void {
     .option("-o, --owner <pubkey>", "Position owner (defaults to wallet)")
     .option("-id, --position-id <number>", "Position ID", "0")
     .option("-m, --token-mint <pubkey>", "Token mint address")
-    .option("-a, --asset <pubkey>", "NFT asset address (for NFT positions)")
+    .option("--config-id <number>", "Config ID", "1")
+    .option("--pool-index <number>", "Pool the position belongs to", "2")
     .option("-p, --position <pubkey>", "Position PDA address (if you know it)")
     .option("--all", "Fetch all positions for the owner", false)
     .action(async (options) => {
@@ -120,20 +121,18 @@ export function fetchPositionCommand(program: Command): void {
           position = await sdk.fetchPositionByAddress(
             new PublicKey(options.position)
           );
-        } else if (options.asset) {
-          // Fetch NFT position
-          spinner.text = `Fetching NFT position for owner ${owner.toString()}...`;
-          const asset = new PublicKey(options.asset);
-          position = await sdk.fetchPosition(
-            owner,
-            positionId,
-            tokenMint,
-            asset
-          );
         } else {
-          // Fetch token position
-          spinner.text = `Fetching token position for owner ${owner.toString()}...`;
-          position = await sdk.fetchPosition(owner, positionId, tokenMint);
+          // Positions are keyed by owner, pool and id
+          spinner.text = `Fetching position for owner ${owner.toString()}...`;
+          const [configPda] = sdk.pda.findConfigPda(
+            wallet.publicKey,
+            parseInt(options.configId)
+          );
+          const [poolPda] = sdk.pda.findPoolPda(
+            configPda,
+            parseInt(options.poolIndex)
+          );
+          position = await sdk.fetchPosition(owner, poolPda, positionId);
         }
 
         if (!position) {
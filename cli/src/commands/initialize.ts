@@ -230,6 +230,17 @@ export function initializeCommand(program: Command): void {
 
           transact.add(authVaultIx);
 
+          // Register the config's collection so its NFTs can be staked
+          const addCollectionIx = await sdk.adminAddCollection({
+            authority: wallet.publicKey,
+            configId,
+            collection,
+            nftValueInTokens,
+            nftsLimitPerUser
+          });
+
+          transact.add(addCollectionIx);
+
           // spinner.text = `Authority vault initialized. Tx: ${authVaultIx}`;
 
           // Short delay to ensure vault is available
@@ -1,4 +1,6 @@
 import { Command } from "commander";
+import fs from "fs";
+import { BN } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 import { getConnection, getSDK, getWallet } from "../utils/connection";
 import ora from "ora";
@@ -17,7 +19,14 @@ export function stakeNftCommand(program: Command): void {
     .requiredOption("-a, --asset <pubkey>", "Asset/NFT address to stake")
     .option("-p, --pool-index <number>", "Pool index to stake in", "2")
     .option("-id, --config-id <number>", "Config ID", "1")
-    .option("-pos, --position-id <number>", "Position ID (optional)", "0")
+    .option(
+      "-l, --lock-days <number>",
+      "Lock period in days (flexible pools only)"
+    )
+    .option(
+      "--allowlist <path>",
+      "JSON file with this wallet's allowlist proof ({ cap, proof })"
+    )
     .action(async (options) => {
       try {
         const spinner = ora("Staking NFT...").start();
@@ -35,7 +44,17 @@ export function stakeNftCommand(program: Command): void {
         // Parse options
         const configId = parseInt(options.configId);
         const poolIndex = parseInt(options.poolIndex);
-        const positionId = parseInt(options.positionId);
+        const lockPeriodDays = options.lockDays
+          ? parseInt(options.lockDays)
+          : undefined;
+        const allowlist = options.allowlist
+          ? (() => {
+              const { cap, proof } = JSON.parse(
+                fs.readFileSync(options.allowlist, "utf8")
+              );
+              return { cap: new BN(cap), proof };
+            })()
+          : undefined;
         const tokenMint = options.tokenMint
           ? new PublicKey(options.tokenMint)
           : new PublicKey(MINT);
@@ -113,6 +132,9 @@ export function stakeNftCommand(program: Command): void {
         );
         spinner.text = `User Pool Stats PDA: ${userPoolStatsPda.toString()}`;
 
+        // The program assigns the position the user's next id
+        const positionId = await sdk.fetchNextPositionId(userAccountPda);
+
         // Stake the NFT
         spinner.text = `Staking NFT ${asset.toString()} in pool ${poolIndex}...`;
 
@@ -121,6 +143,8 @@ export function stakeNftCommand(program: Command): void {
           owner: wallet.publicKey,
           configId,
           positionId,
+          lockPeriodDays,
+          allowlist,
           mint: tokenMint,
           collection,
           asset,
@@ -134,10 +158,9 @@ export function stakeNftCommand(program: Command): void {
         await new Promise((resolve) => setTimeout(resolve, 2000));
 
         // Find the position PDA
-        const [positionPda] = sdk.pda.findNftPositionPda(
+        const [positionPda] = sdk.pda.findPositionPda(
           wallet.publicKey,
-          tokenMint,
-          asset,
+          poolPda,
           positionId
         );
 
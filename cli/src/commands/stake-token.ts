@@ -1,4 +1,5 @@
 import { Command } from "commander";
+import fs from "fs";
 import { PublicKey } from "@solana/web3.js";
 import { getConnection, getSDK, getWallet } from "../utils/connection";
 import ora from "ora";
@@ -17,7 +18,14 @@ export function stakeTokenCommand(program: Command): void {
     .option("-a, --amount <number>", "Amount of tokens to stake", "100")
     .option("-p, --pool-index <number>", "Pool index to stake in", "2")
     .option("-id, --config-id <number>", "Config ID", "1")
-    .option("-pos, --position-id <number>", "Position ID (optional)", "0")
+    .option(
+      "-l, --lock-days <number>",
+      "Lock period in days (flexible pools only)"
+    )
+    .option(
+      "--allowlist <path>",
+      "JSON file with this wallet's allowlist proof ({ cap, proof })"
+    )
     .action(async (options) => {
       try {
         const spinner = ora("Staking tokens...").start();
@@ -29,7 +37,17 @@ export function stakeTokenCommand(program: Command): void {
         // Parse options
         const configId = parseInt(options.configId);
         const poolIndex = parseInt(options.poolIndex);
-        const positionId = parseInt(options.positionId);
+        const lockPeriodDays = options.lockDays
+          ? parseInt(options.lockDays)
+          : undefined;
+        const allowlist = options.allowlist
+          ? (() => {
+              const { cap, proof } = JSON.parse(
+                fs.readFileSync(options.allowlist, "utf8")
+              );
+              return { cap: new BN(cap), proof };
+            })()
+          : undefined;
         const tokenMint = options.tokenMint
           ? new PublicKey(options.tokenMint)
           : new PublicKey(MINT);
@@ -103,6 +121,9 @@ export function stakeTokenCommand(program: Command): void {
         );
         spinner.text = `User Pool Stats PDA: ${userPoolStatsPda.toString()}`;
 
+        // The program assigns the position the user's next id
+        const positionId = await sdk.fetchNextPositionId(userAccountPda);
+
         // Stake the tokens
         spinner.text = `Staking ${options.amount} tokens in pool ${poolIndex}...`;
 
@@ -111,6 +132,8 @@ export function stakeTokenCommand(program: Command): void {
           owner: wallet.publicKey,
           configId,
           positionId,
+          lockPeriodDays,
+          allowlist,
           tokenMint,
           amount: new BN(amount),
           poolIndex,
@@ -125,7 +148,7 @@ export function stakeTokenCommand(program: Command): void {
         // Find the position PDA
         const [positionPda] = sdk.pda.findPositionPda(
           wallet.publicKey,
-          tokenMint,
+          poolPda,
           positionId
         );
 
@@ -0,0 +1,165 @@
+use anchor_lang::{AccountDeserialize, AnchorDeserialize};
+use anyhow::{anyhow, bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bert_staking_client::{
+    pda::find_config_pda,
+    state::{Config, UserAccountV3},
+    ConfigKeys,
+};
+use serde_json::{json, Map, Value};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig, instruction::Instruction, pubkey::Pubkey,
+    signature::read_keypair_file, signer::Signer, transaction::Transaction,
+};
+
+use crate::Cli;
+
+/// RPC connection, signer and config the commands run against
+pub struct Client {
+    pub rpc: RpcClient,
+    pub signer: Box<dyn Signer>,
+    pub config: Pubkey,
+    pub config_id: u64,
+    pub dry_run: bool,
+}
+
+fn normalize_url(url: &str) -> String {
+    match url {
+        "m" | "mainnet" | "mainnet-beta" => "https://api.mainnet-beta.solana.com",
+        "d" | "devnet" => "https://api.devnet.solana.com",
+        "t" | "testnet" => "https://api.testnet.solana.com",
+        "l" | "localhost" => "http://localhost:8899",
+        url => url,
+    }
+    .to_string()
+}
+
+impl Client {
+    pub fn new(cli: &Cli) -> Result<Self> {
+        let solana_config = solana_cli_config::CONFIG_FILE
+            .as_ref()
+            .and_then(|path| solana_cli_config::Config::load(path).ok())
+            .unwrap_or_default();
+
+        let url = normalize_url(cli.url.as_deref().unwrap_or(&solana_config.json_rpc_url));
+        let keypair_path = cli.keypair.clone().unwrap_or(solana_config.keypair_path);
+        let signer = read_keypair_file(&keypair_path)
+            .map_err(|err| anyhow!("failed to read keypair {keypair_path}: {err}"))?;
+
+        let authority = cli.authority.unwrap_or(signer.pubkey());
+        let config = cli
+            .config
+            .unwrap_or_else(|| find_config_pda(&authority, cli.config_id).0);
+
+        Ok(Self {
+            rpc: RpcClient::new_with_commitment(url, CommitmentConfig::confirmed()),
+            signer: Box::new(signer),
+            config,
+            config_id: cli.config_id,
+            dry_run: cli.dry_run,
+        })
+    }
+
+    pub fn pubkey(&self) -> Pubkey {
+        self.signer.pubkey()
+    }
+
+    pub fn fetch<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T> {
+        self.fetch_optional(address)?
+            .with_context(|| format!("account {address} not found"))
+    }
+
+    pub fn fetch_optional<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<Option<T>> {
+        let account = self
+            .rpc
+            .get_account_with_commitment(address, self.rpc.commitment())?
+            .value;
+
+        account
+            .map(|account| {
+                T::try_deserialize(&mut account.data.as_slice())
+                    .with_context(|| format!("failed to decode account {address}"))
+            })
+            .transpose()
+    }
+
+    /// Keys of the selected config, read from chain
+    pub fn keys(&self) -> Result<ConfigKeys> {
+        let account: Config = self.fetch(&self.config).context("config not found")?;
+        Ok(ConfigKeys::from_account(self.config, &account))
+    }
+
+    /// Id the program assigns to `owner`'s next position, zero before their
+    /// user account exists
+    pub fn next_position_id(&self, keys: &ConfigKeys, owner: &Pubkey) -> Result<u64> {
+        let user: Option<UserAccountV3> = self.fetch_optional(&keys.user_account(owner))?;
+        Ok(user.map_or(0, |user| user.next_position_id))
+    }
+
+    /// Simulates a view instruction and decodes its return data. Views never
+    /// send, so they don't need a funded signer.
+    pub fn view<T: AnchorDeserialize>(&self, ix: Instruction) -> Result<T> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.pubkey()),
+            &[self.signer.as_ref()],
+            blockhash,
+        );
+
+        let result = self.rpc.simulate_transaction(&transaction)?.value;
+        if let Some(err) = result.err {
+            bail!(
+                "simulation failed: {err}\n{}",
+                result.logs.unwrap_or_default().join("\n")
+            );
+        }
+
+        let (data, _) = result
+            .return_data
+            .context("the instruction returned no data")?
+            .data;
+        let data = STANDARD.decode(data)?;
+        Ok(T::try_from_slice(&data)?)
+    }
+
+    /// Signs `instructions` with the signer as fee payer and either sends
+    /// them or, with `--dry-run`, simulates them
+    pub fn execute(
+        &self,
+        instructions: &[Instruction],
+        extra_signers: &[&dyn Signer],
+    ) -> Result<Map<String, Value>> {
+        let mut signers: Vec<&dyn Signer> = vec![self.signer.as_ref()];
+        signers.extend_from_slice(extra_signers);
+
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.pubkey()),
+            &signers,
+            blockhash,
+        );
+
+        let mut output = Map::new();
+        if self.dry_run {
+            let result = self.rpc.simulate_transaction(&transaction)?.value;
+            output.insert("success".into(), json!(result.err.is_none()));
+            if let Some(err) = result.err {
+                output.insert("error".into(), json!(err.to_string()));
+            }
+            output.insert("units_consumed".into(), json!(result.units_consumed));
+            output.insert("logs".into(), json!(result.logs.unwrap_or_default()));
+        } else {
+            let signature = self
+                .rpc
+                .send_and_confirm_transaction(&transaction)
+                .context("transaction failed")?;
+            output.insert("success".into(), json!(true));
+            output.insert("signature".into(), json!(signature.to_string()));
+        }
+
+        Ok(output)
+    }
+}
@@ -0,0 +1,961 @@
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use bert_staking_client::{
+    pda::{find_liquid_pool_pda, find_redemption_pda},
+    state::{
+        AllowlistProof, Config, LiquidPool, LockMode, Pool, PoolCapacityView, PoolConfigArgs,
+        PositionType, PositionV4, PositionYieldView, UserAccountV3, UserCapacityView,
+    },
+    ConfigKeys,
+};
+use clap::{ArgAction, Args, Subcommand, ValueEnum};
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    types::UpdateAuthority,
+};
+use serde_json::{json, Map, Value};
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+};
+
+use crate::client::Client;
+
+type Output = Map<String, Value>;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Create a config owned by the signer
+    Initialize {
+        /// Staked token mint
+        #[arg(long)]
+        mint: Pubkey,
+        /// Default NFT collection
+        #[arg(long)]
+        collection: Pubkey,
+        /// Wallet receiving admin withdrawals, defaults to the signer
+        #[arg(long)]
+        admin_withdraw_destination: Option<Pubkey>,
+        /// Maximum tokens staked across the config
+        #[arg(long)]
+        max_cap: u64,
+        /// Value of one NFT in token base units
+        #[arg(long)]
+        nft_value_in_tokens: u64,
+        #[arg(long)]
+        nfts_limit_per_user: u8,
+    },
+    /// Create the config's yield vault
+    InitializeAuthVault,
+    /// Create the collection position receipts are minted into
+    InitializeReceiptCollection {
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        uri: String,
+    },
+    /// Create the signer's user account
+    InitializeUser {
+        #[arg(short, long)]
+        pool_index: u32,
+    },
+    /// Approve a wallet to claim for the signer. Omit to revoke.
+    SetDelegate {
+        #[arg(long)]
+        delegate: Option<Pubkey>,
+    },
+    /// Create a pool
+    InitializePool {
+        #[arg(short, long)]
+        index: u32,
+        #[arg(long)]
+        lock_period_days: u16,
+        /// Yield per lock term, scaled by 1e9 (50000000 = 5%)
+        #[arg(long)]
+        yield_rate: u64,
+        #[arg(long)]
+        max_nfts_cap: u32,
+        #[arg(long)]
+        max_tokens_cap: u64,
+        #[arg(long)]
+        max_value_cap: u64,
+    },
+    /// Turn a pool into a liquid pool
+    InitializeLiquidPool {
+        #[arg(short, long)]
+        pool_index: u32,
+        #[arg(long)]
+        unbonding_period_days: u16,
+    },
+    /// Stake tokens into a new position, creating the user account if needed
+    StakeToken {
+        #[arg(short, long)]
+        pool_index: u32,
+        /// Amount in token base units
+        #[arg(short, long)]
+        amount: u64,
+        /// Lock period for flexible pools
+        #[arg(long)]
+        lock_days: Option<u16>,
+        /// Mint a transferable receipt for the position
+        #[arg(long)]
+        receipt: bool,
+        #[command(flatten)]
+        allowlist: AllowlistArgs,
+    },
+    /// Stake an NFT into a new position, creating the user account if needed
+    StakeNft {
+        #[arg(short, long)]
+        pool_index: u32,
+        #[arg(long)]
+        asset: Pubkey,
+        /// Lock period for flexible pools
+        #[arg(long)]
+        lock_days: Option<u16>,
+        /// Mint a transferable receipt for the position
+        #[arg(long)]
+        receipt: bool,
+        #[command(flatten)]
+        allowlist: AllowlistArgs,
+    },
+    /// Claim a token position, or part of it with `--amount`
+    ClaimToken {
+        #[arg(long)]
+        position: Pubkey,
+        #[arg(short, long)]
+        amount: Option<u64>,
+    },
+    /// Claim an NFT position
+    ClaimNft {
+        #[arg(long)]
+        position: Pubkey,
+    },
+    /// Withdraw a position's tokens or NFT without yield while the config is
+    /// in emergency mode
+    EmergencyWithdraw {
+        #[arg(long)]
+        position: Pubkey,
+    },
+    /// Settle another owner's unlocked token position for the crank tip
+    CrankClaim {
+        #[arg(long)]
+        position: Pubkey,
+    },
+    /// Start the unstake cooldown of a position
+    RequestUnstake {
+        #[arg(long)]
+        position: Pubkey,
+    },
+    /// Relock a position, optionally into another pool
+    ExtendLock {
+        #[arg(long)]
+        position: Pubkey,
+        /// Pool to relock into, defaults to the position's pool
+        #[arg(long)]
+        target_pool_index: Option<u32>,
+        /// Lock period for flexible pools
+        #[arg(long)]
+        lock_days: Option<u16>,
+    },
+    /// Give a position to another user, who co-signs the transfer
+    TransferPosition {
+        #[arg(long)]
+        position: Pubkey,
+        /// Keypair file of the new owner
+        #[arg(long)]
+        new_owner_keypair: String,
+    },
+    /// Move part of a token position into a new position
+    SplitPosition {
+        #[arg(long)]
+        position: Pubkey,
+        #[arg(short, long)]
+        amount: u64,
+    },
+    /// Fold `--other` into `--position`
+    MergePositions {
+        #[arg(long)]
+        position: Pubkey,
+        #[arg(long)]
+        other: Pubkey,
+    },
+    /// Re-key a position created with the legacy seeds
+    MigratePositionSeeds {
+        #[arg(long)]
+        position: Pubkey,
+    },
+    /// Bring the config up to the current layout version
+    MigrateConfig,
+    /// Bring a pool up to the current layout version
+    MigratePool {
+        #[arg(short, long)]
+        pool_index: u32,
+    },
+    /// Bring a position up to the current layout version
+    MigratePosition {
+        #[arg(long)]
+        position: Pubkey,
+    },
+    /// Bring a user account up to the current layout version
+    MigrateUserAccount {
+        /// Defaults to the signer
+        #[arg(long)]
+        owner: Option<Pubkey>,
+    },
+    /// Deposit into a liquid pool for shares
+    StakeLiquid {
+        #[arg(short, long)]
+        pool_index: u32,
+        /// Amount in token base units
+        #[arg(short, long)]
+        amount: u64,
+    },
+    /// Redeem liquid pool shares into a redemption ticket
+    RequestRedeem {
+        #[arg(short, long)]
+        pool_index: u32,
+        #[arg(long)]
+        shares: u64,
+    },
+    /// Withdraw an unbonded redemption ticket
+    Redeem {
+        #[arg(short, long)]
+        pool_index: u32,
+        #[arg(long)]
+        id: u64,
+    },
+    /// Stop new stakes into a pool
+    PausePool {
+        #[arg(short, long)]
+        pool_index: u32,
+    },
+    /// Resume a paused pool
+    ActivatePool {
+        #[arg(short, long)]
+        pool_index: u32,
+    },
+    /// Halt stakes in every pool and open emergency withdrawals
+    EnableEmergency,
+    /// Leave emergency mode
+    DisableEmergency,
+    /// Update a paused pool's settings. Unset options keep their current value.
+    SetPoolConfig {
+        #[arg(short, long)]
+        pool_index: u32,
+        #[command(flatten)]
+        overrides: PoolConfigOverrides,
+    },
+    /// Set a pool's allowlist root, all zeroes to open the pool
+    SetMerkleRoot {
+        #[arg(short, long)]
+        pool_index: u32,
+        /// Hex encoded root
+        #[arg(long, value_parser = parse_hash)]
+        merkle_root: [u8; 32],
+    },
+    /// Withdraw from the yield vault to the config's withdraw destination
+    WithdrawTokens {
+        /// Amount in token base units
+        #[arg(short, long)]
+        amount: u64,
+    },
+    /// Set the lamports paid to crankers
+    SetCrankTip {
+        #[arg(long)]
+        tip_lamports: u64,
+    },
+    /// Register an NFT collection
+    AddCollection {
+        #[arg(long)]
+        collection: Pubkey,
+        #[arg(long)]
+        nft_value_in_tokens: u64,
+        #[arg(long)]
+        nfts_limit_per_user: u8,
+    },
+    /// Update a registered NFT collection
+    UpdateCollection {
+        #[arg(long)]
+        collection: Pubkey,
+        #[arg(long)]
+        nft_value_in_tokens: u64,
+        #[arg(long)]
+        nfts_limit_per_user: u8,
+        #[arg(long, action = ArgAction::Set)]
+        is_active: bool,
+    },
+    /// Cap open positions per user, zero for no limit
+    SetPositionLimit {
+        #[arg(long)]
+        max_positions_per_user: u16,
+    },
+    /// Show a position's pending yield and the yield it pays at unlock
+    PositionYield {
+        #[arg(long)]
+        position: Pubkey,
+    },
+    /// Show the value a pool can still accept under its value cap
+    PoolCapacity {
+        #[arg(short, long)]
+        pool_index: u32,
+    },
+    /// Show what a user can still stake in a pool
+    UserCapacity {
+        #[arg(short, long)]
+        pool_index: u32,
+        /// User to report on, defaults to the signer
+        #[arg(long)]
+        owner: Option<Pubkey>,
+        /// Also report the user's limit in this registered collection
+        #[arg(long)]
+        collection: Option<Pubkey>,
+    },
+}
+
+#[derive(Args)]
+pub struct AllowlistArgs {
+    /// The signer's allowlisted cap, for pools with an allowlist
+    #[arg(long)]
+    allowlist_cap: Option<u64>,
+
+    /// Hex encoded proof hash, from the leaf up. Repeat for each level.
+    #[arg(long = "proof", value_parser = parse_hash, requires = "allowlist_cap")]
+    proof: Vec<[u8; 32]>,
+}
+
+impl AllowlistArgs {
+    fn proof(self) -> Option<AllowlistProof> {
+        self.allowlist_cap.map(|cap| AllowlistProof {
+            cap,
+            proof: self.proof,
+        })
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum LockModeArg {
+    Fixed,
+    Flexible,
+}
+
+#[derive(Args)]
+pub struct PoolConfigOverrides {
+    #[arg(long)]
+    lock_period_days: Option<u16>,
+    /// Yield per lock term, scaled by 1e9 (50000000 = 5%)
+    #[arg(long)]
+    yield_rate: Option<u64>,
+    #[arg(long)]
+    max_nfts_cap: Option<u32>,
+    #[arg(long)]
+    max_tokens_cap: Option<u64>,
+    #[arg(long)]
+    max_value_cap: Option<u64>,
+    #[arg(long, value_enum)]
+    lock_mode: Option<LockModeArg>,
+    #[arg(long)]
+    min_lock_days: Option<u16>,
+    #[arg(long)]
+    max_lock_days: Option<u16>,
+    #[arg(long)]
+    max_boost_bps: Option<u32>,
+    #[arg(long)]
+    nft_boost_bps_per_nft: Option<u16>,
+    #[arg(long)]
+    max_nft_boost_bps: Option<u16>,
+    #[arg(long)]
+    min_stake_amount: Option<u64>,
+    #[arg(long)]
+    max_stake_per_position: Option<u64>,
+    #[arg(long)]
+    unbonding_period_days: Option<u16>,
+}
+
+impl PoolConfigOverrides {
+    fn apply(self, pool: &Pool) -> PoolConfigArgs {
+        PoolConfigArgs {
+            lock_period_days: self.lock_period_days.unwrap_or(pool.lock_period_days),
+            yield_rate: self.yield_rate.unwrap_or(pool.yield_rate),
+            max_nfts_cap: self.max_nfts_cap.unwrap_or(pool.max_nfts_cap),
+            max_tokens_cap: self.max_tokens_cap.unwrap_or(pool.max_tokens_cap),
+            max_value_cap: self.max_value_cap.unwrap_or(pool.max_value_cap),
+            lock_mode: match self.lock_mode {
+                Some(LockModeArg::Fixed) => LockMode::Fixed,
+                Some(LockModeArg::Flexible) => LockMode::Flexible,
+                None => pool.lock_mode,
+            },
+            min_lock_days: self.min_lock_days.unwrap_or(pool.min_lock_days),
+            max_lock_days: self.max_lock_days.unwrap_or(pool.max_lock_days),
+            max_boost_bps: self.max_boost_bps.unwrap_or(pool.max_boost_bps),
+            nft_boost_bps_per_nft: self
+                .nft_boost_bps_per_nft
+                .unwrap_or(pool.nft_boost_bps_per_nft),
+            max_nft_boost_bps: self.max_nft_boost_bps.unwrap_or(pool.max_nft_boost_bps),
+            min_stake_amount: self.min_stake_amount.unwrap_or(pool.min_stake_amount),
+            max_stake_per_position: self
+                .max_stake_per_position
+                .unwrap_or(pool.max_stake_per_position),
+            unbonding_period_days: self
+                .unbonding_period_days
+                .unwrap_or(pool.unbonding_period_days),
+        }
+    }
+}
+
+fn parse_hash(value: &str) -> Result<[u8; 32]> {
+    let value = value.strip_prefix("0x").unwrap_or(value);
+    ensure!(
+        value.len() == 64 && value.is_ascii(),
+        "expected 32 hex encoded bytes"
+    );
+
+    let mut hash = [0; 32];
+    for (i, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&value[i * 2..i * 2 + 2], 16)?;
+    }
+    Ok(hash)
+}
+
+/// A fetched position and the index of its pool
+struct PositionInfo {
+    address: Pubkey,
+    account: PositionV4,
+    index: u32,
+}
+
+impl PositionInfo {
+    fn load(client: &Client, address: Pubkey) -> Result<Self> {
+        let account: PositionV4 = client.fetch(&address)?;
+        let pool: Pool = client.fetch(&account.pool)?;
+        Ok(Self {
+            address,
+            account,
+            index: pool.index,
+        })
+    }
+
+    fn receipt(&self) -> Option<Pubkey> {
+        (self.account.receipt != Pubkey::default()).then_some(self.account.receipt)
+    }
+
+    fn is_nft(&self) -> bool {
+        self.account.position_type == PositionType::NFT
+    }
+}
+
+/// Collection an asset belongs to
+fn asset_collection(client: &Client, asset: &Pubkey) -> Result<Pubkey> {
+    let data = client.rpc.get_account_data(asset)?;
+    let asset = BaseAssetV1::from_bytes(&data).context("failed to decode asset")?;
+    match asset.update_authority {
+        UpdateAuthority::Collection(collection) => Ok(collection),
+        _ => bail!("asset is not part of a collection"),
+    }
+}
+
+fn collection_update_authority(client: &Client, collection: &Pubkey) -> Result<Pubkey> {
+    let data = client.rpc.get_account_data(collection)?;
+    let collection = BaseCollectionV1::from_bytes(&data).context("failed to decode collection")?;
+    Ok(collection.update_authority)
+}
+
+/// Prepends the user account creation when `owner` has none
+fn with_user_init(
+    client: &Client,
+    keys: &ConfigKeys,
+    index: u32,
+    ix: Instruction,
+) -> Result<Vec<Instruction>> {
+    let owner = client.pubkey();
+    let user: Option<UserAccountV3> = client.fetch_optional(&keys.user_account(&owner))?;
+
+    Ok(match user {
+        Some(_) => vec![ix],
+        None => vec![keys.initialize_user(owner, index), ix],
+    })
+}
+
+fn insert(mut output: Output, key: &str, value: impl ToString) -> Output {
+    output.insert(key.into(), json!(value.to_string()));
+    output
+}
+
+/// Output of a view, from the `json!` object of its fields
+fn view_output(view: Value) -> Output {
+    let Value::Object(output) = view else {
+        unreachable!("views are JSON objects")
+    };
+    output
+}
+
+pub fn run(client: &Client, command: Command) -> Result<Output> {
+    let signer = client.pubkey();
+
+    if let Command::Initialize {
+        mint,
+        collection,
+        admin_withdraw_destination,
+        max_cap,
+        nft_value_in_tokens,
+        nfts_limit_per_user,
+    } = command
+    {
+        let keys = ConfigKeys::new(signer, client.config_id, mint, collection);
+        ensure!(
+            keys.config == client.config,
+            "--config does not match the signer and --config-id"
+        );
+
+        let ix = keys.initialize(
+            client.config_id,
+            admin_withdraw_destination.unwrap_or(signer),
+            max_cap,
+            nft_value_in_tokens,
+            nfts_limit_per_user,
+        );
+        let output = client.execute(&[ix], &[])?;
+        return Ok(insert(output, "config", keys.config));
+    }
+
+    let keys = client.keys()?;
+
+    let output = match command {
+        Command::Initialize { .. } => unreachable!("handled above"),
+        Command::InitializeAuthVault => {
+            let output = client.execute(&[keys.initialize_auth_vault()], &[])?;
+            insert(output, "authority_vault", keys.authority_vault())
+        }
+        Command::InitializeReceiptCollection { name, uri } => {
+            let receipt_collection = Keypair::new();
+            let ix = keys.initialize_receipt_collection(receipt_collection.pubkey(), name, uri);
+            let output = client.execute(&[ix], &[&receipt_collection])?;
+            insert(output, "receipt_collection", receipt_collection.pubkey())
+        }
+        Command::InitializeUser { pool_index } => {
+            let output = client.execute(&[keys.initialize_user(signer, pool_index)], &[])?;
+            insert(output, "user_account", keys.user_account(&signer))
+        }
+        Command::SetDelegate { delegate } => {
+            client.execute(&[keys.set_delegate(signer, delegate)], &[])?
+        }
+        Command::InitializePool {
+            index,
+            lock_period_days,
+            yield_rate,
+            max_nfts_cap,
+            max_tokens_cap,
+            max_value_cap,
+        } => {
+            let ix = keys.initialize_pool(
+                index,
+                lock_period_days,
+                yield_rate,
+                max_nfts_cap,
+                max_tokens_cap,
+                max_value_cap,
+            );
+            let output = client.execute(&[ix], &[])?;
+            insert(output, "pool", keys.pool(index))
+        }
+        Command::InitializeLiquidPool {
+            pool_index,
+            unbonding_period_days,
+        } => {
+            let ix = keys.initialize_liquid_pool(pool_index, unbonding_period_days);
+            let output = client.execute(&[ix], &[])?;
+            insert(
+                output,
+                "liquid_pool",
+                find_liquid_pool_pda(&keys.pool(pool_index)).0,
+            )
+        }
+        Command::StakeToken {
+            pool_index,
+            amount,
+            lock_days,
+            receipt,
+            allowlist,
+        } => {
+            let id = client.next_position_id(&keys, &signer)?;
+            let receipt = receipt.then(Keypair::new);
+            let ix = keys.stake_token(
+                signer,
+                pool_index,
+                id,
+                amount,
+                lock_days,
+                allowlist.proof(),
+                receipt.as_ref().map(|receipt| receipt.pubkey()),
+            );
+
+            let ixs = with_user_init(client, &keys, pool_index, ix)?;
+            let extra_signers: Vec<&dyn Signer> = receipt
+                .iter()
+                .map(|receipt| receipt as &dyn Signer)
+                .collect();
+            let output = client.execute(&ixs, &extra_signers)?;
+            insert(output, "position", keys.position(&signer, pool_index, id))
+        }
+        Command::StakeNft {
+            pool_index,
+            asset,
+            lock_days,
+            receipt,
+            allowlist,
+        } => {
+            let id = client.next_position_id(&keys, &signer)?;
+            let collection = asset_collection(client, &asset)?;
+            let receipt = receipt.then(Keypair::new);
+            let ix = keys.stake_nft(
+                signer,
+                pool_index,
+                id,
+                asset,
+                collection,
+                lock_days,
+                allowlist.proof(),
+                receipt.as_ref().map(|receipt| receipt.pubkey()),
+            );
+
+            let ixs = with_user_init(client, &keys, pool_index, ix)?;
+            let extra_signers: Vec<&dyn Signer> = receipt
+                .iter()
+                .map(|receipt| receipt as &dyn Signer)
+                .collect();
+            let output = client.execute(&ixs, &extra_signers)?;
+            insert(output, "position", keys.position(&signer, pool_index, id))
+        }
+        Command::ClaimToken { position, amount } => {
+            let position = PositionInfo::load(client, position)?;
+            ensure!(!position.is_nft(), "use claim-nft for NFT positions");
+
+            let owner = position.account.owner;
+            let ix = match amount {
+                Some(amount) => keys.claim_position_token_partial(
+                    signer,
+                    owner,
+                    position.index,
+                    position.address,
+                    position.receipt(),
+                    amount,
+                ),
+                None => keys.claim_position_token(
+                    signer,
+                    owner,
+                    position.index,
+                    position.address,
+                    position.receipt(),
+                ),
+            };
+            client.execute(&[ix], &[])?
+        }
+        Command::ClaimNft { position } => {
+            let position = PositionInfo::load(client, position)?;
+            ensure!(position.is_nft(), "use claim-token for token positions");
+
+            let asset = position.account.asset;
+            let collection = asset_collection(client, &asset)?;
+            let ix = keys.claim_position_nft(
+                signer,
+                position.account.owner,
+                signer,
+                position.index,
+                position.address,
+                asset,
+                collection,
+                position.account.collection_counted,
+                collection_update_authority(client, &collection)?,
+                position.receipt(),
+            );
+            client.execute(&[ix], &[])?
+        }
+        Command::EmergencyWithdraw { position } => {
+            let position = PositionInfo::load(client, position)?;
+            let owner = position.account.owner;
+
+            let ix = if position.is_nft() {
+                let asset = position.account.asset;
+                let collection = asset_collection(client, &asset)?;
+                keys.emergency_withdraw_nft(
+                    signer,
+                    owner,
+                    signer,
+                    position.index,
+                    position.address,
+                    asset,
+                    collection,
+                    position.account.collection_counted,
+                    collection_update_authority(client, &collection)?,
+                    position.receipt(),
+                )
+            } else {
+                keys.emergency_withdraw_token(
+                    signer,
+                    owner,
+                    position.index,
+                    position.address,
+                    position.receipt(),
+                )
+            };
+            client.execute(&[ix], &[])?
+        }
+        Command::CrankClaim { position } => {
+            let position = PositionInfo::load(client, position)?;
+            let ix = keys.crank_claim(
+                signer,
+                position.account.owner,
+                position.index,
+                position.address,
+            );
+            client.execute(&[ix], &[])?
+        }
+        Command::RequestUnstake { position } => {
+            let position = PositionInfo::load(client, position)?;
+            let ix = keys.request_unstake(
+                signer,
+                position.account.owner,
+                position.index,
+                position.address,
+                position.receipt(),
+            );
+            client.execute(&[ix], &[])?
+        }
+        Command::ExtendLock {
+            position,
+            target_pool_index,
+            lock_days,
+        } => {
+            let position = PositionInfo::load(client, position)?;
+            let owner = position.account.owner;
+            match target_pool_index.filter(|index| *index != position.index) {
+                Some(target_index) => {
+                    // Moving pools re-keys the position under the owner's next id
+                    let id = client.next_position_id(&keys, &owner)?;
+                    let ix = keys.extend_lock(
+                        signer,
+                        owner,
+                        position.index,
+                        Some((target_index, id)),
+                        position.address,
+                        lock_days,
+                    );
+                    let output = client.execute(&[ix], &[])?;
+                    insert(output, "position", keys.position(&owner, target_index, id))
+                }
+                None => {
+                    let ix = keys.extend_lock(
+                        signer,
+                        owner,
+                        position.index,
+                        None,
+                        position.address,
+                        lock_days,
+                    );
+                    client.execute(&[ix], &[])?
+                }
+            }
+        }
+        Command::TransferPosition {
+            position,
+            new_owner_keypair,
+        } => {
+            let new_owner_signer = read_keypair_file(&new_owner_keypair)
+                .map_err(|err| anyhow!("failed to read keypair {new_owner_keypair}: {err}"))?;
+            let new_owner = new_owner_signer.pubkey();
+
+            let position = PositionInfo::load(client, position)?;
+            let new_user: UserAccountV3 = client
+                .fetch(&keys.user_account(&new_owner))
+                .context("the new owner has no user account")?;
+
+            // Positions staked before collections were registered aren't
+            // counted in any registry entry
+            let (asset, collection) = if position.is_nft() {
+                let asset = position.account.asset;
+                let collection = position
+                    .account
+                    .collection_counted
+                    .then(|| asset_collection(client, &asset))
+                    .transpose()?;
+                (Some(asset), collection)
+            } else {
+                (None, None)
+            };
+
+            let id = new_user.next_position_id;
+            let ix = keys.transfer_position(
+                signer,
+                new_owner,
+                position.index,
+                position.address,
+                id,
+                asset,
+                collection,
+            );
+            let output = client.execute(&[ix], &[&new_owner_signer])?;
+            insert(
+                output,
+                "position",
+                keys.position(&new_owner, position.index, id),
+            )
+        }
+        Command::SplitPosition { position, amount } => {
+            let position = PositionInfo::load(client, position)?;
+            let id = client.next_position_id(&keys, &signer)?;
+            let ix = keys.split_position(signer, position.index, position.address, id, amount);
+            let output = client.execute(&[ix], &[])?;
+            insert(
+                output,
+                "position",
+                keys.position(&signer, position.index, id),
+            )
+        }
+        Command::MergePositions { position, other } => {
+            let position = PositionInfo::load(client, position)?;
+            let ix = keys.merge_positions(signer, position.index, position.address, other);
+            client.execute(&[ix], &[])?
+        }
+        Command::MigratePositionSeeds { position } => {
+            let position = PositionInfo::load(client, position)?;
+            let id = client.next_position_id(&keys, &signer)?;
+            let ix =
+                keys.migrate_position_seeds(signer, position.account.pool, position.address, id);
+            let output = client.execute(&[ix], &[])?;
+            insert(
+                output,
+                "position",
+                keys.position(&signer, position.index, id),
+            )
+        }
+        Command::MigrateConfig => client.execute(&[keys.migrate_config(signer)], &[])?,
+        Command::MigratePool { pool_index } => {
+            client.execute(&[keys.migrate_pool(signer, pool_index)], &[])?
+        }
+        Command::MigratePosition { position } => {
+            client.execute(&[keys.migrate_position(signer, position)], &[])?
+        }
+        Command::MigrateUserAccount { owner } => {
+            let owner = owner.unwrap_or(signer);
+            client.execute(&[keys.migrate_user_account(signer, owner)], &[])?
+        }
+        Command::StakeLiquid { pool_index, amount } => {
+            client.execute(&[keys.stake_liquid(signer, pool_index, amount)], &[])?
+        }
+        Command::RequestRedeem { pool_index, shares } => {
+            // The program assigns tickets the liquid pool's next id
+            let liquid_pool: LiquidPool =
+                client.fetch(&find_liquid_pool_pda(&keys.pool(pool_index)).0)?;
+            let id = liquid_pool.next_ticket_id;
+            let ix = keys.request_redeem(signer, pool_index, id, shares);
+            let output = client.execute(&[ix], &[])?;
+            insert(
+                output,
+                "ticket",
+                find_redemption_pda(&signer, &keys.pool(pool_index), id).0,
+            )
+        }
+        Command::Redeem { pool_index, id } => {
+            client.execute(&[keys.redeem(signer, pool_index, id)], &[])?
+        }
+        Command::PausePool { pool_index } => {
+            client.execute(&[keys.admin_pause_pool(pool_index)], &[])?
+        }
+        Command::ActivatePool { pool_index } => {
+            client.execute(&[keys.admin_activate_pool(pool_index)], &[])?
+        }
+        Command::EnableEmergency => client.execute(&[keys.admin_enable_emergency()], &[])?,
+        Command::DisableEmergency => client.execute(&[keys.admin_disable_emergency()], &[])?,
+        Command::SetPoolConfig {
+            pool_index,
+            overrides,
+        } => {
+            let pool: Pool = client.fetch(&keys.pool(pool_index))?;
+            let ix = keys.admin_set_pool_config(pool_index, overrides.apply(&pool));
+            client.execute(&[ix], &[])?
+        }
+        Command::SetMerkleRoot {
+            pool_index,
+            merkle_root,
+        } => client.execute(&[keys.admin_set_merkle_root(pool_index, merkle_root)], &[])?,
+        Command::WithdrawTokens { amount } => {
+            let config: Config = client.fetch(&keys.config)?;
+            let ix = keys.admin_withdraw_tokens(config.admin_withdraw_destination, amount);
+            client.execute(&[ix], &[])?
+        }
+        Command::SetCrankTip { tip_lamports } => {
+            client.execute(&[keys.admin_set_crank_tip(tip_lamports)], &[])?
+        }
+        Command::AddCollection {
+            collection,
+            nft_value_in_tokens,
+            nfts_limit_per_user,
+        } => {
+            let ix =
+                keys.admin_add_collection(collection, nft_value_in_tokens, nfts_limit_per_user);
+            let output = client.execute(&[ix], &[])?;
+            insert(
+                output,
+                "collection_entry",
+                keys.collection_entry(&collection),
+            )
+        }
+        Command::UpdateCollection {
+            collection,
+            nft_value_in_tokens,
+            nfts_limit_per_user,
+            is_active,
+        } => {
+            let ix = keys.admin_update_collection(
+                collection,
+                nft_value_in_tokens,
+                nfts_limit_per_user,
+                is_active,
+            );
+            client.execute(&[ix], &[])?
+        }
+        Command::SetPositionLimit {
+            max_positions_per_user,
+        } => client.execute(
+            &[keys.admin_set_position_limit(max_positions_per_user)],
+            &[],
+        )?,
+        Command::PositionYield { position } => {
+            let position = PositionInfo::load(client, position)?;
+            let view: PositionYieldView = client.view(keys.view_position_yield(
+                position.account.owner,
+                position.index,
+                position.address,
+            ))?;
+            view_output(json!({
+                "pending_yield": view.pending_yield,
+                "projected_yield": view.projected_yield,
+                "unlock_time": view.unlock_time,
+                "is_withdrawable": view.is_withdrawable,
+            }))
+        }
+        Command::PoolCapacity { pool_index } => {
+            let view: PoolCapacityView = client.view(keys.view_pool_capacity(pool_index))?;
+            view_output(json!({
+                "total_value": view.total_value,
+                "max_value_cap": view.max_value_cap,
+                "remaining_value": view.remaining_value,
+            }))
+        }
+        Command::UserCapacity {
+            pool_index,
+            owner,
+            collection,
+        } => {
+            let ix = keys.view_user_capacity(owner.unwrap_or(signer), pool_index, collection);
+            let view: UserCapacityView = client.view(ix)?;
+            view_output(json!({
+                "remaining_value": view.remaining_value,
+                "remaining_pool_nfts": view.remaining_pool_nfts,
+                "remaining_nfts": view.remaining_nfts,
+                "remaining_collection_nfts": view.remaining_collection_nfts,
+            }))
+        }
+    };
+
+    Ok(output)
+}
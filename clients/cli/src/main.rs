@@ -0,0 +1,77 @@
+//! Operator CLI for the BERT staking program.
+//!
+//! Every program instruction has a subcommand. Transactions are signed with a
+//! keypair file, or with the keypair configured for the local Solana CLI, and
+//! `--dry-run` simulates them instead of sending. Results are printed as
+//! `key: value` lines, or as a JSON object with `--output json`.
+
+mod client;
+mod commands;
+mod output;
+
+use clap::Parser;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{client::Client, commands::Command, output::OutputFormat};
+
+#[derive(Parser)]
+#[command(
+    name = "bert-staking",
+    version,
+    about = "CLI for the BERT staking program"
+)]
+pub struct Cli {
+    /// RPC URL or moniker (mainnet-beta, devnet, localhost). Defaults to the
+    /// Solana CLI config.
+    #[arg(short, long, global = true)]
+    url: Option<String>,
+
+    /// Signer keypair file. Defaults to the Solana CLI config keypair.
+    #[arg(short, long, global = true, env = "BERT_STAKING_KEYPAIR")]
+    keypair: Option<String>,
+
+    /// Config account. Defaults to the config derived from `--authority` and
+    /// `--config-id`.
+    #[arg(long, global = true)]
+    config: Option<Pubkey>,
+
+    /// Config authority, defaults to the signer
+    #[arg(long, global = true)]
+    authority: Option<Pubkey>,
+
+    /// Config id
+    #[arg(long, global = true, default_value_t = 1)]
+    config_id: u64,
+
+    /// Simulate the transaction instead of sending it
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    /// Output format
+    #[arg(short, long, global = true, value_enum, default_value_t = OutputFormat::Display)]
+    output: OutputFormat,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let format = cli.output;
+
+    let result = Client::new(&cli).and_then(|client| commands::run(&client, cli.command));
+
+    match result {
+        Ok(output) => {
+            format.print(&output);
+            // A failed simulation is reported like any output but still fails
+            if output.get("success") == Some(&false.into()) {
+                std::process::exit(1);
+            }
+        }
+        Err(err) => {
+            format.print_error(&err);
+            std::process::exit(1);
+        }
+    }
+}
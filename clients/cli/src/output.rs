@@ -0,0 +1,42 @@
+use clap::ValueEnum;
+use serde_json::{json, Map, Value};
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    /// `key: value` lines
+    Display,
+    /// A single JSON object, for scripting
+    Json,
+}
+
+impl OutputFormat {
+    pub fn print(self, output: &Map<String, Value>) {
+        match self {
+            Self::Display => {
+                for (key, value) in output {
+                    match value {
+                        Value::String(value) => println!("{key}: {value}"),
+                        Value::Array(values) => {
+                            println!("{key}:");
+                            for value in values {
+                                match value {
+                                    Value::String(value) => println!("  {value}"),
+                                    value => println!("  {value}"),
+                                }
+                            }
+                        }
+                        value => println!("{key}: {value}"),
+                    }
+                }
+            }
+            Self::Json => println!("{}", Value::Object(output.clone())),
+        }
+    }
+
+    pub fn print_error(self, err: &anyhow::Error) {
+        match self {
+            Self::Display => eprintln!("Error: {err:#}"),
+            Self::Json => println!("{}", json!({ "error": format!("{err:#}") })),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Decoders for fetched program accounts.
+
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::state::{
+    CollectionEntry, Config, LiquidPool, Pool, PositionV4, RedemptionTicket, UserAccountV3,
+    UserCollectionStats, UserPoolStatsAccount,
+};
+
+/// Decodes an account of a known type from its raw data, checking the
+/// discriminator
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+/// Any account owned by the staking program
+#[derive(Debug)]
+pub enum StakingAccount {
+    Config(Config),
+    Pool(Pool),
+    Position(PositionV4),
+    UserAccount(UserAccountV3),
+    UserPoolStats(UserPoolStatsAccount),
+    CollectionEntry(CollectionEntry),
+    UserCollectionStats(UserCollectionStats),
+    LiquidPool(LiquidPool),
+    RedemptionTicket(RedemptionTicket),
+}
+
+impl StakingAccount {
+    /// Decodes a program account by its discriminator
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let discriminator = data
+            .get(..8)
+            .ok_or(ErrorCode::AccountDiscriminatorNotFound)?;
+
+        let account = match discriminator {
+            d if d == Config::DISCRIMINATOR => Self::Config(decode(data)?),
+            d if d == Pool::DISCRIMINATOR => Self::Pool(decode(data)?),
+            d if d == PositionV4::DISCRIMINATOR => Self::Position(decode(data)?),
+            d if d == UserAccountV3::DISCRIMINATOR => Self::UserAccount(decode(data)?),
+            d if d == UserPoolStatsAccount::DISCRIMINATOR => Self::UserPoolStats(decode(data)?),
+            d if d == CollectionEntry::DISCRIMINATOR => Self::CollectionEntry(decode(data)?),
+            d if d == UserCollectionStats::DISCRIMINATOR => {
+                Self::UserCollectionStats(decode(data)?)
+            }
+            d if d == LiquidPool::DISCRIMINATOR => Self::LiquidPool(decode(data)?),
+            d if d == RedemptionTicket::DISCRIMINATOR => Self::RedemptionTicket(decode(data)?),
+            _ => return Err(ErrorCode::AccountDiscriminatorMismatch.into()),
+        };
+
+        Ok(account)
+    }
+}
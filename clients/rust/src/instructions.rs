@@ -0,0 +1,1046 @@
+//! Instruction builders for every program entrypoint.
+//!
+//! Builders live on [`ConfigKeys`], which holds the addresses shared by every
+//! instruction under one config. Pools are addressed by index and positions by
+//! the id they were opened with, see [`crate::pda::find_position_pda`].
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, system_program, sysvar},
+    InstructionData,
+};
+use anchor_spl::{associated_token, associated_token::get_associated_token_address, token};
+use bert_staking_sc::{accounts, instruction};
+
+use crate::{
+    pda::*,
+    state::{AllowlistProof, Config, PoolConfigArgs},
+    ID,
+};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Addresses of a config and the accounts it was initialized with
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConfigKeys {
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub collection: Pubkey,
+    pub receipt_collection: Pubkey,
+}
+
+impl ConfigKeys {
+    /// Keys of the config `authority` creates with `id`, before its receipt
+    /// collection is set
+    pub fn new(authority: Pubkey, id: u64, mint: Pubkey, collection: Pubkey) -> Self {
+        Self {
+            config: find_config_pda(&authority, id).0,
+            authority,
+            mint,
+            collection,
+            receipt_collection: Pubkey::default(),
+        }
+    }
+
+    /// Keys of a fetched config account
+    pub fn from_account(config: Pubkey, account: &Config) -> Self {
+        Self {
+            config,
+            authority: account.authority,
+            mint: account.mint,
+            collection: account.collection,
+            receipt_collection: account.receipt_collection,
+        }
+    }
+
+    pub fn pool(&self, index: u32) -> Pubkey {
+        find_pool_pda(&self.config, index).0
+    }
+
+    pub fn user_account(&self, owner: &Pubkey) -> Pubkey {
+        find_user_account_pda(owner, &self.config).0
+    }
+
+    pub fn user_pool_stats(&self, owner: &Pubkey, index: u32) -> Pubkey {
+        find_user_pool_stats_pda(owner, &self.pool(index)).0
+    }
+
+    pub fn position(&self, owner: &Pubkey, index: u32, id: u64) -> Pubkey {
+        find_position_pda(owner, &self.pool(index), id).0
+    }
+
+    pub fn collection_entry(&self, collection: &Pubkey) -> Pubkey {
+        find_collection_entry_pda(&self.config, collection).0
+    }
+
+    pub fn user_collection_stats(&self, owner: &Pubkey, collection: &Pubkey) -> Pubkey {
+        find_user_collection_stats_pda(owner, &self.collection_entry(collection)).0
+    }
+
+    /// Principal vault, the config's associated token account
+    pub fn vault(&self) -> Pubkey {
+        get_associated_token_address(&self.config, &self.mint)
+    }
+
+    pub fn authority_vault(&self) -> Pubkey {
+        find_authority_vault_pda(&self.config, &self.mint).0
+    }
+
+    /// Staking mint token account of `owner`
+    pub fn token_account(&self, owner: &Pubkey) -> Pubkey {
+        get_associated_token_address(owner, &self.mint)
+    }
+
+    /// Receipt collection and core program, passed along with a receipt. The
+    /// collection is left out while the config has none, so the program
+    /// reports it as not initialized.
+    fn receipt_accounts(&self, receipt: Option<Pubkey>) -> (Option<Pubkey>, Option<Pubkey>) {
+        match receipt {
+            Some(_) => (
+                (self.receipt_collection != Pubkey::default()).then_some(self.receipt_collection),
+                Some(mpl_core::ID),
+            ),
+            None => (None, None),
+        }
+    }
+
+    /// Creates the config. `id` must be the one these keys were derived with.
+    pub fn initialize(
+        &self,
+        id: u64,
+        admin_withdraw_destination: Pubkey,
+        max_cap: u64,
+        nft_value_in_tokens: u64,
+        nfts_limit_per_user: u8,
+    ) -> Instruction {
+        build(
+            accounts::Initialize {
+                authority: self.authority,
+                config: self.config,
+                mint: self.mint,
+                collection: self.collection,
+                vault: self.vault(),
+                nfts_vault: self.config,
+                admin_withdraw_destination,
+                system_program: system_program::ID,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+            },
+            instruction::Initialize {
+                id,
+                max_cap,
+                nft_value_in_tokens,
+                nfts_limit_per_user,
+            },
+        )
+    }
+
+    pub fn initialize_auth_vault(&self) -> Instruction {
+        build(
+            accounts::InitializeAuthVault {
+                authority: self.authority,
+                config: self.config,
+                mint: self.mint,
+                authority_vault: self.authority_vault(),
+                system_program: system_program::ID,
+                token_program: token::ID,
+            },
+            instruction::InitializeAuthVault {},
+        )
+    }
+
+    /// Creates the receipt collection at `receipt_collection`, which must
+    /// also sign
+    pub fn initialize_receipt_collection(
+        &self,
+        receipt_collection: Pubkey,
+        name: String,
+        uri: String,
+    ) -> Instruction {
+        build(
+            accounts::InitializeReceiptCollection {
+                authority: self.authority,
+                config: self.config,
+                receipt_collection,
+                core_program: mpl_core::ID,
+                system_program: system_program::ID,
+            },
+            instruction::InitializeReceiptCollection { name, uri },
+        )
+    }
+
+    pub fn initialize_user(&self, owner: Pubkey, index: u32) -> Instruction {
+        build(
+            accounts::InitializeUser {
+                owner,
+                config: self.config,
+                pool: self.pool(index),
+                user_account: self.user_account(&owner),
+                mint: self.mint,
+                system_program: system_program::ID,
+            },
+            instruction::InitiateUser {},
+        )
+    }
+
+    pub fn set_delegate(&self, owner: Pubkey, delegate: Option<Pubkey>) -> Instruction {
+        build(
+            accounts::SetDelegate {
+                owner,
+                config: self.config,
+                user_account: self.user_account(&owner),
+            },
+            instruction::SetDelegate { delegate },
+        )
+    }
+
+    pub fn initialize_pool(
+        &self,
+        index: u32,
+        lock_period_days: u16,
+        yield_rate: u64,
+        max_nfts_cap: u32,
+        max_tokens_cap: u64,
+        max_value_cap: u64,
+    ) -> Instruction {
+        build(
+            accounts::InitializePool {
+                authority: self.authority,
+                config: self.config,
+                pool: self.pool(index),
+                system_program: system_program::ID,
+            },
+            instruction::InitializePool {
+                index,
+                lock_period_days,
+                yield_rate,
+                max_nfts_cap,
+                max_tokens_cap,
+                max_value_cap,
+            },
+        )
+    }
+
+    pub fn initialize_liquid_pool(&self, index: u32, unbonding_period_days: u16) -> Instruction {
+        let pool = self.pool(index);
+        build(
+            accounts::InitializeLiquidPool {
+                authority: self.authority,
+                config: self.config,
+                pool,
+                liquid_pool: find_liquid_pool_pda(&pool).0,
+                share_mint: find_share_mint_pda(&pool).0,
+                reserve: find_liquid_reserve_pda(&pool).0,
+                mint: self.mint,
+                token_program: token::ID,
+                system_program: system_program::ID,
+            },
+            instruction::InitializeLiquidPool {
+                unbonding_period_days,
+            },
+        )
+    }
+
+    /// Stakes `asset` into a new position with id `position_id`. Pass a fresh
+    /// `receipt` address, which must also sign, to mint a position receipt.
+    #[allow(clippy::too_many_arguments)]
+    pub fn stake_nft(
+        &self,
+        owner: Pubkey,
+        index: u32,
+        position_id: u64,
+        asset: Pubkey,
+        collection: Pubkey,
+        lock_period_days: Option<u16>,
+        allowlist: Option<AllowlistProof>,
+        receipt: Option<Pubkey>,
+    ) -> Instruction {
+        let pool = self.pool(index);
+        let collection_entry = self.collection_entry(&collection);
+        let (receipt_collection, _) = self.receipt_accounts(receipt);
+
+        build(
+            accounts::StakeNFT {
+                owner,
+                config: self.config,
+                pool,
+                user_account: self.user_account(&owner),
+                user_pool_stats: find_user_pool_stats_pda(&owner, &pool).0,
+                position: find_position_pda(&owner, &pool, position_id).0,
+                asset,
+                nft_vault_owner: self.config,
+                collection,
+                collection_entry,
+                user_collection_stats: find_user_collection_stats_pda(&owner, &collection_entry).0,
+                core_program: mpl_core::ID,
+                mint: self.mint,
+                receipt,
+                receipt_collection,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            instruction::StakeNft {
+                lock_period_days,
+                allowlist,
+            },
+        )
+    }
+
+    /// Stakes `amount` into a new position with id `position_id`. Pass a
+    /// fresh `receipt` address, which must also sign, to mint a position
+    /// receipt.
+    #[allow(clippy::too_many_arguments)]
+    pub fn stake_token(
+        &self,
+        owner: Pubkey,
+        index: u32,
+        position_id: u64,
+        amount: u64,
+        lock_period_days: Option<u16>,
+        allowlist: Option<AllowlistProof>,
+        receipt: Option<Pubkey>,
+    ) -> Instruction {
+        let pool = self.pool(index);
+        let (receipt_collection, core_program) = self.receipt_accounts(receipt);
+
+        build(
+            accounts::StakeToken {
+                owner,
+                config: self.config,
+                pool,
+                user_account: self.user_account(&owner),
+                user_pool_stats: find_user_pool_stats_pda(&owner, &pool).0,
+                position: find_position_pda(&owner, &pool, position_id).0,
+                mint: self.mint,
+                token_account: self.token_account(&owner),
+                vault: self.vault(),
+                receipt,
+                receipt_collection,
+                core_program,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            instruction::StakeToken {
+                amount,
+                lock_period_days,
+                allowlist,
+            },
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn claim_nft_accounts(
+        &self,
+        claimant: Pubkey,
+        owner: Pubkey,
+        payer: Pubkey,
+        index: u32,
+        position: Pubkey,
+        asset: Pubkey,
+        collection: Pubkey,
+        collection_counted: bool,
+        update_authority: Pubkey,
+        receipt: Option<Pubkey>,
+    ) -> accounts::ClaimPositionNft {
+        let pool = self.pool(index);
+        let collection_entry = collection_counted.then(|| self.collection_entry(&collection));
+        let (receipt_collection, _) = self.receipt_accounts(receipt);
+
+        accounts::ClaimPositionNft {
+            claimant,
+            owner,
+            payer,
+            config: self.config,
+            pool,
+            user_account: self.user_account(&owner),
+            user_pool_stats: find_user_pool_stats_pda(&owner, &pool).0,
+            position,
+            collection,
+            collection_entry,
+            user_collection_stats: collection_entry
+                .map(|entry| find_user_collection_stats_pda(&owner, &entry).0),
+            update_authority,
+            asset,
+            mint: self.mint,
+            token_account: self.token_account(&owner),
+            vault: self.vault(),
+            authority_vault: self.authority_vault(),
+            receipt,
+            receipt_collection,
+            core_program: mpl_core::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        }
+    }
+
+    /// Claims an NFT position. `claimant` is the owner, their delegate or the
+    /// holder of the position's `receipt`, and `payer` funds the owner's
+    /// collection stats if they were closed. `collection_counted` is the
+    /// position's flag of the same name; positions staked before collections
+    /// were registered are claimed without the registry entry.
+    /// `update_authority` is the collection's update authority.
+    #[allow(clippy::too_many_arguments)]
+    pub fn claim_position_nft(
+        &self,
+        claimant: Pubkey,
+        owner: Pubkey,
+        payer: Pubkey,
+        index: u32,
+        position: Pubkey,
+        asset: Pubkey,
+        collection: Pubkey,
+        collection_counted: bool,
+        update_authority: Pubkey,
+        receipt: Option<Pubkey>,
+    ) -> Instruction {
+        build(
+            self.claim_nft_accounts(
+                claimant,
+                owner,
+                payer,
+                index,
+                position,
+                asset,
+                collection,
+                collection_counted,
+                update_authority,
+                receipt,
+            ),
+            instruction::ClaimPositionNft {},
+        )
+    }
+
+    /// Returns an NFT position's asset without yield while the config is in
+    /// emergency mode. Takes the same accounts as
+    /// [`ConfigKeys::claim_position_nft`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn emergency_withdraw_nft(
+        &self,
+        claimant: Pubkey,
+        owner: Pubkey,
+        payer: Pubkey,
+        index: u32,
+        position: Pubkey,
+        asset: Pubkey,
+        collection: Pubkey,
+        collection_counted: bool,
+        update_authority: Pubkey,
+        receipt: Option<Pubkey>,
+    ) -> Instruction {
+        build(
+            self.claim_nft_accounts(
+                claimant,
+                owner,
+                payer,
+                index,
+                position,
+                asset,
+                collection,
+                collection_counted,
+                update_authority,
+                receipt,
+            ),
+            instruction::EmergencyWithdrawNft {},
+        )
+    }
+
+    fn claim_token_accounts(
+        &self,
+        claimant: Pubkey,
+        owner: Pubkey,
+        index: u32,
+        position: Pubkey,
+        receipt: Option<Pubkey>,
+    ) -> accounts::ClaimPositionToken {
+        let pool = self.pool(index);
+        let (receipt_collection, core_program) = self.receipt_accounts(receipt);
+
+        accounts::ClaimPositionToken {
+            claimant,
+            owner,
+            config: self.config,
+            pool,
+            user_account: self.user_account(&owner),
+            user_pool_stats: find_user_pool_stats_pda(&owner, &pool).0,
+            position,
+            collection: self.collection,
+            mint: self.mint,
+            token_account: self.token_account(&owner),
+            vault: self.vault(),
+            authority_vault: self.authority_vault(),
+            receipt,
+            receipt_collection,
+            core_program,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        }
+    }
+
+    /// Claims a token position. `claimant` is the owner, their delegate or the
+    /// holder of the position's `receipt`.
+    pub fn claim_position_token(
+        &self,
+        claimant: Pubkey,
+        owner: Pubkey,
+        index: u32,
+        position: Pubkey,
+        receipt: Option<Pubkey>,
+    ) -> Instruction {
+        build(
+            self.claim_token_accounts(claimant, owner, index, position, receipt),
+            instruction::ClaimPositionToken {},
+        )
+    }
+
+    pub fn claim_position_token_partial(
+        &self,
+        claimant: Pubkey,
+        owner: Pubkey,
+        index: u32,
+        position: Pubkey,
+        receipt: Option<Pubkey>,
+        amount: u64,
+    ) -> Instruction {
+        build(
+            self.claim_token_accounts(claimant, owner, index, position, receipt),
+            instruction::ClaimPositionTokenPartial { amount },
+        )
+    }
+
+    /// Returns a token position's principal without yield while the config
+    /// is in emergency mode. `claimant` is as for
+    /// [`ConfigKeys::claim_position_token`].
+    pub fn emergency_withdraw_token(
+        &self,
+        claimant: Pubkey,
+        owner: Pubkey,
+        index: u32,
+        position: Pubkey,
+        receipt: Option<Pubkey>,
+    ) -> Instruction {
+        build(
+            self.claim_token_accounts(claimant, owner, index, position, receipt),
+            instruction::EmergencyWithdrawToken {},
+        )
+    }
+
+    pub fn crank_claim(
+        &self,
+        cranker: Pubkey,
+        owner: Pubkey,
+        index: u32,
+        position: Pubkey,
+    ) -> Instruction {
+        let pool = self.pool(index);
+        build(
+            accounts::CrankClaim {
+                cranker,
+                owner,
+                config: self.config,
+                pool,
+                user_account: self.user_account(&owner),
+                user_pool_stats: find_user_pool_stats_pda(&owner, &pool).0,
+                position,
+                mint: self.mint,
+                token_account: self.token_account(&owner),
+                vault: self.vault(),
+                authority_vault: self.authority_vault(),
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+            },
+            instruction::CrankClaim {},
+        )
+    }
+
+    pub fn request_unstake(
+        &self,
+        claimant: Pubkey,
+        owner: Pubkey,
+        index: u32,
+        position: Pubkey,
+        receipt: Option<Pubkey>,
+    ) -> Instruction {
+        build(
+            accounts::RequestUnstake {
+                claimant,
+                config: self.config,
+                pool: self.pool(index),
+                position,
+                user_account: self.user_account(&owner),
+                receipt,
+            },
+            instruction::RequestUnstake {},
+        )
+    }
+
+    /// Relocks a position. `authority` is the owner or their delegate.
+    /// Passing a target pool index moves it there under the owner's next
+    /// position id, which goes alongside.
+    pub fn extend_lock(
+        &self,
+        authority: Pubkey,
+        owner: Pubkey,
+        index: u32,
+        target: Option<(u32, u64)>,
+        position: Pubkey,
+        lock_period_days: Option<u16>,
+    ) -> Instruction {
+        let pool = self.pool(index);
+        let target_pool = target.map(|(index, _)| self.pool(index));
+        build(
+            accounts::ExtendLock {
+                authority,
+                owner,
+                config: self.config,
+                pool,
+                target_pool,
+                user_account: self.user_account(&owner),
+                user_pool_stats: find_user_pool_stats_pda(&owner, &pool).0,
+                target_user_pool_stats: target_pool
+                    .map(|target_pool| find_user_pool_stats_pda(&owner, &target_pool).0),
+                position,
+                new_position: target.map(|(index, id)| self.position(&owner, index, id)),
+                mint: self.mint,
+                token_account: self.token_account(&owner),
+                authority_vault: self.authority_vault(),
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+            },
+            instruction::ExtendLock { lock_period_days },
+        )
+    }
+
+    /// Moves a position to `new_owner`, who must also sign, under their next
+    /// position id. NFT positions also pass their asset, and their collection
+    /// if the position is `collection_counted`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn transfer_position(
+        &self,
+        owner: Pubkey,
+        new_owner: Pubkey,
+        index: u32,
+        position: Pubkey,
+        new_position_id: u64,
+        asset: Option<Pubkey>,
+        collection: Option<Pubkey>,
+    ) -> Instruction {
+        let pool = self.pool(index);
+        let collection_entry = collection.map(|collection| self.collection_entry(&collection));
+
+        build(
+            accounts::TransferPosition {
+                owner,
+                new_owner,
+                config: self.config,
+                pool,
+                user_account: self.user_account(&owner),
+                new_user_account: self.user_account(&new_owner),
+                user_pool_stats: find_user_pool_stats_pda(&owner, &pool).0,
+                new_user_pool_stats: find_user_pool_stats_pda(&new_owner, &pool).0,
+                position,
+                new_position: find_position_pda(&new_owner, &pool, new_position_id).0,
+                asset,
+                collection_entry,
+                user_collection_stats: collection_entry
+                    .map(|entry| find_user_collection_stats_pda(&owner, &entry).0),
+                new_user_collection_stats: collection_entry
+                    .map(|entry| find_user_collection_stats_pda(&new_owner, &entry).0),
+                system_program: system_program::ID,
+            },
+            instruction::TransferPosition {},
+        )
+    }
+
+    pub fn stake_liquid(&self, owner: Pubkey, index: u32, amount: u64) -> Instruction {
+        let pool = self.pool(index);
+        let share_mint = find_share_mint_pda(&pool).0;
+        build(
+            accounts::StakeLiquid {
+                owner,
+                config: self.config,
+                pool,
+                liquid_pool: find_liquid_pool_pda(&pool).0,
+                share_mint,
+                mint: self.mint,
+                token_account: self.token_account(&owner),
+                share_account: get_associated_token_address(&owner, &share_mint),
+                vault: self.vault(),
+                reserve: find_liquid_reserve_pda(&pool).0,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+            },
+            instruction::StakeLiquid { amount },
+        )
+    }
+
+    /// Burns `shares` for a redemption ticket. `id` is the liquid pool's
+    /// `next_ticket_id`, which the program assigns the ticket.
+    pub fn request_redeem(&self, owner: Pubkey, index: u32, id: u64, shares: u64) -> Instruction {
+        let pool = self.pool(index);
+        let share_mint = find_share_mint_pda(&pool).0;
+        build(
+            accounts::RequestRedeem {
+                owner,
+                config: self.config,
+                pool,
+                liquid_pool: find_liquid_pool_pda(&pool).0,
+                share_mint,
+                share_account: get_associated_token_address(&owner, &share_mint),
+                ticket: find_redemption_pda(&owner, &pool, id).0,
+                mint: self.mint,
+                vault: self.vault(),
+                reserve: find_liquid_reserve_pda(&pool).0,
+                token_program: token::ID,
+                system_program: system_program::ID,
+            },
+            instruction::RequestRedeem { shares },
+        )
+    }
+
+    pub fn redeem(&self, owner: Pubkey, index: u32, id: u64) -> Instruction {
+        let pool = self.pool(index);
+        build(
+            accounts::Redeem {
+                owner,
+                config: self.config,
+                pool,
+                liquid_pool: find_liquid_pool_pda(&pool).0,
+                ticket: find_redemption_pda(&owner, &pool, id).0,
+                mint: self.mint,
+                token_account: self.token_account(&owner),
+                vault: self.vault(),
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+            },
+            instruction::Redeem {},
+        )
+    }
+
+    /// Moves `amount` of a token position into a new position with id
+    /// `new_position_id`
+    pub fn split_position(
+        &self,
+        owner: Pubkey,
+        index: u32,
+        position: Pubkey,
+        new_position_id: u64,
+        amount: u64,
+    ) -> Instruction {
+        let pool = self.pool(index);
+        build(
+            accounts::SplitPosition {
+                owner,
+                config: self.config,
+                pool,
+                user_account: self.user_account(&owner),
+                position,
+                new_position: find_position_pda(&owner, &pool, new_position_id).0,
+                system_program: system_program::ID,
+            },
+            instruction::SplitPosition { amount },
+        )
+    }
+
+    /// Folds `other_position` into `position`
+    pub fn merge_positions(
+        &self,
+        owner: Pubkey,
+        index: u32,
+        position: Pubkey,
+        other_position: Pubkey,
+    ) -> Instruction {
+        build(
+            accounts::MergePositions {
+                owner,
+                config: self.config,
+                pool: self.pool(index),
+                user_account: self.user_account(&owner),
+                position,
+                other_position,
+            },
+            instruction::MergePositions {},
+        )
+    }
+
+    /// Re-keys a legacy position in `pool` under the owner's next position id
+    pub fn migrate_position_seeds(
+        &self,
+        owner: Pubkey,
+        pool: Pubkey,
+        position: Pubkey,
+        new_position_id: u64,
+    ) -> Instruction {
+        build(
+            accounts::MigratePositionSeeds {
+                owner,
+                config: self.config,
+                user_account: self.user_account(&owner),
+                position,
+                new_position: find_position_pda(&owner, &pool, new_position_id).0,
+                mint: self.mint,
+                system_program: system_program::ID,
+            },
+            instruction::MigratePositionSeeds {},
+        )
+    }
+
+    fn migrate_accounts(
+        payer: Pubkey,
+        account: Pubkey,
+        config: Option<Pubkey>,
+    ) -> accounts::MigrateAccount {
+        accounts::MigrateAccount {
+            payer,
+            account,
+            config,
+            system_program: system_program::ID,
+        }
+    }
+
+    /// Brings the config up to the current layout version
+    pub fn migrate_config(&self, payer: Pubkey) -> Instruction {
+        build(
+            Self::migrate_accounts(payer, self.config, None),
+            instruction::MigrateConfig {},
+        )
+    }
+
+    /// Brings a pool up to the current layout version, with `payer` funding
+    /// the space it grows by. The config has to be migrated first.
+    pub fn migrate_pool(&self, payer: Pubkey, index: u32) -> Instruction {
+        build(
+            Self::migrate_accounts(payer, self.pool(index), Some(self.config)),
+            instruction::MigratePool {},
+        )
+    }
+
+    /// Brings a position up to the current layout version
+    pub fn migrate_position(&self, payer: Pubkey, position: Pubkey) -> Instruction {
+        build(
+            Self::migrate_accounts(payer, position, None),
+            instruction::MigratePosition {},
+        )
+    }
+
+    /// Brings `owner`'s user account up to the current layout version
+    pub fn migrate_user_account(&self, payer: Pubkey, owner: Pubkey) -> Instruction {
+        build(
+            Self::migrate_accounts(payer, self.user_account(&owner), None),
+            instruction::MigrateUserAccount {},
+        )
+    }
+
+    /// Projects a position's pending and unlock yield. Simulate it and decode
+    /// the return data as a [`crate::state::PositionYieldView`].
+    pub fn view_position_yield(&self, owner: Pubkey, index: u32, position: Pubkey) -> Instruction {
+        let pool = self.pool(index);
+        build(
+            accounts::ViewPositionYield {
+                config: self.config,
+                pool,
+                user_pool_stats: find_user_pool_stats_pda(&owner, &pool).0,
+                position,
+            },
+            instruction::ViewPositionYield {},
+        )
+    }
+
+    /// Reports the room left under a pool's value cap. Simulate it and decode
+    /// the return data as a [`crate::state::PoolCapacityView`].
+    pub fn view_pool_capacity(&self, index: u32) -> Instruction {
+        build(
+            accounts::ViewPoolCapacity {
+                config: self.config,
+                pool: self.pool(index),
+            },
+            instruction::ViewPoolCapacity {},
+        )
+    }
+
+    /// Reports the room left for `owner` in a pool, and in `collection` if
+    /// one is passed. Simulate it and decode the return data as a
+    /// [`crate::state::UserCapacityView`].
+    pub fn view_user_capacity(
+        &self,
+        owner: Pubkey,
+        index: u32,
+        collection: Option<Pubkey>,
+    ) -> Instruction {
+        let pool = self.pool(index);
+        let collection_entry = collection.map(|collection| self.collection_entry(&collection));
+
+        build(
+            accounts::ViewUserCapacity {
+                owner,
+                config: self.config,
+                pool,
+                user_account: self.user_account(&owner),
+                user_pool_stats: find_user_pool_stats_pda(&owner, &pool).0,
+                collection_entry,
+                user_collection_stats: collection
+                    .map(|collection| self.user_collection_stats(&owner, &collection)),
+            },
+            instruction::ViewUserCapacity {},
+        )
+    }
+
+    fn pool_config_accounts(&self, index: u32) -> accounts::AdminSetPoolConfig {
+        accounts::AdminSetPoolConfig {
+            authority: self.authority,
+            config: self.config,
+            pool: self.pool(index),
+        }
+    }
+
+    pub fn admin_pause_pool(&self, index: u32) -> Instruction {
+        build(
+            self.pool_config_accounts(index),
+            instruction::AdminPausePool {},
+        )
+    }
+
+    pub fn admin_activate_pool(&self, index: u32) -> Instruction {
+        build(
+            self.pool_config_accounts(index),
+            instruction::AdminActivatePool {},
+        )
+    }
+
+    /// Halts stakes across all pools and opens emergency withdrawals
+    pub fn admin_enable_emergency(&self) -> Instruction {
+        build(
+            accounts::AdminSetEmergency {
+                authority: self.authority,
+                config: self.config,
+            },
+            instruction::AdminEnableEmergency {},
+        )
+    }
+
+    pub fn admin_disable_emergency(&self) -> Instruction {
+        build(
+            accounts::AdminSetEmergency {
+                authority: self.authority,
+                config: self.config,
+            },
+            instruction::AdminDisableEmergency {},
+        )
+    }
+
+    pub fn admin_set_merkle_root(&self, index: u32, merkle_root: [u8; 32]) -> Instruction {
+        build(
+            self.pool_config_accounts(index),
+            instruction::AdminSetMerkleRoot { merkle_root },
+        )
+    }
+
+    pub fn admin_set_pool_config(&self, index: u32, config_params: PoolConfigArgs) -> Instruction {
+        build(
+            self.pool_config_accounts(index),
+            instruction::AdminSetPoolConfig { config_params },
+        )
+    }
+
+    /// Withdraws from the authority vault to the config's withdraw
+    /// destination
+    pub fn admin_withdraw_tokens(
+        &self,
+        admin_withdraw_destination: Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        build(
+            accounts::AdminWithdrawToken {
+                authority: self.authority,
+                config: self.config,
+                authority_vault: self.authority_vault(),
+                admin_withdraw_destination: get_associated_token_address(
+                    &admin_withdraw_destination,
+                    &self.mint,
+                ),
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+            },
+            instruction::AdminWithdrawTokens { amount },
+        )
+    }
+
+    pub fn admin_set_crank_tip(&self, tip_lamports: u64) -> Instruction {
+        build(
+            accounts::AdminSetCrankTip {
+                authority: self.authority,
+                config: self.config,
+            },
+            instruction::AdminSetCrankTip { tip_lamports },
+        )
+    }
+
+    pub fn admin_add_collection(
+        &self,
+        collection: Pubkey,
+        nft_value_in_tokens: u64,
+        nfts_limit_per_user: u8,
+    ) -> Instruction {
+        build(
+            accounts::AdminAddCollection {
+                authority: self.authority,
+                config: self.config,
+                collection,
+                collection_entry: self.collection_entry(&collection),
+                system_program: system_program::ID,
+            },
+            instruction::AdminAddCollection {
+                nft_value_in_tokens,
+                nfts_limit_per_user,
+            },
+        )
+    }
+
+    pub fn admin_update_collection(
+        &self,
+        collection: Pubkey,
+        nft_value_in_tokens: u64,
+        nfts_limit_per_user: u8,
+        is_active: bool,
+    ) -> Instruction {
+        build(
+            accounts::AdminUpdateCollection {
+                authority: self.authority,
+                config: self.config,
+                collection_entry: self.collection_entry(&collection),
+            },
+            instruction::AdminUpdateCollection {
+                nft_value_in_tokens,
+                nfts_limit_per_user,
+                is_active,
+            },
+        )
+    }
+
+    pub fn admin_set_position_limit(&self, max_positions_per_user: u16) -> Instruction {
+        build(
+            accounts::AdminSetPositionLimit {
+                authority: self.authority,
+                config: self.config,
+            },
+            instruction::AdminSetPositionLimit {
+                max_positions_per_user,
+            },
+        )
+    }
+}
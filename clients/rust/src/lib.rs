@@ -0,0 +1,13 @@
+//! Rust client for the BERT staking program.
+//!
+//! Re-exports the program's account types and provides PDA derivation,
+//! instruction builders for every entrypoint and decoders for fetched
+//! accounts. Sending transactions is left to the caller.
+
+pub mod accounts;
+pub mod instructions;
+pub mod pda;
+
+pub use accounts::StakingAccount;
+pub use bert_staking_sc::{state, StakingError, ID};
+pub use instructions::ConfigKeys;
@@ -0,0 +1,111 @@
+//! PDA derivation for the staking program's accounts. Each helper returns the
+//! address and its bump.
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::ID;
+
+/// Config PDA for an authority and config id
+pub fn find_config_pda(authority: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config", authority.as_ref(), &id.to_le_bytes()], &ID)
+}
+
+/// Pool PDA for a config and pool index
+pub fn find_pool_pda(config: &Pubkey, index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"pool", config.as_ref(), &index.to_le_bytes()], &ID)
+}
+
+/// User account PDA of an owner under a config
+pub fn find_user_account_pda(owner: &Pubkey, config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"user", owner.as_ref(), config.as_ref()], &ID)
+}
+
+/// Per-pool stats PDA of an owner
+pub fn find_user_pool_stats_pda(owner: &Pubkey, pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"user_pool_stats", owner.as_ref(), pool.as_ref()], &ID)
+}
+
+/// Position PDA, derived as `[position, owner, pool, id]`. The id is the
+/// owner's `UserAccountV3::next_position_id` when the position is opened.
+pub fn find_position_pda(owner: &Pubkey, pool: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"position",
+            owner.as_ref(),
+            pool.as_ref(),
+            &id.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+/// Position PDA under the legacy seeds: `[position, owner, mint, id]` for
+/// token positions and `[position, owner, mint, asset, id]` for NFT positions
+pub fn find_legacy_position_pda(
+    owner: &Pubkey,
+    mint: &Pubkey,
+    asset: Option<&Pubkey>,
+    id: u64,
+) -> (Pubkey, u8) {
+    let asset_seed: &[u8] = asset.map(|asset| asset.as_ref()).unwrap_or_default();
+    Pubkey::find_program_address(
+        &[
+            b"position",
+            owner.as_ref(),
+            mint.as_ref(),
+            asset_seed,
+            &id.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+/// Yield vault PDA of a config
+pub fn find_authority_vault_pda(config: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"authority_vault", config.as_ref(), mint.as_ref()], &ID)
+}
+
+/// Registry entry PDA of an NFT collection under a config
+pub fn find_collection_entry_pda(config: &Pubkey, collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"collection", config.as_ref(), collection.as_ref()], &ID)
+}
+
+/// Per-collection stats PDA of an owner
+pub fn find_user_collection_stats_pda(owner: &Pubkey, collection_entry: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"user_collection_stats",
+            owner.as_ref(),
+            collection_entry.as_ref(),
+        ],
+        &ID,
+    )
+}
+
+/// Share accounting PDA of a liquid pool
+pub fn find_liquid_pool_pda(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"liquid_pool", pool.as_ref()], &ID)
+}
+
+/// Share mint PDA of a liquid pool
+pub fn find_share_mint_pda(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"share_mint", pool.as_ref()], &ID)
+}
+
+/// Yield reserve PDA of a liquid pool
+pub fn find_liquid_reserve_pda(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"liquid_reserve", pool.as_ref()], &ID)
+}
+
+/// Redemption ticket PDA of an owner in a liquid pool
+pub fn find_redemption_pda(owner: &Pubkey, pool: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"redemption",
+            owner.as_ref(),
+            pool.as_ref(),
+            &id.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
@@ -0,0 +1,339 @@
+//! Checks every instruction builder against the program IDL: the
+//! discriminator, the account order and each account's writable and signer
+//! flags. Optional accounts left out are passed as the program id.
+
+use std::path::Path;
+
+use anchor_lang::{prelude::Pubkey, solana_program::instruction::Instruction};
+use bert_staking_client::{
+    state::{LockMode, PoolConfigArgs},
+    ConfigKeys, ID,
+};
+use serde_json::Value;
+
+fn idl() -> Value {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../sdk/src/idl.json");
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+/// Asserts that `ix` matches the IDL instruction `name`
+fn assert_matches_idl(idl: &Value, name: &str, ix: &Instruction) {
+    let idl_ix = idl["instructions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|ix| ix["name"] == name)
+        .unwrap_or_else(|| panic!("{name} is not in the IDL"));
+
+    let discriminator: Vec<u8> = serde_json::from_value(idl_ix["discriminator"].clone()).unwrap();
+    assert_eq!(ix.program_id, ID, "{name}: program id");
+    assert_eq!(ix.data[..8], discriminator[..], "{name}: discriminator");
+
+    let accounts = idl_ix["accounts"].as_array().unwrap();
+    assert_eq!(ix.accounts.len(), accounts.len(), "{name}: account count");
+
+    for (meta, account) in ix.accounts.iter().zip(accounts) {
+        let account_name = account["name"].as_str().unwrap();
+        let flag = |key: &str| account[key].as_bool().unwrap_or(false);
+
+        if flag("optional") && meta.pubkey == ID {
+            continue;
+        }
+
+        assert_eq!(
+            meta.is_writable,
+            flag("writable"),
+            "{name}: {account_name} writable"
+        );
+        assert_eq!(
+            meta.is_signer,
+            flag("signer"),
+            "{name}: {account_name} signer"
+        );
+
+        if let Some(address) = account["address"].as_str() {
+            assert_eq!(
+                meta.pubkey.to_string(),
+                address,
+                "{name}: {account_name} address"
+            );
+        }
+    }
+}
+
+fn keys() -> ConfigKeys {
+    let mut keys = ConfigKeys::new(
+        Pubkey::new_unique(),
+        1,
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    keys.receipt_collection = Pubkey::new_unique();
+    keys
+}
+
+fn pool_config_args() -> PoolConfigArgs {
+    PoolConfigArgs {
+        lock_period_days: 7,
+        yield_rate: 50_000_000,
+        max_nfts_cap: 100,
+        max_tokens_cap: 1_000_000,
+        max_value_cap: 5_000_000,
+        lock_mode: LockMode::Fixed,
+        min_lock_days: 0,
+        max_lock_days: 0,
+        max_boost_bps: 0,
+        nft_boost_bps_per_nft: 0,
+        max_nft_boost_bps: 0,
+        min_stake_amount: 0,
+        max_stake_per_position: 0,
+        unbonding_period_days: 0,
+    }
+}
+
+#[test]
+fn admin_instructions_match_idl() {
+    let idl = idl();
+    let keys = keys();
+    let collection = Pubkey::new_unique();
+
+    // Only the hardcoded admin can initialize a config
+    let admin = "DsqQPGmhhySWUFaWDEDVifLGUfe3DwnZ7MnVJcNW5Ykv"
+        .parse()
+        .unwrap();
+    let admin_keys = ConfigKeys::new(admin, 1, keys.mint, keys.collection);
+
+    let cases = [
+        (
+            "initialize",
+            admin_keys.initialize(1, Pubkey::new_unique(), 1_000, 10, 5),
+        ),
+        ("initialize_auth_vault", keys.initialize_auth_vault()),
+        (
+            "initialize_receipt_collection",
+            keys.initialize_receipt_collection(
+                Pubkey::new_unique(),
+                "Receipts".to_string(),
+                "https://example.com".to_string(),
+            ),
+        ),
+        (
+            "initialize_pool",
+            keys.initialize_pool(0, 7, 50_000_000, 100, 1_000, 5_000),
+        ),
+        ("initialize_liquid_pool", keys.initialize_liquid_pool(0, 3)),
+        ("admin_pause_pool", keys.admin_pause_pool(0)),
+        ("admin_activate_pool", keys.admin_activate_pool(0)),
+        (
+            "admin_set_merkle_root",
+            keys.admin_set_merkle_root(0, [1; 32]),
+        ),
+        (
+            "admin_set_pool_config",
+            keys.admin_set_pool_config(0, pool_config_args()),
+        ),
+        (
+            "admin_withdraw_tokens",
+            keys.admin_withdraw_tokens(Pubkey::new_unique(), 10),
+        ),
+        ("admin_set_crank_tip", keys.admin_set_crank_tip(1_000)),
+        (
+            "admin_add_collection",
+            keys.admin_add_collection(collection, 10, 5),
+        ),
+        (
+            "admin_update_collection",
+            keys.admin_update_collection(collection, 10, 5, false),
+        ),
+        ("admin_set_position_limit", keys.admin_set_position_limit(3)),
+        ("admin_enable_emergency", keys.admin_enable_emergency()),
+        ("admin_disable_emergency", keys.admin_disable_emergency()),
+        ("migrate_config", keys.migrate_config(Pubkey::new_unique())),
+        ("migrate_pool", keys.migrate_pool(Pubkey::new_unique(), 0)),
+        (
+            "migrate_position",
+            keys.migrate_position(Pubkey::new_unique(), Pubkey::new_unique()),
+        ),
+        (
+            "migrate_user_account",
+            keys.migrate_user_account(Pubkey::new_unique(), Pubkey::new_unique()),
+        ),
+    ];
+
+    for (name, ix) in &cases {
+        assert_matches_idl(&idl, name, ix);
+    }
+}
+
+#[test]
+fn staking_instructions_match_idl() {
+    let idl = idl();
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    let position = keys.position(&owner, 0, 0);
+    let asset = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let receipt = Some(Pubkey::new_unique());
+
+    let cases = [
+        ("initiate_user", keys.initialize_user(owner, 0)),
+        ("set_delegate", keys.set_delegate(owner, Some(delegate))),
+        (
+            "stake_token",
+            keys.stake_token(owner, 0, 0, 1_000, None, None, None),
+        ),
+        (
+            "stake_token",
+            keys.stake_token(owner, 0, 0, 1_000, Some(30), None, receipt),
+        ),
+        (
+            "stake_nft",
+            keys.stake_nft(owner, 0, 0, asset, collection, None, None, receipt),
+        ),
+        (
+            "claim_position_token",
+            keys.claim_position_token(delegate, owner, 0, position, None),
+        ),
+        (
+            "claim_position_token_partial",
+            keys.claim_position_token_partial(owner, owner, 0, position, receipt, 10),
+        ),
+        (
+            "claim_position_nft",
+            keys.claim_position_nft(
+                owner,
+                owner,
+                owner,
+                0,
+                position,
+                asset,
+                collection,
+                true,
+                Pubkey::new_unique(),
+                None,
+            ),
+        ),
+        (
+            "claim_position_nft",
+            keys.claim_position_nft(
+                owner,
+                owner,
+                delegate,
+                0,
+                position,
+                asset,
+                collection,
+                false,
+                Pubkey::new_unique(),
+                receipt,
+            ),
+        ),
+        (
+            "emergency_withdraw_token",
+            keys.emergency_withdraw_token(owner, owner, 0, position, receipt),
+        ),
+        (
+            "emergency_withdraw_nft",
+            keys.emergency_withdraw_nft(
+                owner,
+                owner,
+                owner,
+                0,
+                position,
+                asset,
+                collection,
+                true,
+                Pubkey::new_unique(),
+                None,
+            ),
+        ),
+        (
+            "crank_claim",
+            keys.crank_claim(delegate, owner, 0, position),
+        ),
+        (
+            "request_unstake",
+            keys.request_unstake(owner, owner, 0, position, receipt),
+        ),
+        (
+            "extend_lock",
+            keys.extend_lock(delegate, owner, 0, None, position, Some(30)),
+        ),
+        (
+            "extend_lock",
+            keys.extend_lock(owner, owner, 0, Some((1, 1)), position, None),
+        ),
+        (
+            "transfer_position",
+            keys.transfer_position(owner, delegate, 0, position, 0, None, None),
+        ),
+        (
+            "transfer_position",
+            keys.transfer_position(
+                owner,
+                delegate,
+                0,
+                position,
+                0,
+                Some(asset),
+                Some(collection),
+            ),
+        ),
+        (
+            "split_position",
+            keys.split_position(owner, 0, position, 1, 10),
+        ),
+        (
+            "merge_positions",
+            keys.merge_positions(owner, 0, position, keys.position(&owner, 0, 1)),
+        ),
+        (
+            "migrate_position_seeds",
+            keys.migrate_position_seeds(owner, keys.pool(0), position, 1),
+        ),
+        ("stake_liquid", keys.stake_liquid(owner, 0, 1_000)),
+        ("request_redeem", keys.request_redeem(owner, 0, 0, 10)),
+        ("redeem", keys.redeem(owner, 0, 0)),
+        (
+            "view_position_yield",
+            keys.view_position_yield(owner, 0, position),
+        ),
+        ("view_pool_capacity", keys.view_pool_capacity(0)),
+        (
+            "view_user_capacity",
+            keys.view_user_capacity(owner, 0, None),
+        ),
+        (
+            "view_user_capacity",
+            keys.view_user_capacity(owner, 0, Some(collection)),
+        ),
+    ];
+
+    for (name, ix) in &cases {
+        assert_matches_idl(&idl, name, ix);
+    }
+}
+
+#[test]
+fn builders_derive_accounts_from_config_keys() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+
+    let ix = keys.stake_token(owner, 2, 7, 1_000, None, None, None);
+    let pubkeys: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+    assert_eq!(
+        pubkeys[..9],
+        [
+            owner,
+            keys.config,
+            keys.pool(2),
+            keys.user_account(&owner),
+            keys.user_pool_stats(&owner, 2),
+            keys.position(&owner, 2, 7),
+            keys.mint,
+            keys.token_account(&owner),
+            keys.vault(),
+        ]
+    );
+}
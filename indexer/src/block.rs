@@ -0,0 +1,206 @@
+//! Blocks as the indexer consumes them, read from `getBlock` JSON dumps or a
+//! local validator's RPC.
+//!
+//! Transactions must be binary encoded (`"encoding": "base64"`) so their
+//! messages can be decoded without a parsed view.
+
+use std::{fs, path::Path};
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use solana_client::{rpc_client::RpcClient, rpc_config::RpcBlockConfig};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
+use solana_transaction_status::{
+    EncodedTransactionWithStatusMeta, TransactionDetails, UiConfirmedBlock, UiInnerInstructions,
+    UiInstruction, UiLoadedAddresses, UiTransactionEncoding,
+};
+
+/// A confirmed block and its transactions
+#[derive(Clone, Debug)]
+pub struct Block {
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub transactions: Vec<Transaction>,
+}
+
+/// A transaction with its instructions flattened in execution order
+#[derive(Clone, Debug)]
+pub struct Transaction {
+    pub signature: String,
+    pub success: bool,
+    pub invocations: Vec<Invocation>,
+    pub logs: Vec<String>,
+}
+
+/// A top-level or inner instruction with its accounts resolved
+#[derive(Clone, Debug)]
+pub struct Invocation {
+    pub program_id: Pubkey,
+    pub accounts: Vec<Pubkey>,
+    pub data: Vec<u8>,
+}
+
+impl From<solana_sdk::instruction::Instruction> for Invocation {
+    fn from(ix: solana_sdk::instruction::Instruction) -> Self {
+        Self {
+            program_id: ix.program_id,
+            accounts: ix.accounts.into_iter().map(|meta| meta.pubkey).collect(),
+            data: ix.data,
+        }
+    }
+}
+
+/// A `getBlock` result tagged with its slot, the format of block dumps
+#[derive(Deserialize)]
+pub struct BlockDump {
+    pub slot: u64,
+    #[serde(flatten)]
+    pub block: UiConfirmedBlock,
+}
+
+impl Block {
+    pub fn from_ui(slot: u64, block: UiConfirmedBlock) -> Result<Self> {
+        let transactions = block
+            .transactions
+            .unwrap_or_default()
+            .into_iter()
+            .map(Transaction::from_encoded)
+            .collect::<Result<_>>()
+            .with_context(|| format!("invalid transaction in slot {slot}"))?;
+
+        Ok(Self {
+            slot,
+            block_time: block.block_time,
+            transactions,
+        })
+    }
+
+    /// Reads a dump file holding one block, an array of blocks or one block
+    /// per line
+    pub fn read_dump(path: &Path) -> Result<Vec<Self>> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+
+        let dumps: Vec<BlockDump> = match contents.trim_start().chars().next() {
+            Some('[') => serde_json::from_str(&contents)?,
+            Some('{') => serde_json::Deserializer::from_str(&contents)
+                .into_iter::<BlockDump>()
+                .collect::<Result<_, _>>()?,
+            _ => vec![],
+        };
+
+        dumps
+            .into_iter()
+            .map(|dump| Self::from_ui(dump.slot, dump.block))
+            .collect()
+    }
+
+    /// Fetches the confirmed blocks in `from..=to` from a validator
+    pub fn fetch_range(rpc: &RpcClient, from: u64, to: u64) -> Result<Vec<Self>> {
+        let config = RpcBlockConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            transaction_details: Some(TransactionDetails::Full),
+            rewards: Some(false),
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        };
+
+        rpc.get_blocks_with_commitment(from, Some(to), CommitmentConfig::confirmed())?
+            .into_iter()
+            .map(|slot| Self::from_ui(slot, rpc.get_block_with_config(slot, config)?))
+            .collect()
+    }
+}
+
+impl Transaction {
+    fn from_encoded(encoded: EncodedTransactionWithStatusMeta) -> Result<Self> {
+        let meta = encoded.meta.context("transaction has no status meta")?;
+        let transaction = encoded
+            .transaction
+            .decode()
+            .context("transactions must be base64 or base58 encoded")?;
+
+        // Addresses loaded from lookup tables follow the static keys
+        let mut keys = transaction.message.static_account_keys().to_vec();
+        let loaded: Option<UiLoadedAddresses> = meta.loaded_addresses.into();
+        if let Some(loaded) = loaded {
+            for key in loaded.writable.iter().chain(loaded.readonly.iter()) {
+                keys.push(key.parse()?);
+            }
+        }
+
+        let key = |index: u8| -> Result<Pubkey> {
+            keys.get(index as usize)
+                .copied()
+                .context("account index out of range")
+        };
+
+        let inner: Vec<UiInnerInstructions> =
+            Option::from(meta.inner_instructions).unwrap_or_default();
+        let mut invocations = vec![];
+
+        for (index, ix) in transaction.message.instructions().iter().enumerate() {
+            invocations.push(Invocation {
+                program_id: key(ix.program_id_index)?,
+                accounts: ix.accounts.iter().map(|&i| key(i)).collect::<Result<_>>()?,
+                data: ix.data.clone(),
+            });
+
+            // Inner instructions are listed in execution order under the
+            // top-level instruction that invoked them
+            for inner in inner.iter().filter(|inner| inner.index as usize == index) {
+                for ix in &inner.instructions {
+                    let UiInstruction::Compiled(ix) = ix else {
+                        bail!("inner instructions must not be parsed");
+                    };
+                    invocations.push(Invocation {
+                        program_id: key(ix.program_id_index)?,
+                        accounts: ix.accounts.iter().map(|&i| key(i)).collect::<Result<_>>()?,
+                        data: bs58::decode(&ix.data).into_vec()?,
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            signature: transaction
+                .signatures
+                .first()
+                .context("transaction has no signature")?
+                .to_string(),
+            success: meta.err.is_none(),
+            invocations,
+            logs: Option::from(meta.log_messages).unwrap_or_default(),
+        })
+    }
+
+    /// Lines each invocation of `program_id` logged, in invocation order.
+    /// Lines logged by programs it calls are left out.
+    pub fn program_logs(&self, program_id: &Pubkey) -> Vec<Vec<String>> {
+        let invoke = format!("Program {program_id} invoke [");
+        let mut segments: Vec<Vec<String>> = vec![];
+        // One frame per active invocation, holding its segment if it is ours
+        let mut frames: Vec<Option<usize>> = vec![];
+
+        for line in &self.logs {
+            if let Some(message) = line.strip_prefix("Program log: ") {
+                if let Some(Some(segment)) = frames.last() {
+                    segments[*segment].push(message.to_string());
+                }
+            } else if line.starts_with("Program ") && line.contains(" invoke [") {
+                if line.starts_with(&invoke) {
+                    segments.push(vec![]);
+                    frames.push(Some(segments.len() - 1));
+                } else {
+                    frames.push(None);
+                }
+            } else if line.starts_with("Program ")
+                && (line.ends_with(" success") || line.contains(" failed: "))
+            {
+                frames.pop();
+            }
+        }
+
+        segments
+    }
+}
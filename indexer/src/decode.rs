@@ -0,0 +1,166 @@
+//! Decoding of staking program instructions and the values their logs report.
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use bert_staking_client::ID;
+use bert_staking_sc::instruction;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::block::{Invocation, Transaction};
+
+macro_rules! staking_instructions {
+    ($($variant:ident => $name:literal,)*) => {
+        /// Arguments of any staking program instruction
+        pub enum StakingInstruction {
+            $($variant(instruction::$variant),)*
+        }
+
+        impl StakingInstruction {
+            /// Decodes instruction data by its discriminator
+            pub fn decode(data: &[u8]) -> Option<Self> {
+                if data.len() < 8 {
+                    return None;
+                }
+                let (discriminator, mut args) = data.split_at(8);
+
+                $(
+                    if discriminator == instruction::$variant::DISCRIMINATOR {
+                        return instruction::$variant::deserialize(&mut args)
+                            .ok()
+                            .map(Self::$variant);
+                    }
+                )*
+                None
+            }
+
+            /// Name of the program entrypoint
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant(_) => $name,)*
+                }
+            }
+        }
+    };
+}
+
+staking_instructions! {
+    Initialize => "initialize",
+    InitializeAuthVault => "initialize_auth_vault",
+    InitializeReceiptCollection => "initialize_receipt_collection",
+    InitiateUser => "initiate_user",
+    SetDelegate => "set_delegate",
+    InitializePool => "initialize_pool",
+    InitializeLiquidPool => "initialize_liquid_pool",
+    StakeNft => "stake_nft",
+    StakeToken => "stake_token",
+    ClaimPositionNft => "claim_position_nft",
+    ClaimPositionToken => "claim_position_token",
+    ClaimPositionTokenPartial => "claim_position_token_partial",
+    EmergencyWithdrawToken => "emergency_withdraw_token",
+    EmergencyWithdrawNft => "emergency_withdraw_nft",
+    CrankClaim => "crank_claim",
+    RequestUnstake => "request_unstake",
+    ExtendLock => "extend_lock",
+    TransferPosition => "transfer_position",
+    StakeLiquid => "stake_liquid",
+    RequestRedeem => "request_redeem",
+    Redeem => "redeem",
+    SplitPosition => "split_position",
+    MergePositions => "merge_positions",
+    MigratePositionSeeds => "migrate_position_seeds",
+    MigrateConfig => "migrate_config",
+    MigratePool => "migrate_pool",
+    MigratePosition => "migrate_position",
+    MigrateUserAccount => "migrate_user_account",
+    AdminPausePool => "admin_pause_pool",
+    AdminActivatePool => "admin_activate_pool",
+    AdminEnableEmergency => "admin_enable_emergency",
+    AdminDisableEmergency => "admin_disable_emergency",
+    AdminWithdrawTokens => "admin_withdraw_tokens",
+    AdminSetCrankTip => "admin_set_crank_tip",
+    AdminSetMerkleRoot => "admin_set_merkle_root",
+    AdminAddCollection => "admin_add_collection",
+    AdminUpdateCollection => "admin_update_collection",
+    AdminSetPositionLimit => "admin_set_position_limit",
+    AdminSetPoolConfig => "admin_set_pool_config",
+}
+
+/// A staking instruction with its accounts and the lines it logged
+pub struct Decoded {
+    pub instruction: StakingInstruction,
+    pub accounts: Vec<Pubkey>,
+    pub logs: Vec<String>,
+}
+
+impl Decoded {
+    /// Account at `index` in the instruction's accounts struct
+    pub fn account(&self, index: usize) -> Pubkey {
+        self.accounts.get(index).copied().unwrap_or_default()
+    }
+
+    /// Optional account at `index`, which clients pass as the program id
+    /// when it's omitted
+    pub fn optional_account(&self, index: usize) -> Option<Pubkey> {
+        Some(self.account(index)).filter(|account| *account != ID)
+    }
+
+    /// Yield the instruction paid out of the authority vault
+    pub fn yield_paid(&self) -> u64 {
+        self.logs
+            .iter()
+            .filter_map(|line| {
+                line.strip_prefix("Yield of ")?
+                    .split(' ')
+                    .next()?
+                    .parse::<u64>()
+                    .ok()
+            })
+            .sum()
+    }
+
+    /// Liquid pool yield the instruction accrued from the authority vault
+    pub fn liquid_yield_accrued(&self) -> u64 {
+        self.logs
+            .iter()
+            .filter_map(|line| {
+                line.strip_prefix("Liquid yield of ")?
+                    .split(' ')
+                    .next()?
+                    .parse::<u64>()
+                    .ok()
+            })
+            .sum()
+    }
+
+    /// Number logged as `key: value` on the line starting with `prefix`
+    pub fn log_value(&self, prefix: &str, key: &str) -> Option<u64> {
+        let line = self.logs.iter().find(|line| line.starts_with(prefix))?;
+        let pattern = format!("{key}: ");
+        let start = line.find(&pattern)? + pattern.len();
+        line[start..]
+            .split(|c: char| !c.is_ascii_digit())
+            .next()?
+            .parse()
+            .ok()
+    }
+}
+
+/// Decodes the staking instructions of a transaction, top-level and inner,
+/// in execution order
+pub fn staking_instructions(transaction: &Transaction) -> Vec<Decoded> {
+    let mut logs = transaction.program_logs(&ID).into_iter();
+
+    transaction
+        .invocations
+        .iter()
+        .filter(|invocation| invocation.program_id == ID)
+        .filter_map(|Invocation { accounts, data, .. }| {
+            // Every invocation has a log segment, decodable or not
+            let logs = logs.next().unwrap_or_default();
+            Some(Decoded {
+                instruction: StakingInstruction::decode(data)?,
+                accounts: accounts.clone(),
+                logs,
+            })
+        })
+        .collect()
+}
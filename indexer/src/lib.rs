@@ -0,0 +1,12 @@
+//! Indexer for the BERT staking program.
+//!
+//! Replays staking transactions from `getBlock` dumps or a validator's RPC
+//! into a SQLite database holding each user's history, positions and stats
+//! and each pool's value locked.
+
+pub mod block;
+pub mod decode;
+pub mod store;
+
+pub use block::Block;
+pub use store::Store;
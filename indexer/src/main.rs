@@ -0,0 +1,105 @@
+//! Command-line entry point of the staking indexer. Ingests blocks into a
+//! SQLite database and prints what it holds as JSON.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use bert_staking_indexer::{Block, Store};
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Parser)]
+#[command(
+    name = "bert-staking-indexer",
+    version,
+    about = "Indexes BERT staking transactions into SQLite"
+)]
+struct Cli {
+    /// SQLite database, created if missing
+    #[arg(long, default_value = "bert-staking.db")]
+    db: PathBuf,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Ingest `getBlock` JSON dumps, each with a `slot` field
+    IngestDump {
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+    },
+    /// Ingest confirmed blocks from a validator
+    IngestRpc {
+        #[arg(short, long, default_value = "http://localhost:8899")]
+        url: String,
+        /// First slot, defaults to the slot after the last one ingested
+        #[arg(long)]
+        from_slot: Option<u64>,
+        /// Last slot, defaults to the latest confirmed slot
+        #[arg(long)]
+        to_slot: Option<u64>,
+    },
+    /// Print a user's instructions, positions and per-pool stats
+    UserHistory { owner: Pubkey },
+    /// Print the value locked in every pool
+    PoolTvl,
+}
+
+fn print(value: &impl Serialize) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+fn ingest(store: &mut Store, blocks: &[Block]) -> Result<()> {
+    let mut transactions = 0;
+    for block in blocks {
+        transactions += store.ingest(block)?;
+    }
+    print(&serde_json::json!({
+        "blocks": blocks.len(),
+        "transactions": transactions,
+        "last_slot": store.last_slot()?,
+    }))
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let mut store = Store::open(&cli.db)?;
+
+    match cli.command {
+        Command::IngestDump { paths } => {
+            let mut blocks = vec![];
+            for path in paths {
+                blocks.extend(Block::read_dump(&path)?);
+            }
+            blocks.sort_by_key(|block| block.slot);
+            ingest(&mut store, &blocks)
+        }
+        Command::IngestRpc {
+            url,
+            from_slot,
+            to_slot,
+        } => {
+            let rpc = RpcClient::new(url);
+            let from = match from_slot {
+                Some(slot) => slot,
+                None => store.last_slot()?.map_or(0, |slot| slot + 1),
+            };
+            let to = match to_slot {
+                Some(slot) => slot,
+                None => rpc.get_slot()?,
+            };
+            ingest(&mut store, &Block::fetch_range(&rpc, from, to)?)
+        }
+        Command::UserHistory { owner } => print(&serde_json::json!({
+            "history": store.user_history(&owner)?,
+            "positions": store.positions(&owner)?,
+            "stats": store.user_stats(&owner)?,
+        })),
+        Command::PoolTvl => print(&store.pool_tvl()?),
+    }
+}
@@ -0,0 +1,1024 @@
+//! SQLite view of the staking program, rebuilt from its transactions.
+//!
+//! Every instruction is recorded in `history`. Configs, pools, collections,
+//! user accounts and positions are replayed from instruction arguments, and
+//! amounts the program computes (yield, liquid pool assets) are read from the
+//! instruction's logs. Per-pool TVL and per-user stats are views over these
+//! tables.
+
+use std::path::Path;
+
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    block::Block,
+    decode::{staking_instructions, Decoded, StakingInstruction},
+};
+
+const SCHEMA: &str = r#"
+CREATE TABLE IF NOT EXISTS meta (
+    key TEXT PRIMARY KEY,
+    value INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS transactions (
+    signature TEXT PRIMARY KEY,
+    slot INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    slot INTEGER NOT NULL,
+    block_time INTEGER,
+    signature TEXT NOT NULL,
+    instruction_index INTEGER NOT NULL,
+    instruction TEXT NOT NULL,
+    owner TEXT,
+    counterparty TEXT,
+    pool TEXT,
+    position TEXT,
+    amount INTEGER,
+    yield_amount INTEGER NOT NULL DEFAULT 0
+);
+CREATE INDEX IF NOT EXISTS history_owner ON history (owner);
+CREATE INDEX IF NOT EXISTS history_pool ON history (pool);
+
+CREATE TABLE IF NOT EXISTS configs (
+    address TEXT PRIMARY KEY,
+    authority TEXT NOT NULL,
+    id INTEGER NOT NULL,
+    mint TEXT NOT NULL,
+    collection TEXT NOT NULL,
+    admin_withdraw_destination TEXT NOT NULL,
+    authority_vault TEXT,
+    receipt_collection TEXT,
+    max_cap INTEGER NOT NULL,
+    nft_value_in_tokens INTEGER NOT NULL,
+    nfts_limit_per_user INTEGER NOT NULL,
+    crank_tip_lamports INTEGER NOT NULL DEFAULT 0,
+    max_positions_per_user INTEGER NOT NULL DEFAULT 0,
+    admin_withdrawn INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS pools (
+    address TEXT PRIMARY KEY,
+    config TEXT NOT NULL,
+    pool_index INTEGER NOT NULL,
+    pool_type TEXT NOT NULL DEFAULT 'standard',
+    is_paused INTEGER NOT NULL DEFAULT 0,
+    lock_period_days INTEGER NOT NULL,
+    yield_rate INTEGER NOT NULL,
+    max_nfts_cap INTEGER NOT NULL,
+    max_tokens_cap INTEGER NOT NULL,
+    max_value_cap INTEGER NOT NULL,
+    lock_mode TEXT NOT NULL DEFAULT 'fixed',
+    min_lock_days INTEGER NOT NULL DEFAULT 0,
+    max_lock_days INTEGER NOT NULL DEFAULT 0,
+    max_boost_bps INTEGER NOT NULL DEFAULT 10000,
+    nft_boost_bps_per_nft INTEGER NOT NULL DEFAULT 0,
+    max_nft_boost_bps INTEGER NOT NULL DEFAULT 0,
+    min_stake_amount INTEGER NOT NULL DEFAULT 0,
+    max_stake_per_position INTEGER NOT NULL DEFAULT 0,
+    unbonding_period_days INTEGER NOT NULL DEFAULT 0,
+    merkle_root TEXT,
+    liquid_assets INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS collections (
+    address TEXT PRIMARY KEY,
+    config TEXT NOT NULL,
+    collection TEXT NOT NULL,
+    nft_value_in_tokens INTEGER NOT NULL,
+    nfts_limit_per_user INTEGER NOT NULL,
+    is_active INTEGER NOT NULL DEFAULT 1
+);
+
+CREATE TABLE IF NOT EXISTS users (
+    address TEXT PRIMARY KEY,
+    config TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    delegate TEXT
+);
+
+CREATE TABLE IF NOT EXISTS positions (
+    address TEXT PRIMARY KEY,
+    owner TEXT NOT NULL,
+    pool TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    asset TEXT,
+    amount INTEGER NOT NULL,
+    status TEXT NOT NULL,
+    claimed_yield INTEGER NOT NULL DEFAULT 0,
+    opened_slot INTEGER NOT NULL,
+    closed_slot INTEGER
+);
+CREATE INDEX IF NOT EXISTS positions_owner ON positions (owner);
+CREATE INDEX IF NOT EXISTS positions_pool ON positions (pool);
+
+CREATE VIEW IF NOT EXISTS pool_tvl AS
+SELECT
+    pools.address AS pool,
+    pools.config,
+    pools.pool_index,
+    pools.pool_type,
+    pools.is_paused,
+    COALESCE(SUM(CASE WHEN positions.kind = 'token' THEN positions.amount END), 0) AS tokens_staked,
+    COUNT(CASE WHEN positions.kind = 'nft' THEN 1 END) AS nfts_staked,
+    COALESCE(SUM(CASE WHEN positions.kind = 'nft' THEN positions.amount END), 0) AS nft_value,
+    pools.liquid_assets,
+    COALESCE(SUM(positions.amount), 0) + pools.liquid_assets AS total_value,
+    (SELECT COALESCE(SUM(yield_amount), 0) FROM history WHERE history.pool = pools.address)
+        AS claimed_yield
+FROM pools
+LEFT JOIN positions
+    ON positions.pool = pools.address AND positions.status IN ('open', 'unbonding')
+GROUP BY pools.address;
+
+CREATE VIEW IF NOT EXISTS user_pool_stats AS
+WITH pairs AS (
+    SELECT owner, pool FROM positions
+    UNION
+    SELECT owner, pool FROM history WHERE yield_amount > 0
+)
+SELECT
+    pairs.owner,
+    pairs.pool,
+    COALESCE(SUM(CASE WHEN positions.kind = 'token' THEN positions.amount END), 0) AS tokens_staked,
+    COUNT(CASE WHEN positions.kind = 'nft' THEN 1 END) AS nfts_staked,
+    COALESCE(SUM(positions.amount), 0) AS total_value,
+    (SELECT COALESCE(SUM(yield_amount), 0) FROM history
+        WHERE history.owner = pairs.owner AND history.pool = pairs.pool) AS claimed_yield
+FROM pairs
+LEFT JOIN positions
+    ON positions.owner = pairs.owner
+    AND positions.pool = pairs.pool
+    AND positions.status IN ('open', 'unbonding')
+GROUP BY pairs.owner, pairs.pool;
+"#;
+
+/// An instruction as recorded in a user's or pool's history
+#[derive(Debug, Serialize)]
+pub struct HistoryEntry {
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub signature: String,
+    pub instruction: String,
+    pub owner: Option<String>,
+    pub counterparty: Option<String>,
+    pub pool: Option<String>,
+    pub position: Option<String>,
+    pub amount: Option<u64>,
+    pub yield_amount: u64,
+}
+
+/// Value locked in a pool
+#[derive(Debug, Serialize)]
+pub struct PoolTvl {
+    pub pool: String,
+    pub config: String,
+    pub pool_index: u32,
+    pub pool_type: String,
+    pub is_paused: bool,
+    pub tokens_staked: u64,
+    pub nfts_staked: u64,
+    pub nft_value: u64,
+    pub liquid_assets: u64,
+    pub total_value: u64,
+    pub claimed_yield: u64,
+}
+
+/// A user's stake and claimed yield in one pool
+#[derive(Debug, Serialize)]
+pub struct UserPoolStats {
+    pub owner: String,
+    pub pool: String,
+    pub tokens_staked: u64,
+    pub nfts_staked: u64,
+    pub total_value: u64,
+    pub claimed_yield: u64,
+}
+
+/// A position as last seen. Closed positions keep the amount they held.
+#[derive(Debug, Serialize)]
+pub struct PositionRow {
+    pub address: String,
+    pub owner: String,
+    pub pool: String,
+    pub kind: String,
+    pub asset: Option<String>,
+    pub amount: u64,
+    pub status: String,
+    pub claimed_yield: u64,
+    pub opened_slot: u64,
+    pub closed_slot: Option<u64>,
+}
+
+pub struct Store {
+    conn: Connection,
+}
+
+/// Where an instruction sits in the ledger
+struct Location<'a> {
+    slot: u64,
+    block_time: Option<i64>,
+    signature: &'a str,
+    index: usize,
+}
+
+/// Fields of a history row
+#[derive(Default)]
+struct Event {
+    owner: Option<Pubkey>,
+    counterparty: Option<Pubkey>,
+    pool: Option<Pubkey>,
+    position: Option<Pubkey>,
+    amount: Option<u64>,
+    yield_amount: u64,
+}
+
+fn key(pubkey: &Pubkey) -> String {
+    pubkey.to_string()
+}
+
+impl Store {
+    pub fn open(path: &Path) -> Result<Self> {
+        Self::init(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// Highest slot ingested so far
+    pub fn last_slot(&self) -> Result<Option<u64>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT value FROM meta WHERE key = 'last_slot'",
+                [],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Applies the successful staking transactions of `block`. Transactions
+    /// already ingested are skipped, so blocks can be replayed. Returns the
+    /// number of transactions applied.
+    pub fn ingest(&mut self, block: &Block) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+        let mut applied = 0;
+
+        for transaction in block.transactions.iter().filter(|t| t.success) {
+            let instructions = staking_instructions(transaction);
+            if instructions.is_empty() {
+                continue;
+            }
+
+            let inserted = tx.execute(
+                "INSERT OR IGNORE INTO transactions (signature, slot) VALUES (?1, ?2)",
+                params![transaction.signature, block.slot],
+            )?;
+            if inserted == 0 {
+                continue;
+            }
+
+            for (index, ix) in instructions.iter().enumerate() {
+                let location = Location {
+                    slot: block.slot,
+                    block_time: block.block_time,
+                    signature: &transaction.signature,
+                    index,
+                };
+                apply(&tx, &location, ix)?;
+            }
+            applied += 1;
+        }
+
+        tx.execute(
+            "INSERT INTO meta (key, value) VALUES ('last_slot', ?1)
+             ON CONFLICT (key) DO UPDATE SET value = MAX(value, excluded.value)",
+            params![block.slot],
+        )?;
+        tx.commit()?;
+
+        Ok(applied)
+    }
+
+    /// Every instruction that involved `owner`, oldest first
+    pub fn user_history(&self, owner: &Pubkey) -> Result<Vec<HistoryEntry>> {
+        let mut statement = self.conn.prepare(
+            "SELECT slot, block_time, signature, instruction, owner, counterparty, pool,
+                    position, amount, yield_amount
+             FROM history WHERE owner = ?1 ORDER BY slot, id",
+        )?;
+
+        let rows = statement.query_map(params![key(owner)], |row| {
+            Ok(HistoryEntry {
+                slot: row.get(0)?,
+                block_time: row.get(1)?,
+                signature: row.get(2)?,
+                instruction: row.get(3)?,
+                owner: row.get(4)?,
+                counterparty: row.get(5)?,
+                pool: row.get(6)?,
+                position: row.get(7)?,
+                amount: row.get(8)?,
+                yield_amount: row.get(9)?,
+            })
+        })?;
+
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Value locked in every pool
+    pub fn pool_tvl(&self) -> Result<Vec<PoolTvl>> {
+        let mut statement = self.conn.prepare(
+            "SELECT pool, config, pool_index, pool_type, is_paused, tokens_staked, nfts_staked,
+                    nft_value, liquid_assets, total_value, claimed_yield
+             FROM pool_tvl ORDER BY config, pool_index",
+        )?;
+
+        let rows = statement.query_map([], |row| {
+            Ok(PoolTvl {
+                pool: row.get(0)?,
+                config: row.get(1)?,
+                pool_index: row.get(2)?,
+                pool_type: row.get(3)?,
+                is_paused: row.get(4)?,
+                tokens_staked: row.get(5)?,
+                nfts_staked: row.get(6)?,
+                nft_value: row.get(7)?,
+                liquid_assets: row.get(8)?,
+                total_value: row.get(9)?,
+                claimed_yield: row.get(10)?,
+            })
+        })?;
+
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// `owner`'s stake and claimed yield per pool
+    pub fn user_stats(&self, owner: &Pubkey) -> Result<Vec<UserPoolStats>> {
+        let mut statement = self.conn.prepare(
+            "SELECT owner, pool, tokens_staked, nfts_staked, total_value, claimed_yield
+             FROM user_pool_stats WHERE owner = ?1 ORDER BY pool",
+        )?;
+
+        let rows = statement.query_map(params![key(owner)], |row| {
+            Ok(UserPoolStats {
+                owner: row.get(0)?,
+                pool: row.get(1)?,
+                tokens_staked: row.get(2)?,
+                nfts_staked: row.get(3)?,
+                total_value: row.get(4)?,
+                claimed_yield: row.get(5)?,
+            })
+        })?;
+
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Every position `owner` has held, oldest first
+    pub fn positions(&self, owner: &Pubkey) -> Result<Vec<PositionRow>> {
+        let mut statement = self.conn.prepare(
+            "SELECT address, owner, pool, kind, asset, amount, status, claimed_yield,
+                    opened_slot, closed_slot
+             FROM positions WHERE owner = ?1 ORDER BY opened_slot, rowid",
+        )?;
+
+        let rows = statement.query_map(params![key(owner)], |row| {
+            Ok(PositionRow {
+                address: row.get(0)?,
+                owner: row.get(1)?,
+                pool: row.get(2)?,
+                kind: row.get(3)?,
+                asset: row.get(4)?,
+                amount: row.get(5)?,
+                status: row.get(6)?,
+                claimed_yield: row.get(7)?,
+                opened_slot: row.get(8)?,
+                closed_slot: row.get(9)?,
+            })
+        })?;
+
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+}
+
+fn record(conn: &Connection, location: &Location, name: &str, event: Event) -> Result<()> {
+    conn.execute(
+        "INSERT INTO history (slot, block_time, signature, instruction_index, instruction, owner,
+                              counterparty, pool, position, amount, yield_amount)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+        params![
+            location.slot,
+            location.block_time,
+            location.signature,
+            location.index,
+            name,
+            event.owner.as_ref().map(key),
+            event.counterparty.as_ref().map(key),
+            event.pool.as_ref().map(key),
+            event.position.as_ref().map(key),
+            event.amount,
+            event.yield_amount,
+        ],
+    )?;
+    Ok(())
+}
+
+fn position_amount(conn: &Connection, position: &Pubkey) -> Result<Option<u64>> {
+    Ok(conn
+        .query_row(
+            "SELECT amount FROM positions WHERE address = ?1",
+            params![key(position)],
+            |row| row.get(0),
+        )
+        .optional()?)
+}
+
+fn position_owner(conn: &Connection, position: &Pubkey) -> Result<Option<Pubkey>> {
+    let owner: Option<String> = conn
+        .query_row(
+            "SELECT owner FROM positions WHERE address = ?1",
+            params![key(position)],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(owner.and_then(|owner| owner.parse().ok()))
+}
+
+fn open_position(
+    conn: &Connection,
+    location: &Location,
+    position: &Pubkey,
+    owner: &Pubkey,
+    pool: &Pubkey,
+    asset: Option<&Pubkey>,
+    amount: u64,
+) -> Result<()> {
+    conn.execute(
+        "INSERT OR REPLACE INTO positions (address, owner, pool, kind, asset, amount, status,
+                                           opened_slot)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, 'open', ?7)",
+        params![
+            key(position),
+            key(owner),
+            key(pool),
+            if asset.is_some() { "nft" } else { "token" },
+            asset.map(key),
+            amount,
+            location.slot,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Opens `new_position` for `owner` with the pool and kind of `position`
+fn copy_position(
+    conn: &Connection,
+    location: &Location,
+    position: &Pubkey,
+    new_position: &Pubkey,
+    owner: &Pubkey,
+    amount: Option<u64>,
+) -> Result<()> {
+    conn.execute(
+        "INSERT OR REPLACE INTO positions (address, owner, pool, kind, asset, amount, status,
+                                           opened_slot)
+         SELECT ?1, ?2, pool, kind, asset, COALESCE(?3, amount), 'open', ?4
+         FROM positions WHERE address = ?5",
+        params![
+            key(new_position),
+            key(owner),
+            amount,
+            location.slot,
+            key(position)
+        ],
+    )?;
+    Ok(())
+}
+
+fn close_position(
+    conn: &Connection,
+    location: &Location,
+    position: &Pubkey,
+    status: &str,
+    yield_amount: u64,
+) -> Result<()> {
+    conn.execute(
+        "UPDATE positions SET status = ?1, closed_slot = ?2, claimed_yield = claimed_yield + ?3
+         WHERE address = ?4",
+        params![status, location.slot, yield_amount, key(position)],
+    )?;
+    Ok(())
+}
+
+fn apply(conn: &Connection, location: &Location, ix: &Decoded) -> Result<()> {
+    let a = |index| ix.account(index);
+    let name = ix.instruction.name();
+
+    let event = match &ix.instruction {
+        StakingInstruction::Initialize(args) => {
+            conn.execute(
+                "INSERT OR REPLACE INTO configs (address, authority, id, mint, collection,
+                    admin_withdraw_destination, max_cap, nft_value_in_tokens, nfts_limit_per_user)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![
+                    key(&a(1)),
+                    key(&a(0)),
+                    args.id,
+                    key(&a(2)),
+                    key(&a(3)),
+                    key(&a(6)),
+                    args.max_cap,
+                    args.nft_value_in_tokens,
+                    args.nfts_limit_per_user,
+                ],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::InitializeAuthVault(_) => {
+            conn.execute(
+                "UPDATE configs SET authority_vault = ?1 WHERE address = ?2",
+                params![key(&a(3)), key(&a(1))],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::InitializeReceiptCollection(_) => {
+            conn.execute(
+                "UPDATE configs SET receipt_collection = ?1 WHERE address = ?2",
+                params![key(&a(2)), key(&a(1))],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::InitiateUser(_) => {
+            conn.execute(
+                "INSERT OR IGNORE INTO users (address, config, owner) VALUES (?1, ?2, ?3)",
+                params![key(&a(3)), key(&a(1)), key(&a(0))],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::SetDelegate(args) => {
+            conn.execute(
+                "UPDATE users SET delegate = ?1 WHERE address = ?2",
+                params![args.delegate.as_ref().map(key), key(&a(2))],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                counterparty: args.delegate,
+                ..Default::default()
+            }
+        }
+        StakingInstruction::InitializePool(args) => {
+            conn.execute(
+                "INSERT OR REPLACE INTO pools (address, config, pool_index, lock_period_days,
+                    yield_rate, max_nfts_cap, max_tokens_cap, max_value_cap)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    key(&a(2)),
+                    key(&a(1)),
+                    args.index,
+                    args.lock_period_days,
+                    args.yield_rate,
+                    args.max_nfts_cap,
+                    args.max_tokens_cap,
+                    args.max_value_cap,
+                ],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                pool: Some(a(2)),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::InitializeLiquidPool(args) => {
+            conn.execute(
+                "UPDATE pools SET pool_type = 'liquid', unbonding_period_days = ?1
+                 WHERE address = ?2",
+                params![args.unbonding_period_days, key(&a(2))],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                pool: Some(a(2)),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::StakeToken(args) => {
+            open_position(conn, location, &a(5), &a(0), &a(2), None, args.amount)?;
+            Event {
+                owner: Some(a(0)),
+                pool: Some(a(2)),
+                position: Some(a(5)),
+                amount: Some(args.amount),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::StakeNft(_) => {
+            let value: u64 = conn
+                .query_row(
+                    "SELECT nft_value_in_tokens FROM collections WHERE address = ?1",
+                    params![key(&a(9))],
+                    |row| row.get(0),
+                )
+                .optional()?
+                .unwrap_or_default();
+            open_position(conn, location, &a(5), &a(0), &a(2), Some(&a(6)), value)?;
+            Event {
+                owner: Some(a(0)),
+                pool: Some(a(2)),
+                position: Some(a(5)),
+                amount: Some(value),
+                ..Default::default()
+            }
+        }
+        // Emergency withdrawals take the claim accounts and pay no yield
+        StakingInstruction::ClaimPositionToken(_)
+        | StakingInstruction::EmergencyWithdrawToken(_) => {
+            let yield_amount = ix.yield_paid();
+            let amount = position_amount(conn, &a(6))?;
+            close_position(conn, location, &a(6), "claimed", yield_amount)?;
+            Event {
+                owner: Some(a(1)),
+                pool: Some(a(3)),
+                position: Some(a(6)),
+                amount,
+                yield_amount,
+                ..Default::default()
+            }
+        }
+        StakingInstruction::ClaimPositionTokenPartial(args) => {
+            let yield_amount = ix.yield_paid();
+            conn.execute(
+                "UPDATE positions SET amount = amount - ?1, claimed_yield = claimed_yield + ?2
+                 WHERE address = ?3",
+                params![args.amount, yield_amount, key(&a(6))],
+            )?;
+            Event {
+                owner: Some(a(1)),
+                pool: Some(a(3)),
+                position: Some(a(6)),
+                amount: Some(args.amount),
+                yield_amount,
+                ..Default::default()
+            }
+        }
+        StakingInstruction::ClaimPositionNft(_) | StakingInstruction::EmergencyWithdrawNft(_) => {
+            let yield_amount = ix.yield_paid();
+            let amount = position_amount(conn, &a(7))?;
+            close_position(conn, location, &a(7), "claimed", yield_amount)?;
+            Event {
+                owner: Some(a(1)),
+                pool: Some(a(4)),
+                position: Some(a(7)),
+                amount,
+                yield_amount,
+                ..Default::default()
+            }
+        }
+        StakingInstruction::CrankClaim(_) => {
+            let yield_amount = ix.yield_paid();
+            let amount = position_amount(conn, &a(6))?;
+            close_position(conn, location, &a(6), "claimed", yield_amount)?;
+            Event {
+                owner: Some(a(1)),
+                counterparty: Some(a(0)),
+                pool: Some(a(3)),
+                position: Some(a(6)),
+                amount,
+                yield_amount,
+            }
+        }
+        StakingInstruction::RequestUnstake(_) => {
+            conn.execute(
+                "UPDATE positions SET status = 'unbonding' WHERE address = ?1",
+                params![key(&a(3))],
+            )?;
+            Event {
+                owner: position_owner(conn, &a(3))?,
+                pool: Some(a(2)),
+                position: Some(a(3)),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::ExtendLock(_) => {
+            // Yield settles in the old pool before the position moves, if it
+            // moves at all
+            let yield_amount = ix.yield_paid();
+            let position = a(8);
+            conn.execute(
+                "UPDATE positions SET claimed_yield = claimed_yield + ?1 WHERE address = ?2",
+                params![yield_amount, key(&position)],
+            )?;
+
+            // Moving pools re-keys the position under the target pool
+            let position = match (ix.optional_account(4), ix.optional_account(9)) {
+                (Some(target_pool), Some(new_position)) => {
+                    copy_position(conn, location, &position, &new_position, &a(1), None)?;
+                    close_position(conn, location, &position, "moved", 0)?;
+                    conn.execute(
+                        "UPDATE positions SET pool = ?1 WHERE address = ?2",
+                        params![key(&target_pool), key(&new_position)],
+                    )?;
+                    new_position
+                }
+                _ => position,
+            };
+
+            Event {
+                owner: Some(a(1)),
+                pool: Some(a(3)),
+                position: Some(position),
+                yield_amount,
+                ..Default::default()
+            }
+        }
+        StakingInstruction::TransferPosition(_) => {
+            let (owner, new_owner) = (a(0), a(1));
+            let (position, new_position) = (a(8), a(9));
+            let amount = position_amount(conn, &position)?;
+
+            copy_position(conn, location, &position, &new_position, &new_owner, None)?;
+            close_position(conn, location, &position, "transferred", 0)?;
+
+            // The recipient gets their own history entry
+            record(
+                conn,
+                location,
+                name,
+                Event {
+                    owner: Some(new_owner),
+                    counterparty: Some(owner),
+                    pool: Some(a(3)),
+                    position: Some(new_position),
+                    amount,
+                    ..Default::default()
+                },
+            )?;
+            Event {
+                owner: Some(owner),
+                counterparty: Some(new_owner),
+                pool: Some(a(3)),
+                position: Some(position),
+                amount,
+                ..Default::default()
+            }
+        }
+        StakingInstruction::SplitPosition(args) => {
+            let (position, new_position) = (a(4), a(5));
+            copy_position(
+                conn,
+                location,
+                &position,
+                &new_position,
+                &a(0),
+                Some(args.amount),
+            )?;
+            conn.execute(
+                "UPDATE positions SET amount = amount - ?1 WHERE address = ?2",
+                params![args.amount, key(&position)],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                pool: Some(a(2)),
+                position: Some(new_position),
+                amount: Some(args.amount),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::MergePositions(_) => {
+            let (position, other) = (a(4), a(5));
+            let amount = position_amount(conn, &other)?;
+            conn.execute(
+                "UPDATE positions SET amount = amount + ?1 WHERE address = ?2",
+                params![amount.unwrap_or_default(), key(&position)],
+            )?;
+            close_position(conn, location, &other, "merged", 0)?;
+            Event {
+                owner: Some(a(0)),
+                pool: Some(a(2)),
+                position: Some(position),
+                amount,
+                ..Default::default()
+            }
+        }
+        StakingInstruction::MigratePositionSeeds(_) => {
+            let (position, new_position) = (a(3), a(4));
+            copy_position(conn, location, &position, &new_position, &a(0), None)?;
+            close_position(conn, location, &position, "migrated", 0)?;
+            Event {
+                owner: Some(a(0)),
+                position: Some(new_position),
+                ..Default::default()
+            }
+        }
+        // Layout migrations rewrite accounts in place without changing
+        // anything indexed
+        StakingInstruction::MigrateConfig(_) | StakingInstruction::MigrateUserAccount(_) => Event {
+            owner: Some(a(0)),
+            ..Default::default()
+        },
+        StakingInstruction::MigratePool(_) => Event {
+            owner: Some(a(0)),
+            pool: Some(a(1)),
+            ..Default::default()
+        },
+        StakingInstruction::MigratePosition(_) => Event {
+            owner: Some(a(0)),
+            position: Some(a(1)),
+            ..Default::default()
+        },
+        StakingInstruction::StakeLiquid(args) => {
+            // The log reports the pool's assets after the deposit
+            let assets = ix.log_value("liquid_pool:", "assets");
+            conn.execute(
+                "UPDATE pools SET liquid_assets = COALESCE(?1, liquid_assets + ?2 + ?3)
+                 WHERE address = ?4",
+                params![assets, args.amount, ix.liquid_yield_accrued(), key(&a(2))],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                pool: Some(a(2)),
+                amount: Some(args.amount),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::RequestRedeem(_) => {
+            let assets = ix.log_value("redemption:", "assets").unwrap_or_default();
+            conn.execute(
+                "UPDATE pools SET liquid_assets = MAX(liquid_assets + ?1 - ?2, 0)
+                 WHERE address = ?3",
+                params![ix.liquid_yield_accrued(), assets, key(&a(2))],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                pool: Some(a(2)),
+                position: Some(a(6)),
+                amount: Some(assets),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::Redeem(_) => Event {
+            owner: Some(a(0)),
+            pool: Some(a(2)),
+            position: Some(a(4)),
+            amount: ix.log_value("redeemed:", "redeemed"),
+            ..Default::default()
+        },
+        StakingInstruction::AdminPausePool(_) | StakingInstruction::AdminActivatePool(_) => {
+            let paused = matches!(ix.instruction, StakingInstruction::AdminPausePool(_));
+            conn.execute(
+                "UPDATE pools SET is_paused = ?1 WHERE address = ?2",
+                params![paused, key(&a(2))],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                pool: Some(a(2)),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::AdminEnableEmergency(_)
+        | StakingInstruction::AdminDisableEmergency(_) => Event {
+            owner: Some(a(0)),
+            ..Default::default()
+        },
+        StakingInstruction::AdminSetPoolConfig(args) => {
+            let params = &args.config_params;
+            conn.execute(
+                "UPDATE pools SET lock_period_days = ?1, yield_rate = ?2, max_nfts_cap = ?3,
+                    max_tokens_cap = ?4, max_value_cap = ?5, lock_mode = ?6, min_lock_days = ?7,
+                    max_lock_days = ?8, max_boost_bps = ?9, nft_boost_bps_per_nft = ?10,
+                    max_nft_boost_bps = ?11, min_stake_amount = ?12,
+                    max_stake_per_position = ?13, unbonding_period_days = ?14
+                 WHERE address = ?15",
+                params![
+                    params.lock_period_days,
+                    params.yield_rate,
+                    params.max_nfts_cap,
+                    params.max_tokens_cap,
+                    params.max_value_cap,
+                    format!("{:?}", params.lock_mode).to_lowercase(),
+                    params.min_lock_days,
+                    params.max_lock_days,
+                    params.max_boost_bps,
+                    params.nft_boost_bps_per_nft,
+                    params.max_nft_boost_bps,
+                    params.min_stake_amount,
+                    params.max_stake_per_position,
+                    params.unbonding_period_days,
+                    key(&a(2)),
+                ],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                pool: Some(a(2)),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::AdminSetMerkleRoot(args) => {
+            let root: String = args
+                .merkle_root
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect();
+            conn.execute(
+                "UPDATE pools SET merkle_root = ?1 WHERE address = ?2",
+                params![root, key(&a(2))],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                pool: Some(a(2)),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::AdminWithdrawTokens(args) => {
+            conn.execute(
+                "UPDATE configs SET admin_withdrawn = admin_withdrawn + ?1 WHERE address = ?2",
+                params![args.amount, key(&a(1))],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                amount: Some(args.amount),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::AdminSetCrankTip(args) => {
+            conn.execute(
+                "UPDATE configs SET crank_tip_lamports = ?1 WHERE address = ?2",
+                params![args.tip_lamports, key(&a(1))],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                amount: Some(args.tip_lamports),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::AdminAddCollection(args) => {
+            conn.execute(
+                "INSERT OR REPLACE INTO collections (address, config, collection,
+                    nft_value_in_tokens, nfts_limit_per_user)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    key(&a(3)),
+                    key(&a(1)),
+                    key(&a(2)),
+                    args.nft_value_in_tokens,
+                    args.nfts_limit_per_user,
+                ],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::AdminUpdateCollection(args) => {
+            conn.execute(
+                "UPDATE collections SET nft_value_in_tokens = ?1, nfts_limit_per_user = ?2,
+                    is_active = ?3
+                 WHERE address = ?4",
+                params![
+                    args.nft_value_in_tokens,
+                    args.nfts_limit_per_user,
+                    args.is_active,
+                    key(&a(2)),
+                ],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                ..Default::default()
+            }
+        }
+        StakingInstruction::AdminSetPositionLimit(args) => {
+            conn.execute(
+                "UPDATE configs SET max_positions_per_user = ?1 WHERE address = ?2",
+                params![args.max_positions_per_user, key(&a(1))],
+            )?;
+            Event {
+                owner: Some(a(0)),
+                ..Default::default()
+            }
+        }
+    };
+
+    record(conn, location, name, event)
+}
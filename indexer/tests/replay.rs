@@ -0,0 +1,222 @@
+use bert_staking_client::{ConfigKeys, ID};
+use bert_staking_indexer::{
+    block::{Invocation, Transaction},
+    Block, Store,
+};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+const ONE_TOKEN: u64 = 1_000_000;
+
+struct Replay {
+    keys: ConfigKeys,
+    admin: Pubkey,
+    slot: u64,
+    signatures: u64,
+}
+
+impl Replay {
+    fn new() -> Self {
+        let admin = Pubkey::new_unique();
+        Self {
+            keys: ConfigKeys::new(admin, 1, Pubkey::new_unique(), Pubkey::new_unique()),
+            admin,
+            slot: 100,
+            signatures: 0,
+        }
+    }
+
+    /// A transaction running `ix` at the top level with the lines it logged
+    fn transaction(&mut self, ix: Instruction, logs: &[&str], success: bool) -> Transaction {
+        self.signatures += 1;
+
+        let mut lines = vec![format!("Program {ID} invoke [1]")];
+        lines.extend(logs.iter().map(|line| format!("Program log: {line}")));
+        lines.push(match success {
+            true => format!("Program {ID} success"),
+            false => format!("Program {ID} failed: custom program error: 0x1770"),
+        });
+
+        Transaction {
+            signature: format!("sig{}", self.signatures),
+            success,
+            invocations: vec![Invocation::from(ix)],
+            logs: lines,
+        }
+    }
+
+    fn block(&mut self, transactions: Vec<Transaction>) -> Block {
+        self.slot += 1;
+        Block {
+            slot: self.slot,
+            block_time: Some(1_700_000_000 + self.slot as i64),
+            transactions,
+        }
+    }
+
+    /// A config with one token pool, as the first block
+    fn setup(&mut self) -> Block {
+        let txs = vec![
+            self.keys.initialize(1, self.admin, 0, 100 * ONE_TOKEN, 5),
+            self.keys.initialize_pool(0, 30, 500, 100, 0, 0),
+        ]
+        .into_iter()
+        .map(|ix| self.transaction(ix, &[], true))
+        .collect();
+        self.block(txs)
+    }
+}
+
+#[test]
+fn replays_stakes_and_claims_into_tvl_and_history() {
+    let mut replay = Replay::new();
+    let mut store = Store::open_in_memory().unwrap();
+    let owner = Pubkey::new_unique();
+    let keys = replay.keys;
+    let position = keys.position(&owner, 0, 0);
+
+    let setup = replay.setup();
+    store.ingest(&setup).unwrap();
+
+    let stake = replay.transaction(
+        keys.stake_token(owner, 0, 0, 1_000 * ONE_TOKEN, None, None, None),
+        &[],
+        true,
+    );
+    let second = replay.transaction(
+        keys.stake_token(owner, 0, 1, 500 * ONE_TOKEN, None, None, None),
+        &[],
+        true,
+    );
+    let block = replay.block(vec![stake, second]);
+    assert_eq!(store.ingest(&block).unwrap(), 2);
+
+    let tvl = store.pool_tvl().unwrap();
+    assert_eq!(tvl.len(), 1);
+    assert_eq!(tvl[0].pool, keys.pool(0).to_string());
+    assert_eq!(tvl[0].tokens_staked, 1_500 * ONE_TOKEN);
+    assert_eq!(tvl[0].total_value, 1_500 * ONE_TOKEN);
+
+    let claim = replay.transaction(
+        keys.claim_position_token(owner, owner, 0, position, None),
+        &["Yield of 50000000 transferred from authority vault"],
+        true,
+    );
+    let block = replay.block(vec![claim]);
+    store.ingest(&block).unwrap();
+
+    let tvl = store.pool_tvl().unwrap();
+    assert_eq!(tvl[0].tokens_staked, 500 * ONE_TOKEN);
+    assert_eq!(tvl[0].claimed_yield, 50 * ONE_TOKEN);
+
+    let history = store.user_history(&owner).unwrap();
+    let names: Vec<_> = history.iter().map(|e| e.instruction.as_str()).collect();
+    assert_eq!(
+        names,
+        ["stake_token", "stake_token", "claim_position_token"]
+    );
+    assert_eq!(history[2].amount, Some(1_000 * ONE_TOKEN));
+    assert_eq!(history[2].yield_amount, 50 * ONE_TOKEN);
+
+    let stats = store.user_stats(&owner).unwrap();
+    assert_eq!(stats.len(), 1);
+    assert_eq!(stats[0].tokens_staked, 500 * ONE_TOKEN);
+    assert_eq!(stats[0].claimed_yield, 50 * ONE_TOKEN);
+
+    let positions = store.positions(&owner).unwrap();
+    assert_eq!(positions[0].status, "claimed");
+    assert_eq!(positions[1].status, "open");
+}
+
+#[test]
+fn replaying_a_block_is_idempotent() {
+    let mut replay = Replay::new();
+    let mut store = Store::open_in_memory().unwrap();
+    let owner = Pubkey::new_unique();
+    let keys = replay.keys;
+
+    let setup = replay.setup();
+    let stake = replay.transaction(
+        keys.stake_token(owner, 0, 0, 1_000 * ONE_TOKEN, None, None, None),
+        &[],
+        true,
+    );
+    let block = replay.block(vec![stake]);
+
+    for _ in 0..2 {
+        store.ingest(&setup).unwrap();
+        store.ingest(&block).unwrap();
+    }
+
+    assert_eq!(store.ingest(&block).unwrap(), 0);
+    assert_eq!(
+        store.pool_tvl().unwrap()[0].tokens_staked,
+        1_000 * ONE_TOKEN
+    );
+    assert_eq!(store.user_history(&owner).unwrap().len(), 1);
+    assert_eq!(store.last_slot().unwrap(), Some(block.slot));
+}
+
+#[test]
+fn skips_failed_transactions() {
+    let mut replay = Replay::new();
+    let mut store = Store::open_in_memory().unwrap();
+    let owner = Pubkey::new_unique();
+    let keys = replay.keys;
+
+    let setup = replay.setup();
+    store.ingest(&setup).unwrap();
+
+    let failed = replay.transaction(
+        keys.stake_token(owner, 0, 0, 1_000 * ONE_TOKEN, None, None, None),
+        &[],
+        false,
+    );
+    let block = replay.block(vec![failed]);
+    assert_eq!(store.ingest(&block).unwrap(), 0);
+
+    assert_eq!(store.pool_tvl().unwrap()[0].total_value, 0);
+    assert!(store.user_history(&owner).unwrap().is_empty());
+}
+
+#[test]
+fn follows_positions_across_owners() {
+    let mut replay = Replay::new();
+    let mut store = Store::open_in_memory().unwrap();
+    let (owner, recipient) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let keys = replay.keys;
+    let position = keys.position(&owner, 0, 0);
+
+    let setup = replay.setup();
+    store.ingest(&setup).unwrap();
+
+    let stake = replay.transaction(
+        keys.stake_token(owner, 0, 0, 1_000 * ONE_TOKEN, None, None, None),
+        &[],
+        true,
+    );
+    let transfer = replay.transaction(
+        keys.transfer_position(owner, recipient, 0, position, 0, None, None),
+        &[],
+        true,
+    );
+    let block = replay.block(vec![stake, transfer]);
+    store.ingest(&block).unwrap();
+
+    assert_eq!(
+        store.pool_tvl().unwrap()[0].tokens_staked,
+        1_000 * ONE_TOKEN
+    );
+    assert_eq!(store.user_stats(&owner).unwrap()[0].total_value, 0);
+
+    let received = store.positions(&recipient).unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(
+        received[0].address,
+        keys.position(&recipient, 0, 0).to_string()
+    );
+    assert_eq!(received[0].amount, 1_000 * ONE_TOKEN);
+
+    let history = store.user_history(&recipient).unwrap();
+    assert_eq!(history[0].instruction, "transfer_position");
+    assert_eq!(history[0].counterparty, Some(owner.to_string()));
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{state::*, StakingError};
+
+#[derive(Accounts)]
+pub struct AdminAddCollection<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ StakingError::Unauthorized,
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    pub collection: Box<Account<'info, BaseCollectionV1>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CollectionEntry::INIT_SPACE,
+        seeds = [b"collection", config.key().as_ref(), collection.key().as_ref()],
+        bump
+    )]
+    pub collection_entry: Box<Account<'info, CollectionEntry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AdminAddCollection<'info> {
+    /// Registers a collection as stakeable under this config
+    pub fn admin_add_collection(
+        &mut self,
+        nft_value_in_tokens: u64,
+        nfts_limit_per_user: u8,
+        bumps: &AdminAddCollectionBumps,
+    ) -> Result<()> {
+        self.collection_entry.set_inner(CollectionEntry {
+            config: self.config.key(),
+            collection: self.collection.key(),
+            nft_value_in_tokens,
+            nfts_limit_per_user,
+            is_active: true,
+            total_nfts_staked: 0,
+            bump: bumps.collection_entry,
+            version: CollectionEntry::VERSION,
+            _padding: [0; 31],
+        });
+
+        msg!(
+            "collection registered: {:?} | value: {:?} | limit: {:?}",
+            self.collection_entry.collection,
+            nft_value_in_tokens,
+            nfts_limit_per_user
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct AdminUpdateCollection<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ StakingError::Unauthorized,
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        has_one = config,
+        seeds = [b"collection", config.key().as_ref(), collection_entry.collection.as_ref()],
+        bump = collection_entry.bump,
+    )]
+    pub collection_entry: Box<Account<'info, CollectionEntry>>,
+}
+
+impl<'info> AdminUpdateCollection<'info> {
+    /// Updates a registered collection. Staked positions keep the value they
+    /// were staked at.
+    pub fn admin_update_collection(
+        &mut self,
+        nft_value_in_tokens: u64,
+        nfts_limit_per_user: u8,
+        is_active: bool,
+    ) -> Result<()> {
+        let collection_entry = &mut self.collection_entry;
+        collection_entry.nft_value_in_tokens = nft_value_in_tokens;
+        collection_entry.nfts_limit_per_user = nfts_limit_per_user;
+        collection_entry.is_active = is_active;
+
+        Ok(())
+    }
+}
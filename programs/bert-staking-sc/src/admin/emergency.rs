@@ -0,0 +1,38 @@
+use crate::{state::*, StakingError};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AdminSetEmergency<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> AdminSetEmergency<'info> {
+    /// Halts stakes in every pool and lets users withdraw their principal
+    /// without waiting for locks or cooldowns
+    pub fn admin_enable_emergency(&mut self) -> Result<()> {
+        let config = &mut self.config;
+        require!(!config.emergency, StakingError::EmergencyActive);
+
+        config.emergency = true;
+
+        Ok(())
+    }
+
+    pub fn admin_disable_emergency(&mut self) -> Result<()> {
+        let config = &mut self.config;
+        require!(config.emergency, StakingError::EmergencyNotActive);
+
+        config.emergency = false;
+
+        Ok(())
+    }
+}
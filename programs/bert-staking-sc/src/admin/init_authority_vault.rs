@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::{state::Config, StakingError};
+use crate::{
+    state::{Config, Versioned},
+    StakingError,
+};
 
 #[derive(Accounts)]
 pub struct InitializeAuthVault<'info> {
@@ -14,6 +17,7 @@ pub struct InitializeAuthVault<'info> {
         has_one = authority,
         seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
         bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub config: Box<Account<'info, Config>>,
 
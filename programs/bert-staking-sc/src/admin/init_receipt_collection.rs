@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use mpl_core::{instructions::CreateCollectionV2CpiBuilder, ID as CORE_PROGRAM_ID};
+
+use crate::{
+    state::{Config, Versioned},
+    StakingError,
+};
+
+#[derive(Accounts)]
+pub struct InitializeReceiptCollection<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The new receipt collection asset
+    #[account(mut)]
+    pub receipt_collection: Signer<'info>,
+
+    #[account(address = CORE_PROGRAM_ID)]
+    /// CHECK: this will be checked by core
+    pub core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeReceiptCollection<'info> {
+    pub fn initialize_receipt_collection(&mut self, name: String, uri: String) -> Result<()> {
+        require!(
+            self.config.receipt_collection == Pubkey::default(),
+            StakingError::ReceiptCollectionAlreadyInitialized
+        );
+
+        // The config PDA is the update authority, so only the program can add receipts
+        CreateCollectionV2CpiBuilder::new(&self.core_program.to_account_info())
+            .collection(&self.receipt_collection.to_account_info())
+            .update_authority(Some(&self.config.to_account_info()))
+            .payer(&self.authority.to_account_info())
+            .system_program(&self.system_program.to_account_info())
+            .name(name)
+            .uri(uri)
+            .invoke()?;
+
+        self.config.receipt_collection = self.receipt_collection.key();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{state::*, StakingError};
+
+#[derive(Accounts)]
+pub struct InitializeLiquidPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ StakingError::Unauthorized,
+        has_one = mint,
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            config.key().as_ref(),
+            pool.index.to_le_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + LiquidPool::INIT_SPACE,
+        seeds = [b"liquid_pool", pool.key().as_ref()],
+        bump
+    )]
+    pub liquid_pool: Box<Account<'info, LiquidPool>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"share_mint", pool.key().as_ref()],
+        bump,
+        mint::decimals = mint.decimals,
+        mint::authority = pool,
+    )]
+    pub share_mint: Box<Account<'info, Mint>>,
+
+    /// Yield budget of the liquid pool
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"liquid_reserve", pool.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = config,
+    )]
+    pub reserve: Box<Account<'info, TokenAccount>>,
+
+    /// Token mint.
+    pub mint: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeLiquidPool<'info> {
+    pub fn initialize_liquid_pool(
+        &mut self,
+        unbonding_period_days: u16,
+        bumps: &InitializeLiquidPoolBumps,
+    ) -> Result<()> {
+        let pool = &mut self.pool;
+
+        // Only an untouched, open standard pool can be turned into a liquid pool
+        require!(
+            pool.pool_type == PoolType::Standard && !pool.is_allowlisted(),
+            StakingError::InvalidPoolType
+        );
+        require!(
+            pool.total_tokens_staked == 0 && pool.total_nfts_staked == 0,
+            StakingError::PoolNotEmpty
+        );
+
+        pool.pool_type = PoolType::Liquid;
+        pool.unbonding_period_days = unbonding_period_days;
+
+        self.liquid_pool.set_inner(LiquidPool {
+            pool: pool.key(),
+            share_mint: self.share_mint.key(),
+            total_assets: 0,
+            pending_redemptions: 0,
+            last_accrual_at: Clock::get()?.unix_timestamp,
+            reserve: self.reserve.key(),
+            next_ticket_id: 0,
+            bump: bumps.liquid_pool,
+            share_mint_bump: bumps.share_mint,
+            reserve_bump: bumps.reserve,
+            version: LiquidPool::VERSION,
+            _padding: [0; 62],
+        });
+
+        Ok(())
+    }
+}
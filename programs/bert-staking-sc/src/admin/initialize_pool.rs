@@ -1,5 +1,5 @@
 use crate::{
-    state::{Config, Pool},
+    state::{Config, LockMode, Pool, Versioned, BPS_DENOMINATOR},
     StakingError,
 };
 use anchor_lang::prelude::*;
@@ -19,7 +19,8 @@ pub struct InitializePool<'info> {
 
     #[account(
         mut,
-        has_one = authority @ StakingError::Unauthorized
+        has_one = authority @ StakingError::Unauthorized,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub config: Account<'info, Config>,
 
@@ -61,14 +62,22 @@ impl<'info> InitializePool<'info> {
         pool.max_value_cap = max_value_cap;
         pool.is_paused = false;
 
+        // Pools start with a fixed lock; the boost curve is set via pool config
+        pool.lock_mode = LockMode::Fixed;
+        pool.min_lock_days = lock_period_days;
+        pool.max_lock_days = lock_period_days;
+        pool.max_boost_bps = BPS_DENOMINATOR;
+
         // Initialize statistics
         pool.total_nfts_staked = 0;
         pool.total_tokens_staked = 0;
+        pool.total_nft_value = 0;
         pool.lifetime_nfts_staked = 0;
         pool.lifetime_tokens_staked = 0;
         pool.lifetime_claimed_yield = 0;
 
         pool.bump = bumps.pool;
+        pool.version = Pool::VERSION;
 
         // Update pool count in config
         self.config.pool_count = self
@@ -80,4 +89,3 @@ impl<'info> InitializePool<'info> {
         Ok(())
     }
 }
-
@@ -9,3 +9,21 @@ pub use init_authority_vault::*;
 
 pub mod initialize_pool;
 pub use initialize_pool::*;
+
+pub mod init_receipt_collection;
+pub use init_receipt_collection::*;
+
+pub mod initialize_liquid_pool;
+pub use initialize_liquid_pool::*;
+
+pub mod set_crank_tip;
+pub use set_crank_tip::*;
+
+pub mod set_position_limit;
+pub use set_position_limit::*;
+
+pub mod collection_registry;
+pub use collection_registry::*;
+
+pub mod emergency;
+pub use emergency::*;
@@ -0,0 +1,30 @@
+use crate::{state::*, StakingError};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AdminSetCrankTip<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> AdminSetCrankTip<'info> {
+    pub fn admin_set_crank_tip(&mut self, tip_lamports: u64) -> Result<()> {
+        // The tip comes out of the closed position's rent
+        let position_rent = Rent::get()?.minimum_balance(8 + PositionV4::INIT_SPACE);
+        let max_tip =
+            (position_rent as u128 * MAX_CRANK_TIP_BPS as u128 / BPS_DENOMINATOR as u128) as u64;
+        require!(tip_lamports <= max_tip, StakingError::CrankTipTooHigh);
+
+        self.config.crank_tip_lamports = tip_lamports;
+
+        Ok(())
+    }
+}
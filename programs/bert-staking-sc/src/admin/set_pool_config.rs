@@ -11,6 +11,7 @@ pub struct AdminSetPoolConfig<'info> {
         has_one = authority,
         seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
         bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub config: Account<'info, Config>,
 
@@ -22,6 +23,7 @@ pub struct AdminSetPoolConfig<'info> {
             pool.index.to_le_bytes().as_ref()
         ],
         bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub pool: Account<'info, Pool>,
 }
@@ -45,6 +47,20 @@ impl<'info> AdminSetPoolConfig<'info> {
         Ok(())
     }
 
+    /// Sets the pool's staker allowlist root. All zeroes opens the pool.
+    pub fn admin_set_merkle_root(&mut self, merkle_root: [u8; 32]) -> Result<()> {
+        require!(
+            self.pool.pool_type == PoolType::Standard || merkle_root == [0; 32],
+            StakingError::InvalidPoolType
+        );
+
+        self.pool.merkle_root = merkle_root;
+
+        msg!("pool[{}] merkle root: {:?}", self.pool.index, merkle_root);
+
+        Ok(())
+    }
+
     pub fn admin_set_pool_config(&mut self, pool_config_args: PoolConfigArgs) -> Result<()> {
         let pool = &mut self.pool;
         require!(pool.is_paused, StakingError::InvalidPoolPauseState);
@@ -55,6 +71,42 @@ impl<'info> AdminSetPoolConfig<'info> {
         pool.yield_rate = pool_config_args.yield_rate;
         pool.lock_period_days = pool_config_args.lock_period_days;
 
+        if pool_config_args.lock_mode == LockMode::Flexible {
+            require!(
+                pool_config_args.min_lock_days > 0
+                    && pool_config_args.min_lock_days <= pool_config_args.max_lock_days,
+                StakingError::InvalidLockPeriod
+            );
+            require!(
+                pool_config_args.max_boost_bps >= BPS_DENOMINATOR,
+                StakingError::InvalidBoostCurve
+            );
+        }
+
+        pool.lock_mode = pool_config_args.lock_mode;
+        pool.min_lock_days = pool_config_args.min_lock_days;
+        pool.max_lock_days = pool_config_args.max_lock_days;
+        pool.max_boost_bps = pool_config_args.max_boost_bps;
+        pool.nft_boost_bps_per_nft = pool_config_args.nft_boost_bps_per_nft;
+        pool.max_nft_boost_bps = pool_config_args.max_nft_boost_bps;
+
+        require!(
+            pool_config_args.max_stake_per_position == 0
+                || pool_config_args.min_stake_amount <= pool_config_args.max_stake_per_position,
+            StakingError::InvalidAmount
+        );
+
+        pool.min_stake_amount = pool_config_args.min_stake_amount;
+        pool.max_stake_per_position = pool_config_args.max_stake_per_position;
+
+        // On liquid pools the period delays redemptions, fixed at creation
+        require!(
+            pool.pool_type == PoolType::Standard
+                || pool_config_args.unbonding_period_days == pool.unbonding_period_days,
+            StakingError::InvalidPoolType
+        );
+        pool.unbonding_period_days = pool_config_args.unbonding_period_days;
+
         Ok(())
     }
 }
@@ -0,0 +1,25 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AdminSetPositionLimit<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> AdminSetPositionLimit<'info> {
+    /// Sets the maximum number of open positions per user. Zero removes the limit.
+    pub fn admin_set_position_limit(&mut self, max_positions_per_user: u16) -> Result<()> {
+        self.config.max_positions_per_user = max_positions_per_user;
+
+        Ok(())
+    }
+}
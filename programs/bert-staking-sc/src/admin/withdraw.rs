@@ -14,9 +14,10 @@ pub struct AdminWithdrawToken<'info> {
 
     #[account(
         has_one = authority,
-        has_one = authority_vault,
+        has_one = authority_vault @ StakingError::AuthorityVaultNotInitialized,
         seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
         bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub config: Account<'info, Config>,
 
@@ -42,11 +43,6 @@ impl<'info> AdminWithdrawToken<'info> {
     pub fn admin_withdraw_token(&mut self, amount: u64) -> Result<()> {
         let config = &self.config;
 
-        require!(
-            config.authority_vault.key() != Pubkey::default(),
-            StakingError::AuthorityVaultNotInitialized
-        );
-
         let bump = config.bump;
         let authority = config.authority.key();
 
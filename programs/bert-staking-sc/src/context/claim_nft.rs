@@ -1,5 +1,5 @@
 use crate::{state::*, StakingError};
-use anchor_lang::prelude::*;
+use anchor_lang::{error::ErrorCode, prelude::*};
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{Mint, Token, TokenAccount},
@@ -10,10 +10,18 @@ use mpl_core::{
     ID as CORE_PROGRAM_ID,
 };
 
+use super::{burn_position_receipt, resolve_claim_recipient};
+
 #[derive(Accounts)]
 pub struct ClaimPositionNft<'info> {
+    /// The position owner or their delegate, or the receipt holder for
+    /// receipt-backed positions
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// CHECK: Position owner, checked against the position
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub owner: UncheckedAccount<'info>,
 
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -21,9 +29,9 @@ pub struct ClaimPositionNft<'info> {
     #[account(
         mut,
         has_one = mint,
-        has_one = collection,
         seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
         bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub config: Box<Account<'info, Config>>,
 
@@ -35,6 +43,7 @@ pub struct ClaimPositionNft<'info> {
             &pool.index.to_le_bytes(),
         ],
         bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub pool: Box<Account<'info, Pool>>,
 
@@ -42,6 +51,7 @@ pub struct ClaimPositionNft<'info> {
         mut,
         seeds = [b"user", owner.key().as_ref(), config.key().as_ref()],
         bump = user_account.bump,
+        constraint = user_account.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub user_account: Box<Account<'info, UserAccountV3>>,
 
@@ -58,17 +68,44 @@ pub struct ClaimPositionNft<'info> {
 
     #[account(
         mut,
-        seeds = [b"position", owner.key().as_ref(), mint.key().as_ref(), asset.key().as_ref(), position.id.to_le_bytes().as_ref()],
+        seeds = [b"position", owner.key().as_ref(), position.seed_key(mint.key()).as_ref(), position.asset_seed(), position.id.to_le_bytes().as_ref()],
         bump = position.bump,
-        constraint = position.owner == owner.key(),
-        constraint = position.status == PositionStatus::Unclaimed,
+        constraint = position.owner == owner.key() @ StakingError::Unauthorized,
+        constraint = position.status != PositionStatus::Claimed,
         constraint = position.pool == pool.key() @ StakingError::InvalidPositionType,
+        constraint = position.asset == asset.key() @ StakingError::InvalidPositionType,
+        constraint = position.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub position: Box<Account<'info, PositionV4>>,
 
-    /// CHECK: This is checked in config constraint
+    /// CHECK: Checked against the collection entry, or the config's own
+    /// collection without one, and the asset
     pub collection: UncheckedAccount<'info>,
 
+    /// Registry entry of the collection, required unless the NFT was staked
+    /// before collections were registered
+    #[account(
+        mut,
+        has_one = config,
+        has_one = collection,
+        seeds = [b"collection", config.key().as_ref(), collection.key().as_ref()],
+        bump = collection_entry.bump,
+    )]
+    pub collection_entry: Option<Box<Account<'info, CollectionEntry>>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + UserCollectionStats::INIT_SPACE,
+        seeds = [
+            b"user_collection_stats",
+            owner.key().as_ref(),
+            collection_entry.as_ref().map(|entry| entry.key()).unwrap_or_default().as_ref(),
+        ],
+        bump
+    )]
+    pub user_collection_stats: Option<Box<Account<'info, UserCollectionStats>>>,
+
     /// CHECK: Used for plugin updates
     pub update_authority: UncheckedAccount<'info>,
 
@@ -82,10 +119,10 @@ pub struct ClaimPositionNft<'info> {
     /// Token mint.
     pub mint: Account<'info, Mint>,
 
+    /// Recipient token account, checked against the claim recipient
     #[account(
         mut,
-        associated_token::mint = mint,
-        associated_token::authority = owner,
+        token::mint = mint,
     )]
     pub token_account: Account<'info, TokenAccount>,
 
@@ -103,6 +140,14 @@ pub struct ClaimPositionNft<'info> {
     )]
     pub authority_vault: Account<'info, TokenAccount>,
 
+    /// Receipt asset, required for receipt-backed positions
+    #[account(mut)]
+    pub receipt: Option<Box<Account<'info, BaseAssetV1>>>,
+
+    /// CHECK: Checked against the config's receipt collection
+    #[account(mut, address = config.receipt_collection @ StakingError::InvalidReceipt)]
+    pub receipt_collection: Option<UncheckedAccount<'info>>,
+
     #[account(address = CORE_PROGRAM_ID)]
     /// CHECK: this will be checked by core
     pub core_program: UncheckedAccount<'info>,
@@ -113,11 +158,24 @@ pub struct ClaimPositionNft<'info> {
 }
 
 impl<'info> ClaimPositionNft<'info> {
-    pub fn claim_nft(&mut self) -> Result<()> {
-        // Check if position is unlocked
-        let current_time = Clock::get()?.unix_timestamp;
-        if current_time < self.position.unlock_time {
-            return Err(StakingError::PositionLocked.into());
+    pub fn claim_nft(&mut self, bumps: &ClaimPositionNftBumps) -> Result<()> {
+        self.withdraw_nft(bumps, false)
+    }
+
+    /// Returns the NFT while the config is in emergency mode, ignoring the
+    /// position's lock and any cooldown. The position's yield is forfeited.
+    pub fn emergency_withdraw_nft(&mut self, bumps: &ClaimPositionNftBumps) -> Result<()> {
+        require!(self.config.emergency, StakingError::EmergencyNotActive);
+
+        self.withdraw_nft(bumps, true)
+    }
+
+    fn withdraw_nft(&mut self, bumps: &ClaimPositionNftBumps, emergency: bool) -> Result<()> {
+        // Check if position is unlocked and any cooldown has elapsed
+        if !emergency {
+            let current_time = Clock::get()?.unix_timestamp;
+            self.position
+                .check_withdrawable(self.pool.unbonding_period_days, current_time)?;
         }
 
         require!(
@@ -125,23 +183,48 @@ impl<'info> ClaimPositionNft<'info> {
             StakingError::InvalidPositionType
         );
 
+        // Proceeds go to the owner, or to the receipt holder if a receipt was minted
+        let recipient = resolve_claim_recipient(
+            &self.position,
+            &self.user_account,
+            self.claimant.key(),
+            self.receipt.as_deref(),
+        )?;
+        require!(
+            self.token_account.owner == recipient,
+            StakingError::Unauthorized
+        );
+
+        // Redeeming the position consumes its receipt
+        if self.position.receipt != Pubkey::default() {
+            let receipt = self.receipt.as_ref().ok_or(StakingError::InvalidReceipt)?;
+            let receipt_collection = self
+                .receipt_collection
+                .as_ref()
+                .ok_or(StakingError::InvalidReceipt)?;
+
+            burn_position_receipt(
+                &self.core_program.to_account_info(),
+                &receipt.to_account_info(),
+                &receipt_collection.to_account_info(),
+                &self.claimant.to_account_info(),
+                &self.system_program.to_account_info(),
+            )?;
+        }
+
         // Get references to main accounts
         let config = &mut self.config;
         let pool = &mut self.pool;
         let user_pool_stats = &mut self.user_pool_stats;
         let position = &mut self.position;
 
-        // Calculate yield based on position type and pool config
-        let position_amount = position.amount;
-        let yield_rate = pool.yield_rate;
-        let base_amount = position_amount;
-        let yield_value = (base_amount as u128)
-            .checked_mul(yield_rate as u128)
-            .ok_or(StakingError::ArithmeticOverflow)?
-            .checked_div(SCALING_FACTOR as u128)
-            .ok_or(StakingError::ArithmeticOverflow)?
-            .try_into()
-            .map_err(|_| StakingError::ArithmeticOverflow)?;
+        // Calculate yield based on position type and pool config. Emergency
+        // withdrawals return the NFT only.
+        let yield_value = if emergency {
+            0
+        } else {
+            position.term_yield(pool.yield_rate)?
+        };
 
         // Prepare common values for transfers
         let bump = config.bump;
@@ -175,11 +258,17 @@ impl<'info> ClaimPositionNft<'info> {
 
         msg!("Yield of {} transferred from authority vault", yield_value);
 
-        // Transfer The asset back to owner:
+        // Transfer The asset back to the recipient:
+        let recipient_info = if recipient == self.owner.key() {
+            self.owner.to_account_info()
+        } else {
+            self.claimant.to_account_info()
+        };
+
         TransferV1CpiBuilder::new(&self.core_program.to_account_info())
             .asset(&self.asset.to_account_info())
             .authority(Some(&config.to_account_info()))
-            .new_owner(&self.owner.to_account_info())
+            .new_owner(&recipient_info)
             .payer(&self.payer.to_account_info())
             .collection(Some(&self.collection.to_account_info()))
             .system_program(Some(&self.system_program.to_account_info()))
@@ -191,7 +280,7 @@ impl<'info> ClaimPositionNft<'info> {
         // Update config's total staked amount
         config.total_staked_amount = config
             .total_staked_amount
-            .checked_sub(config.nft_value_in_tokens)
+            .checked_sub(position.amount)
             .ok_or(ProgramError::ArithmeticOverflow)?;
 
         // Update pool statistics
@@ -200,6 +289,10 @@ impl<'info> ClaimPositionNft<'info> {
             .checked_sub(1)
             .ok_or(StakingError::ArithmeticOverflow)?;
 
+        // NFTs from migrated pools were valued at the config's NFT value at
+        // the time, which may since have changed
+        pool.total_nft_value = pool.total_nft_value.saturating_sub(position.amount);
+
         pool.lifetime_claimed_yield = pool
             .lifetime_claimed_yield
             .checked_add(yield_value)
@@ -223,7 +316,7 @@ impl<'info> ClaimPositionNft<'info> {
 
         user_pool_stats.total_value = user_pool_stats
             .total_value
-            .checked_sub(config.nft_value_in_tokens)
+            .checked_sub(position.amount)
             .ok_or(StakingError::ArithmeticOverflow)?;
 
         user_pool_stats.claimed_yield = user_pool_stats
@@ -231,8 +324,36 @@ impl<'info> ClaimPositionNft<'info> {
             .checked_add(yield_value)
             .ok_or(StakingError::ArithmeticOverflow)?;
 
+        // Release the NFT from its collection's counts. NFTs staked before
+        // collections were registered were never counted, and can only come
+        // from the config's own collection.
+        if position.collection_counted {
+            let collection_entry = self
+                .collection_entry
+                .as_mut()
+                .ok_or(ErrorCode::ConstraintAccountIsNone)?;
+            let user_collection_stats = self
+                .user_collection_stats
+                .as_mut()
+                .ok_or(ErrorCode::ConstraintAccountIsNone)?;
+
+            collection_entry.record_claim(user_collection_stats)?;
+            user_collection_stats.user = self.owner.key();
+            user_collection_stats.collection_entry = collection_entry.key();
+            if let Some(bump) = bumps.user_collection_stats {
+                user_collection_stats.bump = bump;
+            }
+        } else {
+            require_keys_eq!(
+                self.collection.key(),
+                config.collection,
+                ErrorCode::ConstraintAccountIsNone
+            );
+        }
+
         // Update global user stats
         let user_account = &mut self.user_account;
+        user_account.close_position(position);
         user_account.total_staked_nfts = user_account
             .total_staked_nfts
             .checked_sub(1)
@@ -240,7 +361,7 @@ impl<'info> ClaimPositionNft<'info> {
 
         user_account.total_staked_value = user_account
             .total_staked_value
-            .checked_sub(config.nft_value_in_tokens)
+            .checked_sub(position.amount)
             .ok_or(StakingError::ArithmeticOverflow)?;
 
         user_account.total_claimed_yield = user_account
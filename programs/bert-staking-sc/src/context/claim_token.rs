@@ -4,11 +4,19 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token::{Mint, Token, TokenAccount},
 };
+use mpl_core::{accounts::BaseAssetV1, ID as CORE_PROGRAM_ID};
+
+use super::{burn_position_receipt, resolve_claim_recipient};
 
 #[derive(Accounts)]
 pub struct ClaimPositionToken<'info> {
+    /// The position owner or their delegate, or the receipt holder for
+    /// receipt-backed positions
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub claimant: Signer<'info>,
+
+    /// CHECK: Position owner, checked against the position
+    pub owner: UncheckedAccount<'info>,
 
     #[account(
         mut,
@@ -16,6 +24,7 @@ pub struct ClaimPositionToken<'info> {
         has_one = collection,
         seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
         bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub config: Box<Account<'info, Config>>,
 
@@ -27,6 +36,7 @@ pub struct ClaimPositionToken<'info> {
             &pool.index.to_le_bytes(),
         ],
         bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub pool: Box<Account<'info, Pool>>,
 
@@ -34,6 +44,7 @@ pub struct ClaimPositionToken<'info> {
         mut,
         seeds = [b"user", owner.key().as_ref(), config.key().as_ref()],
         bump = user_account.bump,
+        constraint = user_account.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub user_account: Box<Account<'info, UserAccountV3>>,
 
@@ -50,11 +61,12 @@ pub struct ClaimPositionToken<'info> {
 
     #[account(
         mut,
-        seeds = [b"position", owner.key().as_ref(), mint.key().as_ref(), position.id.to_le_bytes().as_ref()],
+        seeds = [b"position", owner.key().as_ref(), position.seed_key(mint.key()).as_ref(), position.asset_seed(), position.id.to_le_bytes().as_ref()],
         bump = position.bump,
-        constraint = position.owner == owner.key(),
-        constraint = position.status == PositionStatus::Unclaimed,
+        constraint = position.owner == owner.key() @ StakingError::Unauthorized,
+        constraint = position.status != PositionStatus::Claimed,
         constraint = position.pool == pool.key() @ StakingError::InvalidPositionType,
+        constraint = position.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub position: Box<Account<'info, PositionV4>>,
 
@@ -64,10 +76,10 @@ pub struct ClaimPositionToken<'info> {
     /// Token mint.
     pub mint: Account<'info, Mint>,
 
+    /// Recipient token account, checked against the claim recipient
     #[account(
         mut,
-        associated_token::mint = mint,
-        associated_token::authority = owner,
+        token::mint = mint,
     )]
     pub token_account: Account<'info, TokenAccount>,
 
@@ -85,6 +97,18 @@ pub struct ClaimPositionToken<'info> {
     )]
     pub authority_vault: Account<'info, TokenAccount>,
 
+    /// Receipt asset, required for receipt-backed positions
+    #[account(mut)]
+    pub receipt: Option<Box<Account<'info, BaseAssetV1>>>,
+
+    /// CHECK: Checked against the config's receipt collection
+    #[account(mut, address = config.receipt_collection @ StakingError::InvalidReceipt)]
+    pub receipt_collection: Option<UncheckedAccount<'info>>,
+
+    #[account(address = CORE_PROGRAM_ID)]
+    /// CHECK: this will be checked by core
+    pub core_program: Option<UncheckedAccount<'info>>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -92,10 +116,42 @@ pub struct ClaimPositionToken<'info> {
 
 impl<'info> ClaimPositionToken<'info> {
     pub fn claim_token(&mut self) -> Result<()> {
-        // Check if position is unlocked
-        let current_time = Clock::get()?.unix_timestamp;
-        if current_time < self.position.unlock_time {
-            return Err(StakingError::PositionLocked.into());
+        let amount = self.position.amount;
+        self.withdraw_token(amount, false)
+    }
+
+    /// Withdraws part of an unlocked position. The remainder stays staked and
+    /// keeps its claim to yield on the remaining amount.
+    pub fn claim_token_partial(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount > 0 && amount < self.position.amount,
+            StakingError::InvalidAmount
+        );
+
+        // The remainder must still be a valid position
+        self.pool
+            .check_stake_amount(self.position.amount - amount)?;
+
+        self.withdraw_token(amount, false)
+    }
+
+    /// Withdraws the whole position while the config is in emergency mode,
+    /// ignoring its lock and any cooldown. The position's yield is forfeited.
+    pub fn emergency_withdraw_token(&mut self) -> Result<()> {
+        require!(self.config.emergency, StakingError::EmergencyNotActive);
+
+        let amount = self.position.amount;
+        self.withdraw_token(amount, true)
+    }
+
+    fn withdraw_token(&mut self, amount: u64, emergency: bool) -> Result<()> {
+        let is_full_withdrawal = amount == self.position.amount;
+
+        // Check if position is unlocked and any cooldown has elapsed
+        if !emergency {
+            let current_time = Clock::get()?.unix_timestamp;
+            self.position
+                .check_withdrawable(self.pool.unbonding_period_days, current_time)?;
         }
 
         require!(
@@ -103,21 +159,56 @@ impl<'info> ClaimPositionToken<'info> {
             StakingError::InvalidPositionType
         );
 
+        // Proceeds go to the owner, or to the receipt holder if a receipt was minted
+        let recipient = resolve_claim_recipient(
+            &self.position,
+            &self.user_account,
+            self.claimant.key(),
+            self.receipt.as_deref(),
+        )?;
+        require!(
+            self.token_account.owner == recipient,
+            StakingError::Unauthorized
+        );
+
+        // Redeeming the whole position consumes its receipt
+        if is_full_withdrawal && self.position.receipt != Pubkey::default() {
+            let receipt = self.receipt.as_ref().ok_or(StakingError::InvalidReceipt)?;
+            let receipt_collection = self
+                .receipt_collection
+                .as_ref()
+                .ok_or(StakingError::InvalidReceipt)?;
+            let core_program = self
+                .core_program
+                .as_ref()
+                .ok_or(StakingError::InvalidReceipt)?;
+
+            burn_position_receipt(
+                &core_program.to_account_info(),
+                &receipt.to_account_info(),
+                &receipt_collection.to_account_info(),
+                &self.claimant.to_account_info(),
+                &self.system_program.to_account_info(),
+            )?;
+        }
+
         // Get references to main accounts
         let config = &mut self.config;
         let pool = &mut self.pool;
         let user_pool_stats = &mut self.user_pool_stats;
         let position = &mut self.position;
 
-        // Calculate yield based on position type and pool config
-        let position_amount = position.amount;
-        let yield_value = (position_amount as u128)
-            .checked_mul(pool.yield_rate as u128)
-            .ok_or(StakingError::ArithmeticOverflow)?
-            .checked_div(SCALING_FACTOR as u128)
-            .ok_or(StakingError::ArithmeticOverflow)?
-            .try_into()
-            .map_err(|_| StakingError::ArithmeticOverflow)?;
+        // Calculate yield on the withdrawn amount based on pool config.
+        // Emergency withdrawals return the principal only.
+        let position_amount = amount;
+        let yield_value = if emergency {
+            0
+        } else {
+            let base_yield = position.term_yield_on(position_amount, pool.yield_rate)?;
+
+            // Boost token yield by the NFTs the user has staked in the same pool
+            pool.apply_nft_boost(base_yield, user_pool_stats.nfts_staked)?
+        };
 
         // Prepare common values for transfers
         let bump = config.bump;
@@ -166,84 +257,111 @@ impl<'info> ClaimPositionToken<'info> {
 
         msg!("Yield of {} transferred from authority vault", yield_value);
 
-        // Update position status to claimed
-        position.status = PositionStatus::Claimed;
-
-        // Update config's total staked amount
-        config.total_staked_amount = config
-            .total_staked_amount
-            .checked_sub(position_amount)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-
-        // Update pool statistics
-        pool.total_tokens_staked = pool
-            .total_tokens_staked
-            .checked_sub(position_amount)
-            .ok_or(StakingError::ArithmeticOverflow)?;
-
-        pool.lifetime_claimed_yield = pool
-            .lifetime_claimed_yield
-            .checked_add(yield_value)
-            .ok_or(StakingError::ArithmeticOverflow)?;
-
-        msg!(
-            "pool_stats: lpd: {:?} | tns: {:?} | tss: {:?} | lns: {:?} | lts: {:?} | lcy: {:?}",
-            pool.lock_period_days,
-            pool.total_nfts_staked,
-            pool.total_tokens_staked,
-            pool.lifetime_nfts_staked,
-            pool.lifetime_tokens_staked,
-            pool.lifetime_claimed_yield
-        );
-
-        // Update user pool stats
-        user_pool_stats.tokens_staked = user_pool_stats
-            .tokens_staked
-            .checked_sub(position_amount)
-            .ok_or(StakingError::ArithmeticOverflow)?;
-
-        user_pool_stats.total_value = user_pool_stats
-            .total_value
-            .checked_sub(position_amount)
-            .ok_or(StakingError::ArithmeticOverflow)?;
-
-        user_pool_stats.claimed_yield = user_pool_stats
-            .claimed_yield
-            .checked_add(yield_value)
-            .ok_or(StakingError::ArithmeticOverflow)?;
-
-        // Update global user stats
-        let user_account = &mut self.user_account;
-        user_account.total_staked_token_amount = user_account
-            .total_staked_token_amount
-            .checked_sub(position_amount)
-            .ok_or(StakingError::ArithmeticOverflow)?;
-
-        user_account.total_staked_value = user_account
-            .total_staked_value
-            .checked_sub(position_amount)
-            .ok_or(StakingError::ArithmeticOverflow)?;
-
-        user_account.total_claimed_yield = user_account
-            .total_claimed_yield
-            .checked_add(yield_value)
-            .ok_or(StakingError::ArithmeticOverflow)?;
-
-        msg!(
-            "user_account: tsta {:?} | tsv: {:?} | tsn: {:?} | tcy: {:?}",
-            user_account.total_staked_token_amount,
-            user_account.total_staked_value,
-            user_account.total_staked_nfts,
-            user_account.total_claimed_yield
-        );
-
-        msg!(
-            "user_pool_stats: tokens: {:?} | value: {:?} | yield: {:?}",
-            user_pool_stats.tokens_staked,
-            user_pool_stats.total_value,
-            user_pool_stats.claimed_yield
-        );
+        // Close out the position, or shrink it on a partial withdrawal
+        if is_full_withdrawal {
+            position.status = PositionStatus::Claimed;
+            self.user_account.close_position(position);
+        } else {
+            position.amount = position
+                .amount
+                .checked_sub(position_amount)
+                .ok_or(StakingError::ArithmeticOverflow)?;
+        }
 
-        Ok(())
+        record_token_claim(
+            config,
+            pool,
+            user_pool_stats,
+            &mut self.user_account,
+            position_amount,
+            yield_value,
+        )
     }
 }
+
+/// Records a token withdrawal of `amount` paying `yield_value` across the
+/// config, pool, user pool stats and user account counters
+pub fn record_token_claim(
+    config: &mut Config,
+    pool: &mut Pool,
+    user_pool_stats: &mut UserPoolStatsAccount,
+    user_account: &mut UserAccountV3,
+    amount: u64,
+    yield_value: u64,
+) -> Result<()> {
+    // Update config's total staked amount
+    config.total_staked_amount = config
+        .total_staked_amount
+        .checked_sub(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Update pool statistics
+    pool.total_tokens_staked = pool
+        .total_tokens_staked
+        .checked_sub(amount)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+
+    pool.lifetime_claimed_yield = pool
+        .lifetime_claimed_yield
+        .checked_add(yield_value)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+
+    msg!(
+        "pool_stats: lpd: {:?} | tns: {:?} | tss: {:?} | lns: {:?} | lts: {:?} | lcy: {:?}",
+        pool.lock_period_days,
+        pool.total_nfts_staked,
+        pool.total_tokens_staked,
+        pool.lifetime_nfts_staked,
+        pool.lifetime_tokens_staked,
+        pool.lifetime_claimed_yield
+    );
+
+    // Update user pool stats
+    user_pool_stats.tokens_staked = user_pool_stats
+        .tokens_staked
+        .checked_sub(amount)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+
+    user_pool_stats.total_value = user_pool_stats
+        .total_value
+        .checked_sub(amount)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+
+    user_pool_stats.claimed_yield = user_pool_stats
+        .claimed_yield
+        .checked_add(yield_value)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+
+    // Update global user stats
+    user_account.total_staked_token_amount = user_account
+        .total_staked_token_amount
+        .checked_sub(amount)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+
+    user_account.total_staked_value = user_account
+        .total_staked_value
+        .checked_sub(amount)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+
+    user_account.total_claimed_yield = user_account
+        .total_claimed_yield
+        .checked_add(yield_value)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+
+    msg!(
+        "user_account: tsta {:?} | tsv: {:?} | tsn: {:?} | tcy: {:?}",
+        user_account.total_staked_token_amount,
+        user_account.total_staked_value,
+        user_account.total_staked_nfts,
+        user_account.total_claimed_yield
+    );
+
+    msg!(
+        "user_pool_stats: tokens: {:?} | value: {:?} | yield: {:?}",
+        user_pool_stats.tokens_staked,
+        user_pool_stats.total_value,
+        user_pool_stats.claimed_yield
+    );
+
+    Ok(())
+}
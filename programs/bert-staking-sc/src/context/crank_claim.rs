@@ -0,0 +1,186 @@
+use crate::{state::*, StakingError};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use super::record_token_claim;
+
+#[derive(Accounts)]
+pub struct CrankClaim<'info> {
+    /// Anyone can settle a matured position and earn the crank tip
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    /// CHECK: Position owner, checked against the position. Receives the
+    /// position's rent refund minus the tip.
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = mint,
+        has_one = vault,
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            config.key().as_ref(),
+            pool.index.to_le_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref(), config.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub user_account: Box<Account<'info, UserAccountV3>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"user_pool_stats",
+            owner.key().as_ref(),
+            pool.key().as_ref(),
+        ],
+        bump = user_pool_stats.bump,
+    )]
+    pub user_pool_stats: Box<Account<'info, UserPoolStatsAccount>>,
+
+    #[account(
+        mut,
+        constraint = position.owner == owner.key() @ StakingError::Unauthorized,
+        constraint = position.status != PositionStatus::Claimed,
+        constraint = position.pool == pool.key() @ StakingError::InvalidPositionType,
+        constraint = position.position_type == PositionType::Token @ StakingError::InvalidPositionType,
+        constraint = position.receipt == Pubkey::default() @ StakingError::PositionHasReceipt,
+        constraint = position.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub position: Box<Account<'info, PositionV4>>,
+
+    /// Token mint.
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = config,
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"authority_vault", config.key().as_ref(), mint.key().as_ref()],
+        bump = config.authority_vault_bump,
+    )]
+    pub authority_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CrankClaim<'info> {
+    /// Settles a matured token position to its owner and closes it. The
+    /// cranker is paid `config.crank_tip_lamports` out of the position rent.
+    pub fn crank_claim(&mut self) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        self.position
+            .check_withdrawable(self.pool.unbonding_period_days, current_time)?;
+
+        let config = &mut self.config;
+        let pool = &mut self.pool;
+        let user_pool_stats = &mut self.user_pool_stats;
+
+        let position_amount = self.position.amount;
+        let base_yield = self.position.term_yield(pool.yield_rate)?;
+        let yield_value = pool.apply_nft_boost(base_yield, user_pool_stats.nfts_staked)?;
+
+        require!(
+            self.authority_vault.amount >= yield_value,
+            StakingError::InsufficientYieldFunds
+        );
+
+        let bump = config.bump;
+        let authority = config.authority.key();
+        let id = config.id.to_le_bytes();
+        let seeds = &[b"config".as_ref(), authority.as_ref(), id.as_ref(), &[bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        // Principal from the main vault
+        anchor_spl::token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: self.vault.to_account_info(),
+                    to: self.token_account.to_account_info(),
+                    authority: config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            position_amount,
+        )?;
+
+        // Yield from the authority vault
+        anchor_spl::token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: self.authority_vault.to_account_info(),
+                    to: self.token_account.to_account_info(),
+                    authority: config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            yield_value,
+        )?;
+        self.user_account.close_position(&self.position);
+
+        msg!("Yield of {} transferred from authority vault", yield_value);
+
+        let tip = config.crank_tip_lamports;
+
+        record_token_claim(
+            config,
+            pool,
+            user_pool_stats,
+            &mut self.user_account,
+            position_amount,
+            yield_value,
+        )?;
+
+        // Pay the cranker from the rent refund, then close the position to the owner
+        let position_info = self.position.to_account_info();
+        let tip = tip.min(position_info.lamports());
+        if tip > 0 {
+            **position_info.try_borrow_mut_lamports()? -= tip;
+            **self.cranker.to_account_info().try_borrow_mut_lamports()? += tip;
+        }
+
+        self.position.close(self.owner.to_account_info())?;
+
+        msg!("position settled by crank | tip: {:?}", tip);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,378 @@
+use crate::{state::*, StakingError};
+use anchor_lang::{error::ErrorCode, prelude::*};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct ExtendLock<'info> {
+    /// The position owner or their delegate. Settled yield still goes only
+    /// to the owner's token account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Position owner, checked against the position and user account.
+    /// Receives the rent of a position closed by a pool move.
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        has_one = mint,
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// Pool the position currently belongs to
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            config.key().as_ref(),
+            pool.index.to_le_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Pool the position moves into. Omit it to relock in the same pool.
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            config.key().as_ref(),
+            target_pool.index.to_le_bytes().as_ref()
+        ],
+        bump = target_pool.bump,
+        constraint = target_pool.key() != pool.key() @ StakingError::SameTargetPool,
+        constraint = target_pool.pool_type == PoolType::Standard @ StakingError::InvalidPoolType,
+        constraint = !target_pool.is_allowlisted() @ StakingError::NotAllowlisted,
+        constraint = target_pool.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub target_pool: Option<Box<Account<'info, Pool>>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref(), config.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.is_owner_or_delegate(owner.key(), authority.key()) @ StakingError::Unauthorized,
+        constraint = user_account.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub user_account: Box<Account<'info, UserAccountV3>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"user_pool_stats",
+            owner.key().as_ref(),
+            pool.key().as_ref(),
+        ],
+        bump = user_pool_stats.bump,
+    )]
+    pub user_pool_stats: Box<Account<'info, UserPoolStatsAccount>>,
+
+    /// Required with `target_pool`
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + UserPoolStatsAccount::INIT_SPACE,
+        seeds = [
+            b"user_pool_stats",
+            owner.key().as_ref(),
+            target_pool.as_ref().map(|pool| pool.key()).unwrap_or_default().as_ref(),
+        ],
+        bump
+    )]
+    pub target_user_pool_stats: Option<Box<Account<'info, UserPoolStatsAccount>>>,
+
+    #[account(
+        mut,
+        constraint = position.owner == owner.key() @ StakingError::Unauthorized,
+        constraint = position.status == PositionStatus::Unclaimed,
+        constraint = position.pool == pool.key() @ StakingError::InvalidPositionType,
+        constraint = position.receipt == Pubkey::default() @ StakingError::PositionHasReceipt,
+        constraint = position.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub position: Box<Account<'info, PositionV4>>,
+
+    /// The position re-keyed under `target_pool`, required with it. Position
+    /// seeds carry the pool, so a moved position gets the owner's next id
+    /// there and the old account is closed.
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PositionV4::INIT_SPACE,
+        seeds = [
+            b"position",
+            owner.key().as_ref(),
+            target_pool.as_ref().map(|pool| pool.key()).unwrap_or_default().as_ref(),
+            user_account.next_position_id.to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub new_position: Option<Box<Account<'info, PositionV4>>>,
+
+    /// Token mint.
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"authority_vault", config.key().as_ref(), mint.key().as_ref()],
+        bump = config.authority_vault_bump,
+    )]
+    pub authority_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExtendLock<'info> {
+    pub fn extend_lock(
+        &mut self,
+        lock_period_days: Option<u16>,
+        bumps: &ExtendLockBumps,
+    ) -> Result<()> {
+        require!(
+            self.target_pool.is_some() == self.new_position.is_some(),
+            ErrorCode::ConstraintAccountIsNone
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        let config = &self.config;
+        let pool = &mut self.pool;
+        let user_pool_stats = &mut self.user_pool_stats;
+        let position = &mut self.position;
+
+        // Extending restakes the position, which emergency mode halts
+        require!(!config.emergency, StakingError::EmergencyActive);
+
+        // Only relock into an active pool, under its lock terms
+        let lock_pool = self.target_pool.as_ref().unwrap_or(&*pool);
+        require!(!lock_pool.is_paused, StakingError::PoolAlreadyPaused);
+
+        // Resolve the new lock terms and make sure they actually extend the lock
+        let (lock_days, yield_multiplier_bps) = lock_pool.resolve_lock(lock_period_days)?;
+        let new_unlock_time = current_time + (lock_days as i64 * 86400);
+        require!(
+            new_unlock_time > position.unlock_time,
+            StakingError::LockNotExtended
+        );
+
+        // Settle the yield accrued so far under the old pool terms
+        let mut yield_value = position.accrued_yield(pool.yield_rate, current_time)?;
+        if position.position_type == PositionType::Token {
+            yield_value = pool.apply_nft_boost(yield_value, user_pool_stats.nfts_staked)?;
+        }
+
+        if yield_value > 0 {
+            require!(
+                self.authority_vault.amount >= yield_value,
+                StakingError::InsufficientYieldFunds
+            );
+
+            let bump = config.bump;
+            let authority = config.authority.key();
+            let id = config.id.to_le_bytes();
+            let seeds = &[b"config".as_ref(), authority.as_ref(), id.as_ref(), &[bump]];
+            let signer_seeds = &[&seeds[..]];
+
+            anchor_spl::token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    anchor_spl::token::Transfer {
+                        from: self.authority_vault.to_account_info(),
+                        to: self.token_account.to_account_info(),
+                        authority: config.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                yield_value,
+            )?;
+
+            msg!("Yield of {} transferred from authority vault", yield_value);
+        }
+
+        pool.lifetime_claimed_yield = pool
+            .lifetime_claimed_yield
+            .checked_add(yield_value)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        user_pool_stats.claimed_yield = user_pool_stats
+            .claimed_yield
+            .checked_add(yield_value)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        if let Some(target_pool) = self.target_pool.as_mut() {
+            let target_user_pool_stats = self
+                .target_user_pool_stats
+                .as_mut()
+                .ok_or(ErrorCode::ConstraintAccountIsNone)?;
+
+            // Move the position's value from the old pool to the target pool
+            let position_amount = position.amount;
+            let new_target_total_value = target_user_pool_stats
+                .total_value
+                .checked_add(position_amount)
+                .ok_or(StakingError::ArithmeticOverflow)?;
+
+            // Check user has not exceeded the target pool's max token cap
+            require!(
+                new_target_total_value <= target_pool.max_tokens_cap,
+                StakingError::UserTokensLimitCapReached
+            );
+
+            match position.position_type {
+                PositionType::Token => {
+                    target_pool.check_stake_amount(position_amount)?;
+
+                    let new_target_tokens_staked = target_pool
+                        .total_tokens_staked
+                        .checked_add(position_amount)
+                        .ok_or(StakingError::ArithmeticOverflow)?;
+
+                    let total_pool_value = target_pool
+                        .total_value()?
+                        .checked_add(position_amount)
+                        .ok_or(StakingError::ArithmeticOverflow)?;
+
+                    require!(
+                        total_pool_value <= target_pool.max_value_cap,
+                        StakingError::PoolValueLimitReached
+                    );
+
+                    pool.total_tokens_staked = pool
+                        .total_tokens_staked
+                        .checked_sub(position_amount)
+                        .ok_or(StakingError::ArithmeticOverflow)?;
+                    target_pool.total_tokens_staked = new_target_tokens_staked;
+                    target_pool.lifetime_tokens_staked = target_pool
+                        .lifetime_tokens_staked
+                        .checked_add(position_amount)
+                        .ok_or(StakingError::ArithmeticOverflow)?;
+
+                    user_pool_stats.tokens_staked = user_pool_stats
+                        .tokens_staked
+                        .checked_sub(position_amount)
+                        .ok_or(StakingError::ArithmeticOverflow)?;
+                    target_user_pool_stats.tokens_staked = target_user_pool_stats
+                        .tokens_staked
+                        .checked_add(position_amount)
+                        .ok_or(StakingError::ArithmeticOverflow)?;
+                }
+                PositionType::NFT => {
+                    let new_target_nfts_staked = target_user_pool_stats
+                        .nfts_staked
+                        .checked_add(1)
+                        .ok_or(StakingError::ArithmeticOverflow)?;
+
+                    require!(
+                        new_target_nfts_staked <= target_pool.max_nfts_cap,
+                        StakingError::NftLimitReached
+                    );
+
+                    let total_pool_value = target_pool
+                        .total_value()?
+                        .checked_add(position_amount)
+                        .ok_or(StakingError::ArithmeticOverflow)?;
+
+                    require!(
+                        total_pool_value <= target_pool.max_value_cap,
+                        StakingError::PoolValueLimitReached
+                    );
+
+                    pool.total_nfts_staked = pool
+                        .total_nfts_staked
+                        .checked_sub(1)
+                        .ok_or(StakingError::ArithmeticOverflow)?;
+                    target_pool.total_nfts_staked = target_pool
+                        .total_nfts_staked
+                        .checked_add(1)
+                        .ok_or(StakingError::ArithmeticOverflow)?;
+                    pool.total_nft_value = pool.total_nft_value.saturating_sub(position_amount);
+                    target_pool.total_nft_value = target_pool
+                        .total_nft_value
+                        .checked_add(position_amount)
+                        .ok_or(StakingError::ArithmeticOverflow)?;
+                    target_pool.lifetime_nfts_staked = target_pool
+                        .lifetime_nfts_staked
+                        .checked_add(1)
+                        .ok_or(StakingError::ArithmeticOverflow)?;
+
+                    user_pool_stats.nfts_staked = user_pool_stats
+                        .nfts_staked
+                        .checked_sub(1)
+                        .ok_or(StakingError::ArithmeticOverflow)?;
+                    target_user_pool_stats.nfts_staked = new_target_nfts_staked;
+                }
+            }
+
+            user_pool_stats.total_value = user_pool_stats
+                .total_value
+                .checked_sub(position_amount)
+                .ok_or(StakingError::ArithmeticOverflow)?;
+            target_user_pool_stats.total_value = new_target_total_value;
+
+            target_user_pool_stats.user = self.owner.key();
+            target_user_pool_stats.pool = target_pool.key();
+            if let Some(bump) = bumps.target_user_pool_stats {
+                target_user_pool_stats.bump = bump;
+            }
+        }
+
+        // Update global user stats
+        let user_account = &mut self.user_account;
+        user_account.total_claimed_yield = user_account
+            .total_claimed_yield
+            .checked_add(yield_value)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        // Restart the position under the new terms
+        position.deposit_time = current_time;
+        position.last_claimed_at = current_time;
+        position.unlock_time = new_unlock_time;
+        position.lock_period_days = lock_days;
+        position.yield_multiplier_bps = yield_multiplier_bps;
+
+        // Re-key a moved position under the target pool; the old account is closed
+        if let (Some(target_pool), Some(new_position)) =
+            (self.target_pool.as_ref(), self.new_position.as_mut())
+        {
+            user_account.close_position(position);
+            let new_id = user_account.open_position(config.max_positions_per_user)?;
+
+            let mut moved = PositionV4::clone(position);
+            moved.pool = target_pool.key();
+            moved.id = new_id;
+            moved.bump = bumps
+                .new_position
+                .ok_or(ErrorCode::ConstraintAccountIsNone)?;
+            moved.seed_version = POSITION_SEED_VERSION;
+            new_position.set_inner(moved);
+
+            position.close(self.owner.to_account_info())?;
+        }
+
+        let position = self.new_position.as_deref().unwrap_or(&self.position);
+        msg!(
+            "position: pool: {:?} | unlock: {:?} | lock_days: {:?} | multiplier: {:?}",
+            position.pool,
+            position.unlock_time,
+            position.lock_period_days,
+            position.yield_multiplier_bps
+        );
+
+        Ok(())
+    }
+}
@@ -84,7 +84,16 @@ impl<'info> Initialize<'info> {
             bump: bumps.config,
             authority_vault_bump: 0,
 
-            _padding: [0; 96],
+            receipt_collection: Pubkey::default(),
+
+            crank_tip_lamports: 0,
+            max_positions_per_user: 0,
+
+            version: Config::VERSION,
+
+            emergency: false,
+
+            _padding: [0; 52],
         });
 
         Ok(())
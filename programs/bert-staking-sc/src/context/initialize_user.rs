@@ -11,6 +11,7 @@ pub struct InitializeUser<'info> {
         has_one = mint,
         seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
         bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub config: Account<'info, Config>,
 
@@ -21,6 +22,7 @@ pub struct InitializeUser<'info> {
             pool.index.to_le_bytes().as_ref()
         ],
         bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub pool: Account<'info, Pool>,
 
@@ -48,7 +50,11 @@ impl<'info> InitializeUser<'info> {
             total_staked_token_amount: 0,
             total_claimed_yield: 0,
             bump: bumps.user_account,
-            _padding: [0; 64],
+            delegate: Pubkey::default(),
+            position_count: 0,
+            next_position_id: 0,
+            version: UserAccountV3::VERSION,
+            _padding: [0; 19],
         });
 
         Ok(())
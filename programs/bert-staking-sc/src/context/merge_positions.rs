@@ -0,0 +1,131 @@
+use crate::{state::*, StakingError};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MergePositions<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [
+            b"pool",
+            config.key().as_ref(),
+            pool.index.to_le_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref(), config.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub user_account: Box<Account<'info, UserAccountV3>>,
+
+    /// Position that absorbs the other one
+    #[account(
+        mut,
+        constraint = position.owner == owner.key(),
+        constraint = position.status == PositionStatus::Unclaimed,
+        constraint = position.pool == pool.key() @ StakingError::InvalidPositionType,
+        constraint = position.position_type == PositionType::Token @ StakingError::InvalidPositionType,
+        constraint = position.receipt == Pubkey::default() @ StakingError::PositionHasReceipt,
+        constraint = position.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub position: Box<Account<'info, PositionV4>>,
+
+    /// Position merged into `position` and closed
+    #[account(
+        mut,
+        close = owner,
+        constraint = other_position.key() != position.key() @ StakingError::InvalidMerge,
+        constraint = other_position.owner == owner.key(),
+        constraint = other_position.status == PositionStatus::Unclaimed,
+        constraint = other_position.pool == position.pool @ StakingError::InvalidMerge,
+        constraint = other_position.position_type == PositionType::Token @ StakingError::InvalidPositionType,
+        constraint = other_position.receipt == Pubkey::default() @ StakingError::PositionHasReceipt,
+        constraint = other_position.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub other_position: Box<Account<'info, PositionV4>>,
+}
+
+impl<'info> MergePositions<'info> {
+    /// Combines two token positions in the same pool. The merged position
+    /// unlocks with the later of the two, so merging can't shorten a lock.
+    /// Deposit time and the yield multiplier are amount-weighted, rounded
+    /// down.
+    pub fn merge_positions(&mut self) -> Result<()> {
+        let other = &self.other_position;
+        let position = &mut self.position;
+
+        let total_amount = position
+            .amount
+            .checked_add(other.amount)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        // The merged position has to respect the per-position maximum
+        self.pool.check_stake_amount(total_amount)?;
+
+        let deposit_time = weighted_average(
+            position.deposit_time,
+            position.amount,
+            other.deposit_time,
+            other.amount,
+        )?;
+        let yield_multiplier_bps = weighted_average(
+            position.multiplier_bps() as i64,
+            position.amount,
+            other.multiplier_bps() as i64,
+            other.amount,
+        )?;
+
+        position.amount = total_amount;
+        position.unlock_time = position.unlock_time.max(other.unlock_time);
+        position.deposit_time = deposit_time;
+        position.last_claimed_at = position.last_claimed_at.max(other.last_claimed_at);
+        position.lock_period_days = position.lock_period_days.max(other.lock_period_days);
+        position.yield_multiplier_bps =
+            u32::try_from(yield_multiplier_bps).map_err(|_| StakingError::ArithmeticOverflow)?;
+
+        self.user_account.close_position(other);
+
+        msg!(
+            "positions merged: amount: {:?} | unlock: {:?} | multiplier: {:?}",
+            position.amount,
+            position.unlock_time,
+            position.yield_multiplier_bps
+        );
+
+        Ok(())
+    }
+}
+
+/// Amount-weighted average of two values, rounded down
+fn weighted_average(a: i64, a_weight: u64, b: i64, b_weight: u64) -> Result<i64> {
+    let total_weight = (a_weight as i128)
+        .checked_add(b_weight as i128)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+    require!(total_weight > 0, StakingError::InvalidAmount);
+
+    let sum = (a as i128)
+        .checked_mul(a_weight as i128)
+        .ok_or(StakingError::ArithmeticOverflow)?
+        .checked_add(
+            (b as i128)
+                .checked_mul(b_weight as i128)
+                .ok_or(StakingError::ArithmeticOverflow)?,
+        )
+        .ok_or(StakingError::ArithmeticOverflow)?;
+
+    i64::try_from(sum / total_weight).map_err(|_| StakingError::ArithmeticOverflow.into())
+}
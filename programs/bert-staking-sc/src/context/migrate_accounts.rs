@@ -0,0 +1,72 @@
+use crate::{state::*, StakingError};
+use anchor_lang::{error::ErrorCode, prelude::*, system_program};
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// Pays for any space the current layout adds
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: A program account of the type being migrated, checked against
+    /// its discriminator
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+
+    /// The account's config, required to migrate pools from the original
+    /// layout. Migrate the config first.
+    pub config: Option<Box<Account<'info, Config>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateAccount<'info> {
+    /// Rewrites `account` in the current layout of `T`. Migrations only fill
+    /// in defaults, so anyone willing to pay for the extra space may run them.
+    pub fn migrate<T: Migrate>(&self) -> Result<()> {
+        let account = self.account.to_account_info();
+
+        require!(
+            account.try_borrow_data()?.starts_with(&T::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        // Grow accounts written with a shorter layout; the new bytes read as zero
+        let space = 8 + T::INIT_SPACE;
+        if account.data_len() < space {
+            let rent = Rent::get()?
+                .minimum_balance(space)
+                .saturating_sub(account.lamports());
+
+            if rent > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: self.payer.to_account_info(),
+                            to: account.clone(),
+                        },
+                    ),
+                    rent,
+                )?;
+            }
+
+            account.realloc(space, true)?;
+        }
+
+        let mut state = T::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+
+        let from_version = state.version();
+        require!(
+            from_version < T::VERSION,
+            StakingError::AccountAlreadyMigrated
+        );
+
+        state.upgrade(from_version, self.config.as_deref())?;
+        state.set_version(T::VERSION);
+
+        let mut data = account.try_borrow_mut_data()?;
+        state.try_serialize(&mut &mut data[..])?;
+
+        Ok(())
+    }
+}
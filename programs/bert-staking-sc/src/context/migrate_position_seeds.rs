@@ -0,0 +1,79 @@
+use crate::{state::*, StakingError};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct MigratePositionSeeds<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = mint,
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref(), config.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub user_account: Box<Account<'info, UserAccountV3>>,
+
+    /// Position derived with the legacy seeds, closed once migrated
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"position", owner.key().as_ref(), mint.key().as_ref(), position.asset_seed(), position.id.to_le_bytes().as_ref()],
+        bump = position.bump,
+        constraint = position.owner == owner.key() @ StakingError::Unauthorized,
+        constraint = position.seed_version == POSITION_SEED_VERSION_LEGACY @ StakingError::PositionAlreadyMigrated,
+        constraint = position.status != PositionStatus::Claimed,
+        constraint = position.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub position: Box<Account<'info, PositionV4>>,
+
+    /// The same position derived as `[position, owner, pool, id]`
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + PositionV4::INIT_SPACE,
+        seeds = [
+            b"position",
+            owner.key().as_ref(),
+            position.pool.as_ref(),
+            user_account.next_position_id.to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub new_position: Box<Account<'info, PositionV4>>,
+
+    /// Token mint.
+    pub mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigratePositionSeeds<'info> {
+    /// Re-keys a legacy position under the current seed scheme with a
+    /// program-assigned id. Balances and lock terms are carried over as is.
+    pub fn migrate_position_seeds(&mut self, bumps: &MigratePositionSeedsBumps) -> Result<()> {
+        // Legacy positions predate the open position count, so they are
+        // counted here without applying the per-user limit
+        let new_id = self.user_account.open_position(0)?;
+
+        let old_id = self.position.id;
+        let mut position = PositionV4::clone(&self.position);
+        position.id = new_id;
+        position.bump = bumps.new_position;
+        position.seed_version = POSITION_SEED_VERSION;
+        self.new_position.set_inner(position);
+
+        msg!("position seeds migrated: id {:?} -> {:?}", old_id, new_id);
+
+        Ok(())
+    }
+}
@@ -1,13 +1,41 @@
 pub mod claim_nft;
 pub mod claim_token;
+pub mod crank_claim;
+pub mod extend_lock;
 pub mod initialize;
 pub mod initialize_user;
+pub mod merge_positions;
+pub mod migrate_accounts;
+pub mod migrate_position_seeds;
+pub mod receipt;
+pub mod redeem;
+pub mod request_redeem;
+pub mod request_unstake;
+pub mod set_delegate;
+pub mod split_position;
+pub mod stake_liquid;
 pub mod stake_nft;
 pub mod stake_token;
+pub mod transfer_position;
+pub mod views;
 
 pub use claim_nft::*;
 pub use claim_token::*;
+pub use crank_claim::*;
+pub use extend_lock::*;
 pub use initialize::*;
 pub use initialize_user::*;
+pub use merge_positions::*;
+pub use migrate_accounts::*;
+pub use migrate_position_seeds::*;
+pub use receipt::*;
+pub use redeem::*;
+pub use request_redeem::*;
+pub use request_unstake::*;
+pub use set_delegate::*;
+pub use split_position::*;
+pub use stake_liquid::*;
 pub use stake_nft::*;
 pub use stake_token::*;
+pub use transfer_position::*;
+pub use views::*;
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    accounts::BaseAssetV1,
+    instructions::{BurnV1CpiBuilder, CreateV2CpiBuilder},
+    types::DataState,
+};
+
+use crate::{state::*, StakingError};
+
+/// Mints a receipt asset for a new position into the config's receipt collection
+#[allow(clippy::too_many_arguments)]
+pub fn mint_position_receipt<'info>(
+    core_program: &AccountInfo<'info>,
+    receipt: &AccountInfo<'info>,
+    receipt_collection: &AccountInfo<'info>,
+    config: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    position_id: u64,
+) -> Result<()> {
+    CreateV2CpiBuilder::new(core_program)
+        .asset(receipt)
+        .collection(Some(receipt_collection))
+        .authority(Some(config))
+        .payer(owner)
+        .owner(Some(owner))
+        .system_program(system_program)
+        .data_state(DataState::AccountState)
+        .name(format!("BERT Staking Position #{}", position_id))
+        .uri(String::new())
+        .invoke_signed(signer_seeds)?;
+
+    Ok(())
+}
+
+/// Returns the wallet entitled to a position's proceeds.
+///
+/// Positions without a receipt are claimed by their owner or the owner's
+/// delegate, and always pay the owner. Positions with a receipt are claimed by
+/// whoever holds it, and the receipt must be passed in.
+pub fn resolve_claim_recipient(
+    position: &PositionV4,
+    user_account: &UserAccountV3,
+    claimant: Pubkey,
+    receipt: Option<&Account<'_, BaseAssetV1>>,
+) -> Result<Pubkey> {
+    if position.receipt == Pubkey::default() {
+        require!(
+            user_account.is_owner_or_delegate(position.owner, claimant),
+            StakingError::Unauthorized
+        );
+        return Ok(position.owner);
+    }
+
+    let receipt = receipt.ok_or(StakingError::InvalidReceipt)?;
+    require!(
+        receipt.key() == position.receipt,
+        StakingError::InvalidReceipt
+    );
+    require!(receipt.owner == claimant, StakingError::Unauthorized);
+
+    Ok(claimant)
+}
+
+/// Burns a redeemed receipt. The holder signs as the asset owner.
+pub fn burn_position_receipt<'info>(
+    core_program: &AccountInfo<'info>,
+    receipt: &AccountInfo<'info>,
+    receipt_collection: &AccountInfo<'info>,
+    holder: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    BurnV1CpiBuilder::new(core_program)
+        .asset(receipt)
+        .collection(Some(receipt_collection))
+        .payer(holder)
+        .authority(Some(holder))
+        .system_program(Some(system_program))
+        .invoke()?;
+
+    Ok(())
+}
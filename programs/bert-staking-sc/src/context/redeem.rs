@@ -0,0 +1,122 @@
+use crate::{state::*, StakingError};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct Redeem<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = vault,
+        has_one = mint,
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [
+            b"pool",
+            config.key().as_ref(),
+            pool.index.to_le_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        has_one = pool,
+        seeds = [b"liquid_pool", pool.key().as_ref()],
+        bump = liquid_pool.bump,
+    )]
+    pub liquid_pool: Box<Account<'info, LiquidPool>>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner,
+        has_one = pool,
+        seeds = [
+            b"redemption",
+            owner.key().as_ref(),
+            pool.key().as_ref(),
+            ticket.id.to_le_bytes().as_ref()
+        ],
+        bump = ticket.bump,
+    )]
+    pub ticket: Box<Account<'info, RedemptionTicket>>,
+
+    /// Token mint.
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = config,
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> Redeem<'info> {
+    pub fn redeem(&mut self) -> Result<()> {
+        // Emergency mode releases tickets without waiting out the unbonding period
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= self.ticket.unlock_time || self.config.emergency,
+            StakingError::RedemptionLocked
+        );
+
+        let config = &self.config;
+        let amount = self.ticket.amount;
+
+        let bump = config.bump;
+        let authority = config.authority.key();
+        let id = config.id.to_le_bytes();
+        let seeds = &[b"config".as_ref(), authority.as_ref(), id.as_ref(), &[bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        anchor_spl::token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: self.vault.to_account_info(),
+                    to: self.token_account.to_account_info(),
+                    authority: config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        let liquid_pool = &mut self.liquid_pool;
+        liquid_pool.pending_redemptions = liquid_pool
+            .pending_redemptions
+            .checked_sub(amount)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        msg!(
+            "redeemed: {:?} | pending: {:?}",
+            amount,
+            liquid_pool.pending_redemptions
+        );
+
+        Ok(())
+    }
+}
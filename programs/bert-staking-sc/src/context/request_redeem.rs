@@ -0,0 +1,168 @@
+use crate::{state::*, StakingError};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use super::accrue_liquid_rewards;
+
+#[derive(Accounts)]
+pub struct RequestRedeem<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        has_one = mint,
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            config.key().as_ref(),
+            pool.index.to_le_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        constraint = pool.pool_type == PoolType::Liquid @ StakingError::InvalidPoolType,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        has_one = pool,
+        has_one = share_mint,
+        seeds = [b"liquid_pool", pool.key().as_ref()],
+        bump = liquid_pool.bump,
+    )]
+    pub liquid_pool: Box<Account<'info, LiquidPool>>,
+
+    #[account(mut)]
+    pub share_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = owner,
+    )]
+    pub share_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + RedemptionTicket::INIT_SPACE,
+        seeds = [
+            b"redemption",
+            owner.key().as_ref(),
+            pool.key().as_ref(),
+            liquid_pool.next_ticket_id.to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub ticket: Box<Account<'info, RedemptionTicket>>,
+
+    /// Token mint.
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = config,
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"liquid_reserve", pool.key().as_ref()],
+        bump = liquid_pool.reserve_bump,
+    )]
+    pub reserve: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RequestRedeem<'info> {
+    pub fn request_redeem(&mut self, shares: u64, bumps: &RequestRedeemBumps) -> Result<()> {
+        require!(shares > 0, StakingError::InvalidAmount);
+
+        // Price the shares at the current exchange rate
+        accrue_liquid_rewards(
+            &mut self.liquid_pool,
+            &mut self.pool,
+            &mut self.config,
+            &self.reserve,
+            &self.vault.to_account_info(),
+            &self.token_program.to_account_info(),
+        )?;
+
+        let config = &mut self.config;
+        let pool = &mut self.pool;
+        let liquid_pool = &mut self.liquid_pool;
+
+        let assets = liquid_pool.assets_for_shares(shares, self.share_mint.supply)?;
+        require!(assets > 0, StakingError::InvalidAmount);
+
+        anchor_spl::token::burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                anchor_spl::token::Burn {
+                    mint: self.share_mint.to_account_info(),
+                    from: self.share_account.to_account_info(),
+                    authority: self.owner.to_account_info(),
+                },
+            ),
+            shares,
+        )?;
+
+        // The redeemed tokens stop earning and wait out the unbonding period
+        liquid_pool.total_assets = liquid_pool
+            .total_assets
+            .checked_sub(assets)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        liquid_pool.pending_redemptions = liquid_pool
+            .pending_redemptions
+            .checked_add(assets)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        pool.total_tokens_staked = pool
+            .total_tokens_staked
+            .checked_sub(assets)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        config.total_staked_amount = config
+            .total_staked_amount
+            .checked_sub(assets)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        // Number the ticket from the pool's counter
+        let id = liquid_pool.next_ticket_id;
+        liquid_pool.next_ticket_id = id.checked_add(1).ok_or(StakingError::ArithmeticOverflow)?;
+
+        let current_time = Clock::get()?.unix_timestamp;
+        self.ticket.set_inner(RedemptionTicket {
+            owner: self.owner.key(),
+            pool: pool.key(),
+            amount: assets,
+            unlock_time: current_time + (pool.unbonding_period_days as i64 * 86400),
+            id,
+            bump: bumps.ticket,
+            version: RedemptionTicket::VERSION,
+            _padding: [0; 31],
+        });
+
+        msg!(
+            "redemption: shares: {:?} | assets: {:?} | unlock: {:?}",
+            shares,
+            assets,
+            self.ticket.unlock_time
+        );
+
+        Ok(())
+    }
+}
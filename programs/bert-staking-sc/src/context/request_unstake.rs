@@ -0,0 +1,83 @@
+use crate::{state::*, StakingError};
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseAssetV1;
+
+use super::resolve_claim_recipient;
+
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    /// The position owner or their delegate, or the receipt holder for
+    /// receipt-backed positions
+    pub claimant: Signer<'info>,
+
+    #[account(
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [
+            b"pool",
+            config.key().as_ref(),
+            pool.index.to_le_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        constraint = position.status == PositionStatus::Unclaimed,
+        constraint = position.pool == pool.key() @ StakingError::InvalidPositionType,
+        constraint = position.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub position: Box<Account<'info, PositionV4>>,
+
+    #[account(
+        seeds = [b"user", position.owner.as_ref(), config.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub user_account: Box<Account<'info, UserAccountV3>>,
+
+    /// Receipt asset, required for receipt-backed positions
+    pub receipt: Option<Box<Account<'info, BaseAssetV1>>>,
+}
+
+impl<'info> RequestUnstake<'info> {
+    pub fn request_unstake(&mut self) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let pool = &self.pool;
+
+        require!(
+            pool.unbonding_period_days > 0,
+            StakingError::CooldownNotRequired
+        );
+        require!(
+            current_time >= self.position.unlock_time,
+            StakingError::PositionLocked
+        );
+
+        resolve_claim_recipient(
+            &self.position,
+            &self.user_account,
+            self.claimant.key(),
+            self.receipt.as_deref(),
+        )?;
+
+        // Yield stops at the request; principal is released after the cooldown
+        let position = &mut self.position;
+        position.status = PositionStatus::Unbonding;
+        position.cooldown_ends_at = current_time + (pool.unbonding_period_days as i64 * 86400);
+
+        msg!(
+            "position unbonding: cooldown ends at {:?}",
+            position.cooldown_ends_at
+        );
+
+        Ok(())
+    }
+}
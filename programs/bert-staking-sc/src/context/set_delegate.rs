@@ -0,0 +1,33 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetDelegate<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref(), config.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub user_account: Account<'info, UserAccountV3>,
+}
+
+impl<'info> SetDelegate<'info> {
+    /// Approves a delegate to claim and relock for the owner, or revokes it
+    pub fn set_delegate(&mut self, delegate: Option<Pubkey>) -> Result<()> {
+        self.user_account.delegate = delegate.unwrap_or_default();
+
+        msg!("user delegate: {:?}", self.user_account.delegate);
+
+        Ok(())
+    }
+}
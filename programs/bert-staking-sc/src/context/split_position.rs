@@ -0,0 +1,99 @@
+use crate::{state::*, StakingError};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct SplitPosition<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [
+            b"pool",
+            config.key().as_ref(),
+            pool.index.to_le_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref(), config.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub user_account: Box<Account<'info, UserAccountV3>>,
+
+    #[account(
+        mut,
+        constraint = position.owner == owner.key(),
+        constraint = position.status == PositionStatus::Unclaimed,
+        constraint = position.pool == pool.key() @ StakingError::InvalidPositionType,
+        constraint = position.position_type == PositionType::Token @ StakingError::InvalidPositionType,
+        constraint = position.receipt == Pubkey::default() @ StakingError::PositionHasReceipt,
+        constraint = position.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub position: Box<Account<'info, PositionV4>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + PositionV4::INIT_SPACE,
+        seeds = [b"position", owner.key().as_ref(), pool.key().as_ref(), user_account.next_position_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub new_position: Box<Account<'info, PositionV4>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SplitPosition<'info> {
+    /// Moves `amount` of a token position into a new position with the same
+    /// pool, lock terms and unlock time. Pool and user counters are unchanged.
+    pub fn split_position(&mut self, amount: u64, bumps: &SplitPositionBumps) -> Result<()> {
+        let position = &mut self.position;
+        require!(
+            amount > 0 && amount < position.amount,
+            StakingError::InvalidAmount
+        );
+
+        // Both halves have to stay above the pool minimum
+        self.pool.check_stake_amount(amount)?;
+        self.pool.check_stake_amount(position.amount - amount)?;
+
+        let new_id = self
+            .user_account
+            .open_position(self.config.max_positions_per_user)?;
+
+        let mut split = PositionV4::clone(position);
+        split.amount = amount;
+        split.id = new_id;
+        split.bump = bumps.new_position;
+        split.seed_version = POSITION_SEED_VERSION;
+
+        position.amount = position
+            .amount
+            .checked_sub(amount)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        self.new_position.set_inner(split);
+
+        msg!(
+            "position split: {:?} -> {:?} | new id: {:?}",
+            position.amount,
+            amount,
+            new_id
+        );
+
+        Ok(())
+    }
+}
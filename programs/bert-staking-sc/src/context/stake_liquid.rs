@@ -0,0 +1,253 @@
+use crate::{state::*, StakingError};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct StakeLiquid<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        has_one = mint,
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            config.key().as_ref(),
+            pool.index.to_le_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        constraint = pool.pool_type == PoolType::Liquid @ StakingError::InvalidPoolType,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        has_one = pool,
+        has_one = share_mint,
+        seeds = [b"liquid_pool", pool.key().as_ref()],
+        bump = liquid_pool.bump,
+    )]
+    pub liquid_pool: Box<Account<'info, LiquidPool>>,
+
+    #[account(mut)]
+    pub share_mint: Box<Account<'info, Mint>>,
+
+    /// Token mint.
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = share_mint,
+        associated_token::authority = owner,
+    )]
+    pub share_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = config,
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"liquid_reserve", pool.key().as_ref()],
+        bump = liquid_pool.reserve_bump,
+    )]
+    pub reserve: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StakeLiquid<'info> {
+    pub fn stake_liquid(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, StakingError::InvalidAmount);
+        require!(!self.config.emergency, StakingError::EmergencyActive);
+        require!(!self.pool.is_paused, StakingError::PoolAlreadyPaused);
+
+        // Bring the exchange rate up to date before pricing the deposit
+        accrue_liquid_rewards(
+            &mut self.liquid_pool,
+            &mut self.pool,
+            &mut self.config,
+            &self.reserve,
+            &self.vault.to_account_info(),
+            &self.token_program.to_account_info(),
+        )?;
+
+        let config = &mut self.config;
+        let pool = &mut self.pool;
+        let liquid_pool = &mut self.liquid_pool;
+
+        // Check if the new value exceeds the pool's max value cap
+        let new_pool_total_tokens_staked = pool
+            .total_tokens_staked
+            .checked_add(amount)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        let total_pool_value = pool
+            .total_value()?
+            .checked_add(amount)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        require!(
+            total_pool_value <= pool.max_value_cap,
+            StakingError::PoolValueLimitReached
+        );
+
+        let shares = liquid_pool.shares_for_assets(amount, self.share_mint.supply)?;
+        require!(shares > 0, StakingError::InvalidAmount);
+
+        // Transfer tokens from user to program
+        anchor_spl::token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: self.token_account.to_account_info(),
+                    to: self.vault.to_account_info(),
+                    authority: self.owner.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        // Mint shares, signed by the pool as mint authority
+        let config_key = config.key();
+        let index = pool.index.to_le_bytes();
+        let seeds = &[
+            b"pool".as_ref(),
+            config_key.as_ref(),
+            index.as_ref(),
+            &[pool.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        anchor_spl::token::mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                anchor_spl::token::MintTo {
+                    mint: self.share_mint.to_account_info(),
+                    to: self.share_account.to_account_info(),
+                    authority: pool.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            shares,
+        )?;
+
+        liquid_pool.total_assets = liquid_pool
+            .total_assets
+            .checked_add(amount)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        pool.total_tokens_staked = new_pool_total_tokens_staked;
+        pool.lifetime_tokens_staked = pool
+            .lifetime_tokens_staked
+            .checked_add(amount)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        config.total_staked_amount = config
+            .total_staked_amount
+            .checked_add(amount)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        msg!(
+            "liquid_pool: assets: {:?} | shares minted: {:?} | pending: {:?}",
+            liquid_pool.total_assets,
+            shares,
+            liquid_pool.pending_redemptions
+        );
+
+        Ok(())
+    }
+}
+
+/// Accrues liquid pool yield up to now, moving it from the pool's reserve into
+/// the main vault so outstanding shares stay fully backed. Accrual is capped at
+/// the reserve balance.
+pub fn accrue_liquid_rewards<'info>(
+    liquid_pool: &mut LiquidPool,
+    pool: &mut Pool,
+    config: &mut Account<'info, Config>,
+    reserve: &Account<'info, TokenAccount>,
+    vault: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> Result<u64> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let rewards = liquid_pool
+        .pending_rewards(pool.yield_rate, pool.lock_period_days, current_time)?
+        .min(reserve.amount);
+
+    liquid_pool.last_accrual_at = current_time;
+
+    if rewards == 0 {
+        return Ok(0);
+    }
+
+    let bump = config.bump;
+    let authority = config.authority.key();
+    let id = config.id.to_le_bytes();
+    let seeds = &[b"config".as_ref(), authority.as_ref(), id.as_ref(), &[bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    anchor_spl::token::transfer(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            anchor_spl::token::Transfer {
+                from: reserve.to_account_info(),
+                to: vault.clone(),
+                authority: config.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        rewards,
+    )?;
+
+    liquid_pool.total_assets = liquid_pool
+        .total_assets
+        .checked_add(rewards)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+
+    pool.total_tokens_staked = pool
+        .total_tokens_staked
+        .checked_add(rewards)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+
+    pool.lifetime_claimed_yield = pool
+        .lifetime_claimed_yield
+        .checked_add(rewards)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+
+    config.total_staked_amount = config
+        .total_staked_amount
+        .checked_add(rewards)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+
+    msg!("Liquid yield of {} accrued from reserve", rewards);
+
+    Ok(rewards)
+}
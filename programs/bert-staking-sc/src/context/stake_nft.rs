@@ -12,8 +12,9 @@ use mpl_core::{
     ID as CORE_PROGRAM_ID,
 };
 
+use super::mint_position_receipt;
+
 #[derive(Accounts)]
-#[instruction(id: u64)]
 pub struct StakeNFT<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -21,9 +22,9 @@ pub struct StakeNFT<'info> {
     #[account(
         mut,
         has_one = mint,
-        has_one = collection,
         seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
         bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub config: Box<Account<'info, Config>>,
 
@@ -35,6 +36,7 @@ pub struct StakeNFT<'info> {
             &pool.index.to_le_bytes(),
         ],
         bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub pool: Box<Account<'info, Pool>>,
 
@@ -42,6 +44,7 @@ pub struct StakeNFT<'info> {
         mut,
         seeds = [b"user", owner.key().as_ref(), config.key().as_ref()],
         bump = user_account.bump,
+        constraint = user_account.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub user_account: Box<Account<'info, UserAccountV3>>,
 
@@ -62,7 +65,7 @@ pub struct StakeNFT<'info> {
         init,
         payer = owner,
         space = 8 + PositionV4::INIT_SPACE,
-        seeds = [b"position", owner.key().as_ref(), mint.key().as_ref(), asset.key().as_ref(), id.to_le_bytes().as_ref()],
+        seeds = [b"position", owner.key().as_ref(), pool.key().as_ref(), user_account.next_position_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub position: Box<Account<'info, PositionV4>>,
@@ -80,12 +83,43 @@ pub struct StakeNFT<'info> {
     #[account(mut)]
     pub collection: Box<Account<'info, BaseCollectionV1>>,
 
+    #[account(
+        mut,
+        has_one = config,
+        has_one = collection,
+        seeds = [b"collection", config.key().as_ref(), collection.key().as_ref()],
+        bump = collection_entry.bump,
+        constraint = collection_entry.is_active @ StakingError::CollectionNotActive,
+    )]
+    pub collection_entry: Box<Account<'info, CollectionEntry>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + UserCollectionStats::INIT_SPACE,
+        seeds = [
+            b"user_collection_stats",
+            owner.key().as_ref(),
+            collection_entry.key().as_ref(),
+        ],
+        bump
+    )]
+    pub user_collection_stats: Box<Account<'info, UserCollectionStats>>,
+
     #[account(address = CORE_PROGRAM_ID)]
     /// CHECK: this will be checked by core
     pub core_program: UncheckedAccount<'info>,
 
     pub mint: Account<'info, Mint>,
 
+    /// Optional receipt asset to mint for this position
+    #[account(mut)]
+    pub receipt: Option<Signer<'info>>,
+
+    /// CHECK: Checked against the config's receipt collection
+    #[account(mut, address = config.receipt_collection @ StakingError::InvalidReceipt)]
+    pub receipt_collection: Option<UncheckedAccount<'info>>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -93,15 +127,35 @@ pub struct StakeNFT<'info> {
 }
 
 impl<'info> StakeNFT<'info> {
-    pub fn stake_nft(&mut self, id: u64, bumps: &StakeNFTBumps) -> Result<()> {
+    pub fn stake_nft(
+        &mut self,
+        lock_period_days: Option<u16>,
+        allowlist: Option<AllowlistProof>,
+        bumps: &StakeNFTBumps,
+    ) -> Result<()> {
         let config_account_info = &self.config.to_account_info();
         let config = &mut self.config;
         let pool = &mut self.pool;
         let user_pool_stats = &mut self.user_pool_stats;
 
+        // Each registered collection sets its own NFT value
+        let nft_value = self.collection_entry.nft_value_in_tokens;
+
+        // No new stakes while the config is in emergency mode
+        require!(!config.emergency, StakingError::EmergencyActive);
+
         // Stake only if pool is not paused
         require!(!pool.is_paused, StakingError::PoolAlreadyPaused);
 
+        // Liquid pools stake through shares instead of positions
+        require!(
+            pool.pool_type == PoolType::Standard,
+            StakingError::InvalidPoolType
+        );
+
+        // Resolve the lock period and its yield multiplier from the pool's lock mode
+        let (lock_days, yield_multiplier_bps) = pool.resolve_lock(lock_period_days)?;
+
         // Calculate new per-pool NFT count
         let new_pool_nfts_staked = user_pool_stats
             .nfts_staked
@@ -111,16 +165,23 @@ impl<'info> StakeNFT<'info> {
         // Calculate new per-pool total value
         let new_pool_total_value = user_pool_stats
             .total_value
-            .checked_add(config.nft_value_in_tokens)
+            .checked_add(nft_value)
             .ok_or(StakingError::ArithmeticOverflow)?;
 
         // Calculate new total staked value across all pools
         let new_user_total_value = self
             .user_account
             .total_staked_value
-            .checked_add(config.nft_value_in_tokens)
+            .checked_add(nft_value)
             .ok_or(StakingError::ArithmeticOverflow)?;
 
+        // Private pools only accept allowlisted stakers, up to their own cap
+        let allowlist_cap = pool.allowlist_cap(&self.owner.key(), allowlist.as_ref())?;
+        require!(
+            new_pool_total_value <= allowlist_cap,
+            StakingError::AllowlistCapReached
+        );
+
         // Check user has not exceed the pool's token value cap
         require!(
             new_pool_total_value <= pool.max_tokens_cap,
@@ -145,14 +206,14 @@ impl<'info> StakeNFT<'info> {
             StakingError::GlobalNftLimitReached
         );
 
-        // Calculate new total value for the pool (new NFT value + existing value)
-        let new_nft_value = (pool.total_nfts_staked as u64 + 1)
-            .checked_mul(config.nft_value_in_tokens)
-            .ok_or(StakingError::ArithmeticOverflow)?;
+        // Check user has not exceeded the collection's NFT limit
+        self.collection_entry
+            .record_stake(&mut self.user_collection_stats)?;
 
+        // Calculate new total value for the pool (new NFT value + existing value)
         let total_pool_value = pool
-            .total_tokens_staked
-            .checked_add(new_nft_value)
+            .total_value()?
+            .checked_add(nft_value)
             .ok_or(StakingError::ArithmeticOverflow)?;
 
         // Check if the new value exceeds the pool's max value cap
@@ -164,25 +225,70 @@ impl<'info> StakeNFT<'info> {
         // Check if staking would exceed the max cap
         let new_total = config
             .total_staked_amount
-            .checked_add(config.nft_value_in_tokens)
+            .checked_add(nft_value)
             .ok_or(StakingError::ArithmeticOverflow)?;
 
+        // Take the next position id, within the per-user open position limit
+        let id = self
+            .user_account
+            .open_position(config.max_positions_per_user)?;
+
         // Create a position for the staked tokens
         let position = &mut self.position;
         position.owner = self.owner.key();
         position.pool = pool.key();
         position.deposit_time = Clock::get()?.unix_timestamp;
-        position.amount = config.nft_value_in_tokens;
+        position.amount = nft_value;
         position.position_type = PositionType::NFT;
         position.asset = self.asset.key();
         position.id = id;
         position.bump = bumps.position;
+        position.seed_version = POSITION_SEED_VERSION;
+        position.collection_counted = true;
+        position.version = PositionV4::VERSION;
         position.last_claimed_at = Clock::get()?.unix_timestamp;
 
         // Calculate unlock time (current time + lock_time in seconds)
-        let lock_days = pool.lock_period_days;
         position.unlock_time = Clock::get()?.unix_timestamp + (lock_days as i64 * 86400);
         position.status = PositionStatus::Unclaimed;
+        position.lock_period_days = lock_days;
+        position.yield_multiplier_bps = yield_multiplier_bps;
+
+        // Mint a receipt carrying the claim rights, if requested
+        if let Some(receipt) = &self.receipt {
+            require!(
+                config.receipt_collection != Pubkey::default(),
+                StakingError::ReceiptCollectionNotInitialized
+            );
+
+            let receipt_collection = self
+                .receipt_collection
+                .as_ref()
+                .ok_or(StakingError::InvalidReceipt)?;
+
+            let bump = config.bump;
+            let authority = config.authority.key();
+            let config_id = config.id.to_le_bytes();
+            let seeds = &[
+                b"config".as_ref(),
+                authority.as_ref(),
+                config_id.as_ref(),
+                &[bump],
+            ];
+
+            mint_position_receipt(
+                &self.core_program.to_account_info(),
+                &receipt.to_account_info(),
+                &receipt_collection.to_account_info(),
+                config_account_info,
+                &self.owner.to_account_info(),
+                &self.system_program.to_account_info(),
+                &[&seeds[..]],
+                id,
+            )?;
+
+            position.receipt = receipt.key();
+        }
 
         // Transfer The asset:
         TransferV1CpiBuilder::new(&self.core_program.to_account_info())
@@ -201,6 +307,11 @@ impl<'info> StakeNFT<'info> {
             .checked_add(1)
             .ok_or(StakingError::ArithmeticOverflow)?;
 
+        pool.total_nft_value = pool
+            .total_nft_value
+            .checked_add(nft_value)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
         pool.lifetime_nfts_staked = pool
             .lifetime_nfts_staked
             .checked_add(1)
@@ -224,6 +335,12 @@ impl<'info> StakeNFT<'info> {
 
         user_pool_stats.bump = bumps.user_pool_stats;
 
+        // Update user stats for this collection
+        let user_collection_stats = &mut self.user_collection_stats;
+        user_collection_stats.user = self.owner.key();
+        user_collection_stats.collection_entry = self.collection_entry.key();
+        user_collection_stats.bump = bumps.user_collection_stats;
+
         // Update global user stats
         let user_account = &mut self.user_account;
         user_account.total_staked_nfts = new_user_nfts_staked;
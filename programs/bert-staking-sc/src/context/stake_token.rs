@@ -4,9 +4,11 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token::{Mint, Token, TokenAccount},
 };
+use mpl_core::ID as CORE_PROGRAM_ID;
+
+use super::mint_position_receipt;
 
 #[derive(Accounts)]
-#[instruction(id: u64)]
 pub struct StakeToken<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -16,6 +18,7 @@ pub struct StakeToken<'info> {
         has_one = vault,
         seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
         bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub config: Box<Account<'info, Config>>,
 
@@ -27,6 +30,7 @@ pub struct StakeToken<'info> {
             pool.index.to_le_bytes().as_ref()
         ],
         bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub pool: Box<Account<'info, Pool>>,
 
@@ -34,6 +38,7 @@ pub struct StakeToken<'info> {
         mut,
         seeds = [b"user", owner.key().as_ref(), config.key().as_ref()],
         bump = user_account.bump,
+        constraint = user_account.is_current() @ StakingError::StaleAccountVersion,
     )]
     pub user_account: Box<Account<'info, UserAccountV3>>,
 
@@ -54,7 +59,7 @@ pub struct StakeToken<'info> {
         init,
         payer = owner,
         space = 8 + PositionV4::INIT_SPACE,
-        seeds = [b"position", owner.key().as_ref(), mint.key().as_ref(), id.to_le_bytes().as_ref()],
+        seeds = [b"position", owner.key().as_ref(), pool.key().as_ref(), user_account.next_position_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub position: Box<Account<'info, PositionV4>>,
@@ -75,6 +80,18 @@ pub struct StakeToken<'info> {
     )]
     pub vault: Box<Account<'info, TokenAccount>>,
 
+    /// Optional receipt asset to mint for this position
+    #[account(mut)]
+    pub receipt: Option<Signer<'info>>,
+
+    /// CHECK: Checked against the config's receipt collection
+    #[account(mut, address = config.receipt_collection @ StakingError::InvalidReceipt)]
+    pub receipt_collection: Option<UncheckedAccount<'info>>,
+
+    #[account(address = CORE_PROGRAM_ID)]
+    /// CHECK: this will be checked by core
+    pub core_program: Option<UncheckedAccount<'info>>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -82,7 +99,13 @@ pub struct StakeToken<'info> {
 }
 
 impl<'info> StakeToken<'info> {
-    pub fn stake_token(&mut self, id: u64, amount: u64, bumps: &StakeTokenBumps) -> Result<()> {
+    pub fn stake_token(
+        &mut self,
+        amount: u64,
+        lock_period_days: Option<u16>,
+        allowlist: Option<AllowlistProof>,
+        bumps: &StakeTokenBumps,
+    ) -> Result<()> {
         // Check if amount is valid
         if amount == 0 {
             return Err(StakingError::InvalidAmount.into());
@@ -93,9 +116,24 @@ impl<'info> StakeToken<'info> {
         let pool = &mut self.pool;
         let user_pool_stats = &mut self.user_pool_stats;
 
+        // No new stakes while the config is in emergency mode
+        require!(!config.emergency, StakingError::EmergencyActive);
+
         // Stake only if pool is not paused
         require!(!pool.is_paused, StakingError::PoolAlreadyPaused);
 
+        // Liquid pools stake through shares instead of positions
+        require!(
+            pool.pool_type == PoolType::Standard,
+            StakingError::InvalidPoolType
+        );
+
+        // Keep positions within the pool's size bounds
+        pool.check_stake_amount(amount)?;
+
+        // Resolve the lock period and its yield multiplier from the pool's lock mode
+        let (lock_days, yield_multiplier_bps) = pool.resolve_lock(lock_period_days)?;
+
         // Calculate new per-pool token staked amount
         let new_pool_tokens_staked = user_pool_stats
             .tokens_staked
@@ -115,6 +153,13 @@ impl<'info> StakeToken<'info> {
             .checked_add(amount)
             .ok_or(StakingError::ArithmeticOverflow)?;
 
+        // Private pools only accept allowlisted stakers, up to their own cap
+        let allowlist_cap = pool.allowlist_cap(&self.owner.key(), allowlist.as_ref())?;
+        require!(
+            new_pool_total_value <= allowlist_cap,
+            StakingError::AllowlistCapReached
+        );
+
         // Check user has not exceeded the pool's max token cap
         require!(
             new_pool_total_value <= pool.max_tokens_cap,
@@ -127,13 +172,9 @@ impl<'info> StakeToken<'info> {
             .checked_add(amount)
             .ok_or(StakingError::ArithmeticOverflow)?;
 
-        // Add the value from NFTs in the pool
-        let nft_value = (pool.total_nfts_staked as u64)
-            .checked_mul(config.nft_value_in_tokens)
-            .ok_or(StakingError::ArithmeticOverflow)?;
-
-        let total_pool_value = new_pool_total_tokens_staked
-            .checked_add(nft_value)
+        let total_pool_value = pool
+            .total_value()?
+            .checked_add(amount)
             .ok_or(StakingError::ArithmeticOverflow)?;
 
         // Check if the new value exceeds the pool's max value cap
@@ -142,6 +183,11 @@ impl<'info> StakeToken<'info> {
             StakingError::PoolValueLimitReached
         );
 
+        // Take the next position id, within the per-user open position limit
+        let id = self
+            .user_account
+            .open_position(config.max_positions_per_user)?;
+
         // Create a position for the staked tokens
         let position = &mut self.position;
         position.owner = self.owner.key();
@@ -152,12 +198,55 @@ impl<'info> StakeToken<'info> {
         position.asset = self.mint.key();
         position.id = id;
         position.bump = bumps.position;
+        position.seed_version = POSITION_SEED_VERSION;
+        position.version = PositionV4::VERSION;
         position.last_claimed_at = Clock::get()?.unix_timestamp;
 
         // Calculate unlock time (current time + lock_time in seconds)
-        let lock_days = pool.lock_period_days;
         position.unlock_time = Clock::get()?.unix_timestamp + (lock_days as i64 * 86400);
         position.status = PositionStatus::Unclaimed;
+        position.lock_period_days = lock_days;
+        position.yield_multiplier_bps = yield_multiplier_bps;
+
+        // Mint a receipt carrying the claim rights, if requested
+        if let Some(receipt) = &self.receipt {
+            require!(
+                config.receipt_collection != Pubkey::default(),
+                StakingError::ReceiptCollectionNotInitialized
+            );
+
+            let receipt_collection = self
+                .receipt_collection
+                .as_ref()
+                .ok_or(StakingError::InvalidReceipt)?;
+            let core_program = self
+                .core_program
+                .as_ref()
+                .ok_or(StakingError::InvalidReceipt)?;
+
+            let bump = config.bump;
+            let authority = config.authority.key();
+            let config_id = config.id.to_le_bytes();
+            let seeds = &[
+                b"config".as_ref(),
+                authority.as_ref(),
+                config_id.as_ref(),
+                &[bump],
+            ];
+
+            mint_position_receipt(
+                &core_program.to_account_info(),
+                &receipt.to_account_info(),
+                &receipt_collection.to_account_info(),
+                &config.to_account_info(),
+                &self.owner.to_account_info(),
+                &self.system_program.to_account_info(),
+                &[&seeds[..]],
+                id,
+            )?;
+
+            position.receipt = receipt.key();
+        }
 
         // Transfer tokens from user to program
         anchor_spl::token::transfer(
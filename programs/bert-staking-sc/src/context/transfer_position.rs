@@ -0,0 +1,303 @@
+use crate::{state::*, StakingError};
+use anchor_lang::prelude::*;
+use mpl_core::{accounts::BaseAssetV1, types::UpdateAuthority};
+
+#[derive(Accounts)]
+pub struct TransferPosition<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The receiving wallet co-signs, since the position counts against its limits.
+    /// It must already have a user account.
+    #[account(constraint = new_owner.key() != owner.key() @ StakingError::InvalidTransferRecipient)]
+    pub new_owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [
+            b"pool",
+            config.key().as_ref(),
+            pool.index.to_le_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        constraint = !pool.is_allowlisted() @ StakingError::NotAllowlisted,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref(), config.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub user_account: Box<Account<'info, UserAccountV3>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", new_owner.key().as_ref(), config.key().as_ref()],
+        bump = new_user_account.bump,
+        constraint = new_user_account.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub new_user_account: Box<Account<'info, UserAccountV3>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"user_pool_stats",
+            owner.key().as_ref(),
+            pool.key().as_ref(),
+        ],
+        bump = user_pool_stats.bump,
+    )]
+    pub user_pool_stats: Box<Account<'info, UserPoolStatsAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + UserPoolStatsAccount::INIT_SPACE,
+        seeds = [
+            b"user_pool_stats",
+            new_owner.key().as_ref(),
+            pool.key().as_ref(),
+        ],
+        bump
+    )]
+    pub new_user_pool_stats: Box<Account<'info, UserPoolStatsAccount>>,
+
+    #[account(
+        mut,
+        close = owner,
+        constraint = position.owner == owner.key(),
+        constraint = position.status == PositionStatus::Unclaimed,
+        constraint = position.pool == pool.key() @ StakingError::InvalidPositionType,
+        constraint = position.receipt == Pubkey::default() @ StakingError::PositionHasReceipt,
+        constraint = position.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub position: Box<Account<'info, PositionV4>>,
+
+    /// The position re-keyed under the new owner
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + PositionV4::INIT_SPACE,
+        seeds = [
+            b"position",
+            new_owner.key().as_ref(),
+            pool.key().as_ref(),
+            new_user_account.next_position_id.to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub new_position: Box<Account<'info, PositionV4>>,
+
+    /// Staked asset, required for NFT positions
+    #[account(address = position.asset @ StakingError::InvalidPositionType)]
+    pub asset: Option<Box<Account<'info, BaseAssetV1>>>,
+
+    /// Registry entry of the asset's collection, required for NFT positions
+    /// unless the NFT was staked before collections were registered
+    #[account(
+        mut,
+        has_one = config,
+        seeds = [b"collection", config.key().as_ref(), collection_entry.collection.as_ref()],
+        bump = collection_entry.bump,
+    )]
+    pub collection_entry: Option<Box<Account<'info, CollectionEntry>>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + UserCollectionStats::INIT_SPACE,
+        seeds = [
+            b"user_collection_stats",
+            owner.key().as_ref(),
+            collection_entry.as_ref().map(|entry| entry.key()).unwrap_or_default().as_ref(),
+        ],
+        bump
+    )]
+    pub user_collection_stats: Option<Box<Account<'info, UserCollectionStats>>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + UserCollectionStats::INIT_SPACE,
+        seeds = [
+            b"user_collection_stats",
+            new_owner.key().as_ref(),
+            collection_entry.as_ref().map(|entry| entry.key()).unwrap_or_default().as_ref(),
+        ],
+        bump
+    )]
+    pub new_user_collection_stats: Option<Box<Account<'info, UserCollectionStats>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TransferPosition<'info> {
+    pub fn transfer_position(&mut self, bumps: &TransferPositionBumps) -> Result<()> {
+        let config = &self.config;
+        let pool = &self.pool;
+        let user_pool_stats = &mut self.user_pool_stats;
+        let new_user_pool_stats = &mut self.new_user_pool_stats;
+        let user_account = &mut self.user_account;
+        let new_user_account = &mut self.new_user_account;
+
+        let position_amount = self.position.amount;
+
+        // The receiving user has to stay within the pool's per-user caps
+        let new_pool_total_value = new_user_pool_stats
+            .total_value
+            .checked_add(position_amount)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        require!(
+            new_pool_total_value <= pool.max_tokens_cap,
+            StakingError::UserTokensLimitCapReached
+        );
+
+        match self.position.position_type {
+            PositionType::Token => {
+                user_pool_stats.tokens_staked = user_pool_stats
+                    .tokens_staked
+                    .checked_sub(position_amount)
+                    .ok_or(StakingError::ArithmeticOverflow)?;
+                new_user_pool_stats.tokens_staked = new_user_pool_stats
+                    .tokens_staked
+                    .checked_add(position_amount)
+                    .ok_or(StakingError::ArithmeticOverflow)?;
+
+                user_account.total_staked_token_amount = user_account
+                    .total_staked_token_amount
+                    .checked_sub(position_amount)
+                    .ok_or(StakingError::ArithmeticOverflow)?;
+                new_user_account.total_staked_token_amount = new_user_account
+                    .total_staked_token_amount
+                    .checked_add(position_amount)
+                    .ok_or(StakingError::ArithmeticOverflow)?;
+            }
+            PositionType::NFT => {
+                let new_pool_nfts_staked = new_user_pool_stats
+                    .nfts_staked
+                    .checked_add(1)
+                    .ok_or(StakingError::ArithmeticOverflow)?;
+
+                require!(
+                    new_pool_nfts_staked <= pool.max_nfts_cap,
+                    StakingError::NftLimitReached
+                );
+
+                let new_user_nfts_staked = new_user_account
+                    .total_staked_nfts
+                    .checked_add(1)
+                    .ok_or(StakingError::ArithmeticOverflow)?;
+
+                require!(
+                    new_user_nfts_staked <= config.nfts_limit_per_user as u32,
+                    StakingError::GlobalNftLimitReached
+                );
+
+                user_pool_stats.nfts_staked = user_pool_stats
+                    .nfts_staked
+                    .checked_sub(1)
+                    .ok_or(StakingError::ArithmeticOverflow)?;
+                new_user_pool_stats.nfts_staked = new_pool_nfts_staked;
+
+                user_account.total_staked_nfts = user_account
+                    .total_staked_nfts
+                    .checked_sub(1)
+                    .ok_or(StakingError::ArithmeticOverflow)?;
+                new_user_account.total_staked_nfts = new_user_nfts_staked;
+
+                // Move the NFT to the receiver's count for its collection.
+                // NFTs staked before collections were registered were never
+                // counted and stay uncounted.
+                if self.position.collection_counted {
+                    let asset = self
+                        .asset
+                        .as_ref()
+                        .ok_or(StakingError::InvalidPositionType)?;
+                    let collection_entry = self
+                        .collection_entry
+                        .as_mut()
+                        .ok_or(StakingError::InvalidPositionType)?;
+                    let user_collection_stats = self
+                        .user_collection_stats
+                        .as_mut()
+                        .ok_or(StakingError::InvalidPositionType)?;
+                    let new_user_collection_stats = self
+                        .new_user_collection_stats
+                        .as_mut()
+                        .ok_or(StakingError::InvalidPositionType)?;
+
+                    require!(
+                        asset.update_authority
+                            == UpdateAuthority::Collection(collection_entry.collection),
+                        StakingError::InvalidPositionType
+                    );
+
+                    collection_entry.record_claim(user_collection_stats)?;
+                    collection_entry.record_stake(new_user_collection_stats)?;
+
+                    user_collection_stats.user = self.owner.key();
+                    user_collection_stats.collection_entry = collection_entry.key();
+                    new_user_collection_stats.user = self.new_owner.key();
+                    new_user_collection_stats.collection_entry = collection_entry.key();
+
+                    if let Some(bump) = bumps.user_collection_stats {
+                        user_collection_stats.bump = bump;
+                    }
+                    if let Some(bump) = bumps.new_user_collection_stats {
+                        new_user_collection_stats.bump = bump;
+                    }
+                }
+            }
+        }
+
+        user_pool_stats.total_value = user_pool_stats
+            .total_value
+            .checked_sub(position_amount)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+        new_user_pool_stats.total_value = new_pool_total_value;
+
+        user_account.total_staked_value = user_account
+            .total_staked_value
+            .checked_sub(position_amount)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+        new_user_account.total_staked_value = new_user_account
+            .total_staked_value
+            .checked_add(position_amount)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        new_user_pool_stats.user = self.new_owner.key();
+        new_user_pool_stats.pool = pool.key();
+        new_user_pool_stats.bump = bumps.new_user_pool_stats;
+
+        // The position takes the receiver's next id and counts against their limit
+        user_account.close_position(&self.position);
+        let new_id = new_user_account.open_position(config.max_positions_per_user)?;
+
+        // Re-key the position under the new owner; the old account is closed
+        let mut position = (**self.position).clone();
+        position.owner = self.new_owner.key();
+        position.id = new_id;
+        position.bump = bumps.new_position;
+        position.seed_version = POSITION_SEED_VERSION;
+        self.new_position.set_inner(position);
+
+        msg!(
+            "position transferred: {:?} -> {:?} | id: {:?}",
+            self.owner.key(),
+            self.new_owner.key(),
+            new_id
+        );
+
+        Ok(())
+    }
+}
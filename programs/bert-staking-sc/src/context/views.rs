@@ -0,0 +1,221 @@
+use crate::{state::*, StakingError};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ViewPositionYield<'info> {
+    #[account(
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [
+            b"pool",
+            config.key().as_ref(),
+            pool.index.to_le_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        seeds = [
+            b"user_pool_stats",
+            position.owner.as_ref(),
+            pool.key().as_ref(),
+        ],
+        bump = user_pool_stats.bump,
+    )]
+    pub user_pool_stats: Box<Account<'info, UserPoolStatsAccount>>,
+
+    #[account(
+        constraint = position.status != PositionStatus::Claimed,
+        constraint = position.pool == pool.key() @ StakingError::InvalidPositionType,
+        constraint = position.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub position: Box<Account<'info, PositionV4>>,
+}
+
+impl<'info> ViewPositionYield<'info> {
+    pub fn view_position_yield(&self) -> Result<PositionYieldView> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let pool = &self.pool;
+        let position = &self.position;
+
+        // Token positions earn the NFT holding boost, as they do when claimed
+        let boost = |yield_value: u64| match position.position_type {
+            PositionType::Token => {
+                pool.apply_nft_boost(yield_value, self.user_pool_stats.nfts_staked)
+            }
+            PositionType::NFT => Ok(yield_value),
+        };
+
+        Ok(PositionYieldView {
+            pending_yield: boost(position.accrued_yield(pool.yield_rate, current_time)?)?,
+            projected_yield: boost(position.term_yield(pool.yield_rate)?)?,
+            unlock_time: position.unlock_time,
+            is_withdrawable: position
+                .check_withdrawable(pool.unbonding_period_days, current_time)
+                .is_ok(),
+        })
+    }
+}
+
+#[derive(Accounts)]
+pub struct ViewPoolCapacity<'info> {
+    #[account(
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [
+            b"pool",
+            config.key().as_ref(),
+            pool.index.to_le_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+impl<'info> ViewPoolCapacity<'info> {
+    pub fn view_pool_capacity(&self) -> Result<PoolCapacityView> {
+        pool_capacity(&self.pool)
+    }
+}
+
+fn pool_capacity(pool: &Pool) -> Result<PoolCapacityView> {
+    let total_value = pool.total_value()?;
+
+    Ok(PoolCapacityView {
+        total_value,
+        max_value_cap: pool.max_value_cap,
+        remaining_value: pool.max_value_cap.saturating_sub(total_value),
+    })
+}
+
+#[derive(Accounts)]
+pub struct ViewUserCapacity<'info> {
+    /// CHECK: Only used to derive the user's accounts
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"config", config.authority.key().as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [
+            b"pool",
+            config.key().as_ref(),
+            pool.index.to_le_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        constraint = pool.is_current() @ StakingError::StaleAccountVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// CHECK: The user's account, read only if it has been created
+    #[account(seeds = [b"user", owner.key().as_ref(), config.key().as_ref()], bump)]
+    pub user_account: UncheckedAccount<'info>,
+
+    /// CHECK: The user's stats for the pool, read only if they have been created
+    #[account(
+        seeds = [
+            b"user_pool_stats",
+            owner.key().as_ref(),
+            pool.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_pool_stats: UncheckedAccount<'info>,
+
+    /// Registered collection to report the per-collection limit for
+    #[account(has_one = config)]
+    pub collection_entry: Option<Box<Account<'info, CollectionEntry>>>,
+
+    /// CHECK: The user's stats for `collection_entry`, checked against its
+    /// address and read only if they have been created
+    pub user_collection_stats: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> ViewUserCapacity<'info> {
+    pub fn view_user_capacity(&self) -> Result<UserCapacityView> {
+        let config = &self.config;
+        let pool = &self.pool;
+
+        // A user who never staked has empty stats
+        let user_account = load_if_initialized::<UserAccountV3>(&self.user_account)?;
+        let user_pool_stats = load_if_initialized::<UserPoolStatsAccount>(&self.user_pool_stats)?;
+
+        let (pool_value, pool_nfts) = user_pool_stats
+            .map(|stats| (stats.total_value, stats.nfts_staked))
+            .unwrap_or_default();
+        let user_nfts = user_account
+            .map(|user| user.total_staked_nfts)
+            .unwrap_or_default();
+
+        let remaining_value = pool
+            .max_tokens_cap
+            .saturating_sub(pool_value)
+            .min(pool_capacity(pool)?.remaining_value);
+
+        let remaining_collection_nfts = match &self.collection_entry {
+            Some(collection_entry) => {
+                let user_collection_stats = self
+                    .user_collection_stats
+                    .as_ref()
+                    .ok_or(StakingError::InvalidCollectionStats)?;
+
+                let (expected, _) = Pubkey::find_program_address(
+                    &[
+                        b"user_collection_stats",
+                        self.owner.key().as_ref(),
+                        collection_entry.key().as_ref(),
+                    ],
+                    &crate::ID,
+                );
+                require_keys_eq!(
+                    user_collection_stats.key(),
+                    expected,
+                    StakingError::InvalidCollectionStats
+                );
+
+                let nfts_staked =
+                    load_if_initialized::<UserCollectionStats>(user_collection_stats)?
+                        .map(|stats| stats.nfts_staked)
+                        .unwrap_or_default();
+
+                Some((collection_entry.nfts_limit_per_user as u32).saturating_sub(nfts_staked))
+            }
+            None => None,
+        };
+
+        Ok(UserCapacityView {
+            remaining_value,
+            remaining_pool_nfts: pool.max_nfts_cap.saturating_sub(pool_nfts),
+            remaining_nfts: (config.nfts_limit_per_user as u32).saturating_sub(user_nfts),
+            remaining_collection_nfts,
+        })
+    }
+}
+
+/// Deserializes a program account, or returns `None` if it hasn't been
+/// created yet
+fn load_if_initialized<T: AccountDeserialize>(account: &AccountInfo) -> Result<Option<T>> {
+    if account.owner != &crate::ID || account.data_is_empty() {
+        return Ok(None);
+    }
+
+    let data = account.try_borrow_data()?;
+    Ok(Some(T::try_deserialize(&mut &data[..])?))
+}
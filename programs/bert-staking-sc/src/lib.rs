@@ -3,7 +3,8 @@ use anchor_lang::prelude::*;
 
 mod admin;
 mod context;
-mod state;
+pub mod rewards;
+pub mod state;
 
 use admin::*;
 use context::*;
@@ -39,10 +40,22 @@ pub mod bert_staking_sc {
         ctx.accounts.initialize_auth_vault(&ctx.bumps)
     }
 
+    pub fn initialize_receipt_collection(
+        ctx: Context<InitializeReceiptCollection>,
+        name: String,
+        uri: String,
+    ) -> Result<()> {
+        ctx.accounts.initialize_receipt_collection(name, uri)
+    }
+
     pub fn initiate_user(ctx: Context<InitializeUser>) -> Result<()> {
         ctx.accounts.initialize_user(&ctx.bumps)
     }
 
+    pub fn set_delegate(ctx: Context<SetDelegate>, delegate: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.set_delegate(delegate)
+    }
+
     pub fn initialize_pool(
         ctx: Context<InitializePool>,
         index: u32,
@@ -63,22 +76,130 @@ pub mod bert_staking_sc {
         )
     }
 
-    pub fn stake_nft(ctx: Context<StakeNFT>, id: u64) -> Result<()> {
-        ctx.accounts.stake_nft(id, &ctx.bumps)
+    pub fn initialize_liquid_pool(
+        ctx: Context<InitializeLiquidPool>,
+        unbonding_period_days: u16,
+    ) -> Result<()> {
+        ctx.accounts
+            .initialize_liquid_pool(unbonding_period_days, &ctx.bumps)
     }
 
-    pub fn stake_token(ctx: Context<StakeToken>, id: u64, amount: u64) -> Result<()> {
-        ctx.accounts.stake_token(id, amount, &ctx.bumps)
+    pub fn stake_nft(
+        ctx: Context<StakeNFT>,
+        lock_period_days: Option<u16>,
+        allowlist: Option<AllowlistProof>,
+    ) -> Result<()> {
+        ctx.accounts
+            .stake_nft(lock_period_days, allowlist, &ctx.bumps)
+    }
+
+    pub fn stake_token(
+        ctx: Context<StakeToken>,
+        amount: u64,
+        lock_period_days: Option<u16>,
+        allowlist: Option<AllowlistProof>,
+    ) -> Result<()> {
+        ctx.accounts
+            .stake_token(amount, lock_period_days, allowlist, &ctx.bumps)
     }
 
     pub fn claim_position_nft(ctx: Context<ClaimPositionNft>) -> Result<()> {
-        ctx.accounts.claim_nft()
+        ctx.accounts.claim_nft(&ctx.bumps)
     }
 
     pub fn claim_position_token(ctx: Context<ClaimPositionToken>) -> Result<()> {
         ctx.accounts.claim_token()
     }
 
+    pub fn claim_position_token_partial(
+        ctx: Context<ClaimPositionToken>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.claim_token_partial(amount)
+    }
+
+    // Return principal while the config is in emergency mode, ignoring locks
+    // and cooldowns and forfeiting yield
+    pub fn emergency_withdraw_token(ctx: Context<ClaimPositionToken>) -> Result<()> {
+        ctx.accounts.emergency_withdraw_token()
+    }
+
+    pub fn emergency_withdraw_nft(ctx: Context<ClaimPositionNft>) -> Result<()> {
+        ctx.accounts.emergency_withdraw_nft(&ctx.bumps)
+    }
+
+    pub fn crank_claim(ctx: Context<CrankClaim>) -> Result<()> {
+        ctx.accounts.crank_claim()
+    }
+
+    pub fn request_unstake(ctx: Context<RequestUnstake>) -> Result<()> {
+        ctx.accounts.request_unstake()
+    }
+
+    pub fn extend_lock(ctx: Context<ExtendLock>, lock_period_days: Option<u16>) -> Result<()> {
+        ctx.accounts.extend_lock(lock_period_days, &ctx.bumps)
+    }
+
+    pub fn transfer_position(ctx: Context<TransferPosition>) -> Result<()> {
+        ctx.accounts.transfer_position(&ctx.bumps)
+    }
+
+    pub fn stake_liquid(ctx: Context<StakeLiquid>, amount: u64) -> Result<()> {
+        ctx.accounts.stake_liquid(amount)
+    }
+
+    pub fn request_redeem(ctx: Context<RequestRedeem>, shares: u64) -> Result<()> {
+        ctx.accounts.request_redeem(shares, &ctx.bumps)
+    }
+
+    pub fn redeem(ctx: Context<Redeem>) -> Result<()> {
+        ctx.accounts.redeem()
+    }
+
+    pub fn split_position(ctx: Context<SplitPosition>, amount: u64) -> Result<()> {
+        ctx.accounts.split_position(amount, &ctx.bumps)
+    }
+
+    pub fn merge_positions(ctx: Context<MergePositions>) -> Result<()> {
+        ctx.accounts.merge_positions()
+    }
+
+    pub fn migrate_position_seeds(ctx: Context<MigratePositionSeeds>) -> Result<()> {
+        ctx.accounts.migrate_position_seeds(&ctx.bumps)
+    }
+
+    // Bring accounts written before a layout change up to the current
+    // version, see `state::Versioned`
+    pub fn migrate_config(ctx: Context<MigrateAccount>) -> Result<()> {
+        ctx.accounts.migrate::<Config>()
+    }
+
+    pub fn migrate_pool(ctx: Context<MigrateAccount>) -> Result<()> {
+        ctx.accounts.migrate::<Pool>()
+    }
+
+    pub fn migrate_position(ctx: Context<MigrateAccount>) -> Result<()> {
+        ctx.accounts.migrate::<PositionV4>()
+    }
+
+    pub fn migrate_user_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        ctx.accounts.migrate::<UserAccountV3>()
+    }
+
+    // Read-only projections returned through return data, for clients to
+    // simulate instead of reimplementing the yield and capacity math
+    pub fn view_position_yield(ctx: Context<ViewPositionYield>) -> Result<PositionYieldView> {
+        ctx.accounts.view_position_yield()
+    }
+
+    pub fn view_pool_capacity(ctx: Context<ViewPoolCapacity>) -> Result<PoolCapacityView> {
+        ctx.accounts.view_pool_capacity()
+    }
+
+    pub fn view_user_capacity(ctx: Context<ViewUserCapacity>) -> Result<UserCapacityView> {
+        ctx.accounts.view_user_capacity()
+    }
+
     pub fn admin_pause_pool(ctx: Context<AdminSetPoolConfig>) -> Result<()> {
         ctx.accounts.admin_pause_pool()
     }
@@ -87,10 +208,56 @@ pub mod bert_staking_sc {
         ctx.accounts.admin_activate_pool()
     }
 
+    pub fn admin_enable_emergency(ctx: Context<AdminSetEmergency>) -> Result<()> {
+        ctx.accounts.admin_enable_emergency()
+    }
+
+    pub fn admin_disable_emergency(ctx: Context<AdminSetEmergency>) -> Result<()> {
+        ctx.accounts.admin_disable_emergency()
+    }
+
     pub fn admin_withdraw_tokens(ctx: Context<AdminWithdrawToken>, amount: u64) -> Result<()> {
         ctx.accounts.admin_withdraw_token(amount)
     }
 
+    pub fn admin_set_crank_tip(ctx: Context<AdminSetCrankTip>, tip_lamports: u64) -> Result<()> {
+        ctx.accounts.admin_set_crank_tip(tip_lamports)
+    }
+
+    pub fn admin_set_merkle_root(
+        ctx: Context<AdminSetPoolConfig>,
+        merkle_root: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.admin_set_merkle_root(merkle_root)
+    }
+
+    pub fn admin_add_collection(
+        ctx: Context<AdminAddCollection>,
+        nft_value_in_tokens: u64,
+        nfts_limit_per_user: u8,
+    ) -> Result<()> {
+        ctx.accounts
+            .admin_add_collection(nft_value_in_tokens, nfts_limit_per_user, &ctx.bumps)
+    }
+
+    pub fn admin_update_collection(
+        ctx: Context<AdminUpdateCollection>,
+        nft_value_in_tokens: u64,
+        nfts_limit_per_user: u8,
+        is_active: bool,
+    ) -> Result<()> {
+        ctx.accounts
+            .admin_update_collection(nft_value_in_tokens, nfts_limit_per_user, is_active)
+    }
+
+    pub fn admin_set_position_limit(
+        ctx: Context<AdminSetPositionLimit>,
+        max_positions_per_user: u16,
+    ) -> Result<()> {
+        ctx.accounts
+            .admin_set_position_limit(max_positions_per_user)
+    }
+
     pub fn admin_set_pool_config(
         ctx: Context<AdminSetPoolConfig>,
         config_params: PoolConfigArgs,
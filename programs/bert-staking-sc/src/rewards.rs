@@ -0,0 +1,219 @@
+//! Yield math shared by every claim path.
+//!
+//! Units:
+//! - `yield_rate` is the yield earned over one full lock term, scaled by
+//!   [`SCALING_FACTOR`]: `50_000_000` is 5% per term.
+//! - Multipliers and boosts are in basis points of [`BPS_DENOMINATOR`], so
+//!   `10_000` is 1x and a boost of `500` adds 5%.
+//!
+//! Every function rounds down, so rounding never pays out more than the exact
+//! yield. Intermediate products are computed in `u128` and a result that does
+//! not fit in `u64` is an `ArithmeticOverflow`.
+
+use anchor_lang::prelude::*;
+
+use crate::state::{StakingError, BPS_DENOMINATOR, SCALING_FACTOR};
+
+const SECONDS_PER_DAY: u128 = 86_400;
+
+/// Yield earned by `amount` over a full lock term at `yield_rate`, scaled by
+/// a lock multiplier in bps
+pub fn term_yield(amount: u64, yield_rate: u64, multiplier_bps: u32) -> Result<u64> {
+    let numerator = (amount as u128)
+        .checked_mul(yield_rate as u128)
+        .ok_or(StakingError::ArithmeticOverflow)?
+        .checked_mul(multiplier_bps as u128)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+
+    // A single division rounds down once instead of at every step
+    to_u64(numerator / (BPS_DENOMINATOR as u128 * SCALING_FACTOR as u128))
+}
+
+/// Share of `value` earned after `elapsed` seconds of a `duration` second
+/// term. Elapsed time is clamped to the term, and an empty term is fully
+/// earned.
+pub fn pro_rata(value: u64, elapsed: i64, duration: i64) -> Result<u64> {
+    if duration <= 0 {
+        return Ok(value);
+    }
+
+    let elapsed = elapsed.clamp(0, duration);
+    let accrued = (value as u128)
+        .checked_mul(elapsed as u128)
+        .ok_or(StakingError::ArithmeticOverflow)?
+        / duration as u128;
+
+    to_u64(accrued)
+}
+
+/// Yield earned by `amount` over `elapsed` seconds, at `yield_rate` per
+/// `period_days` day term. Used for continuously accruing liquid pools.
+pub fn period_yield(amount: u64, yield_rate: u64, period_days: u16, elapsed: i64) -> Result<u64> {
+    if elapsed <= 0 || period_days == 0 {
+        return Ok(0);
+    }
+
+    let numerator = (amount as u128)
+        .checked_mul(yield_rate as u128)
+        .ok_or(StakingError::ArithmeticOverflow)?
+        .checked_mul(elapsed as u128)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+    let denominator = SCALING_FACTOR as u128 * period_days as u128 * SECONDS_PER_DAY;
+
+    to_u64(numerator / denominator)
+}
+
+/// Adds a boost in bps on top of `value`
+pub fn apply_boost(value: u64, boost_bps: u64) -> Result<u64> {
+    let boosted = (value as u128)
+        .checked_mul(BPS_DENOMINATOR as u128 + boost_bps as u128)
+        .ok_or(StakingError::ArithmeticOverflow)?
+        / BPS_DENOMINATOR as u128;
+
+    to_u64(boosted)
+}
+
+/// Boost in bps for holding `nfts_staked` NFTs, capped at `max_boost_bps`
+pub fn nft_boost_bps(boost_bps_per_nft: u16, nfts_staked: u32, max_boost_bps: u16) -> u64 {
+    (boost_bps_per_nft as u64 * nfts_staked as u64).min(max_boost_bps as u64)
+}
+
+/// Multiplier in bps for a `lock_days` lock on a linear curve from 1x at
+/// `min_lock_days` to `max_boost_bps` at `max_lock_days`
+pub fn lock_multiplier_bps(
+    min_lock_days: u16,
+    max_lock_days: u16,
+    max_boost_bps: u32,
+    lock_days: u16,
+) -> Result<u32> {
+    let range = max_lock_days.saturating_sub(min_lock_days) as u64;
+    if range == 0 {
+        return Ok(max_boost_bps);
+    }
+
+    let max_extra = max_boost_bps
+        .checked_sub(BPS_DENOMINATOR)
+        .ok_or(StakingError::InvalidBoostCurve)? as u64;
+
+    let lock_days = lock_days.clamp(min_lock_days, max_lock_days);
+    let extra = max_extra * (lock_days - min_lock_days) as u64 / range;
+
+    Ok(BPS_DENOMINATOR + extra as u32)
+}
+
+fn to_u64(value: u128) -> Result<u64> {
+    value
+        .try_into()
+        .map_err(|_| StakingError::ArithmeticOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIVE_PERCENT: u64 = 50_000_000;
+    const DAY: i64 = 86_400;
+
+    #[test]
+    fn term_yield_applies_rate_and_multiplier() {
+        assert_eq!(term_yield(1_000_000, FIVE_PERCENT, 10_000).unwrap(), 50_000);
+        assert_eq!(term_yield(1_000_000, FIVE_PERCENT, 15_000).unwrap(), 75_000);
+        assert_eq!(term_yield(1_000_000, 0, 15_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn term_yield_rounds_down() {
+        // 19 * 5% = 0.95
+        assert_eq!(term_yield(19, FIVE_PERCENT, 10_000).unwrap(), 0);
+        // 39 * 5% * 1.0001 = 1.950195
+        assert_eq!(term_yield(39, FIVE_PERCENT, 10_001).unwrap(), 1);
+    }
+
+    #[test]
+    fn term_yield_handles_large_amounts() {
+        assert_eq!(
+            term_yield(u64::MAX, SCALING_FACTOR as u64, 10_000).unwrap(),
+            u64::MAX
+        );
+        assert!(term_yield(u64::MAX, SCALING_FACTOR as u64, 10_001).is_err());
+        assert!(term_yield(u64::MAX, u64::MAX, u32::MAX).is_err());
+    }
+
+    #[test]
+    fn pro_rata_clamps_elapsed_time() {
+        assert_eq!(pro_rata(1_000, 0, 10 * DAY).unwrap(), 0);
+        assert_eq!(pro_rata(1_000, 5 * DAY, 10 * DAY).unwrap(), 500);
+        assert_eq!(pro_rata(1_000, 20 * DAY, 10 * DAY).unwrap(), 1_000);
+        assert_eq!(pro_rata(1_000, -DAY, 10 * DAY).unwrap(), 0);
+        assert_eq!(pro_rata(1_000, DAY, 0).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn pro_rata_rounds_down() {
+        assert_eq!(pro_rata(1_000, 1, 3).unwrap(), 333);
+        assert_eq!(pro_rata(2, 1, 3).unwrap(), 0);
+        assert_eq!(
+            pro_rata(u64::MAX, DAY - 1, DAY).unwrap(),
+            u64::MAX - u64::MAX / DAY as u64 - 1
+        );
+    }
+
+    #[test]
+    fn period_yield_accrues_by_time() {
+        let amount = 1_000_000;
+        assert_eq!(
+            period_yield(amount, FIVE_PERCENT, 7, 7 * DAY).unwrap(),
+            50_000
+        );
+        assert_eq!(
+            period_yield(amount, FIVE_PERCENT, 7, 14 * DAY).unwrap(),
+            100_000
+        );
+        assert_eq!(period_yield(amount, FIVE_PERCENT, 7, DAY).unwrap(), 7_142);
+        assert_eq!(period_yield(amount, FIVE_PERCENT, 7, 0).unwrap(), 0);
+        assert_eq!(period_yield(amount, FIVE_PERCENT, 0, DAY).unwrap(), 0);
+    }
+
+    #[test]
+    fn period_yield_matches_term_yield_over_a_term() {
+        for amount in [1, 999, 1_000_000, 123_456_789_012] {
+            assert_eq!(
+                period_yield(amount, FIVE_PERCENT, 30, 30 * DAY).unwrap(),
+                term_yield(amount, FIVE_PERCENT, BPS_DENOMINATOR).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn boost_is_added_on_top() {
+        assert_eq!(apply_boost(10_000, 0).unwrap(), 10_000);
+        assert_eq!(apply_boost(10_000, 500).unwrap(), 10_500);
+        assert_eq!(apply_boost(3, 5_000).unwrap(), 4);
+        assert!(apply_boost(u64::MAX, 1).is_err());
+    }
+
+    #[test]
+    fn nft_boost_is_capped() {
+        assert_eq!(nft_boost_bps(100, 0, 500), 0);
+        assert_eq!(nft_boost_bps(100, 3, 500), 300);
+        assert_eq!(nft_boost_bps(100, 10, 500), 500);
+        assert_eq!(nft_boost_bps(u16::MAX, u32::MAX, 500), 500);
+        assert_eq!(nft_boost_bps(0, 10, 500), 0);
+    }
+
+    #[test]
+    fn lock_multiplier_is_linear() {
+        assert_eq!(lock_multiplier_bps(10, 30, 20_000, 10).unwrap(), 10_000);
+        assert_eq!(lock_multiplier_bps(10, 30, 20_000, 20).unwrap(), 15_000);
+        assert_eq!(lock_multiplier_bps(10, 30, 20_000, 30).unwrap(), 20_000);
+        // 10_000 * 1 / 3 rounds down
+        assert_eq!(lock_multiplier_bps(0, 3, 20_000, 1).unwrap(), 13_333);
+    }
+
+    #[test]
+    fn lock_multiplier_handles_degenerate_curves() {
+        assert_eq!(lock_multiplier_bps(7, 7, 12_000, 7).unwrap(), 12_000);
+        assert_eq!(lock_multiplier_bps(10, 30, 20_000, 40).unwrap(), 20_000);
+        assert!(lock_multiplier_bps(10, 30, 9_999, 20).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use super::StakingError;
+
+/// A Core collection accepted for NFT staking under a config
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct CollectionEntry {
+    /// Parent config reference
+    pub config: Pubkey,
+
+    /// The registered collection
+    pub collection: Pubkey,
+
+    /// Value of each NFT from this collection, in tokens
+    pub nft_value_in_tokens: u64,
+
+    /// Maximum NFTs from this collection per user
+    pub nfts_limit_per_user: u8,
+
+    /// Whether new stakes from this collection are accepted. Staked NFTs can
+    /// always be claimed.
+    pub is_active: bool,
+
+    /// Current NFTs from this collection staked across all pools
+    pub total_nfts_staked: u32,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Layout version, see `Versioned`
+    pub version: u8,
+
+    /// Padding for future extensions
+    pub _padding: [u8; 31],
+}
+
+/// A separate PDA for each user's NFT count in a registered collection
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct UserCollectionStats {
+    /// The user these stats belong to
+    pub user: Pubkey,
+
+    /// The collection entry these stats are for
+    pub collection_entry: Pubkey,
+
+    /// NFTs from the collection currently staked by the user
+    pub nfts_staked: u32,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Layout version, see `Versioned`
+    pub version: u8,
+
+    /// Padding for future extensions
+    pub _padding: [u8; 15],
+}
+
+impl CollectionEntry {
+    /// Counts a newly staked NFT from this collection against the user's limit
+    pub fn record_stake(&mut self, user_stats: &mut UserCollectionStats) -> Result<()> {
+        let nfts_staked = user_stats
+            .nfts_staked
+            .checked_add(1)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        require!(
+            nfts_staked <= self.nfts_limit_per_user as u32,
+            StakingError::CollectionNftLimitReached
+        );
+
+        user_stats.nfts_staked = nfts_staked;
+        self.total_nfts_staked = self
+            .total_nfts_staked
+            .checked_add(1)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    /// Releases an NFT counted by `record_stake` from the collection counts
+    pub fn record_claim(&mut self, user_stats: &mut UserCollectionStats) -> Result<()> {
+        user_stats.nfts_staked = user_stats
+            .nfts_staked
+            .checked_sub(1)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+        self.total_nfts_staked = self
+            .total_nfts_staked
+            .checked_sub(1)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+}
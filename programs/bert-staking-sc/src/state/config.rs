@@ -1,14 +1,61 @@
 use anchor_lang::prelude::*;
 
+use super::LockMode;
+
+/// Scale of pool yield rates: a `yield_rate` of `SCALING_FACTOR` pays 100% per lock term
 pub const SCALING_FACTOR: u32 = 1_000_000_000;
 
+/// Denominator for multipliers expressed in basis points (10_000 = 1x)
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Largest crank tip, as a share of a position's rent in bps. The tip is paid
+/// out of the rent of the position being closed, so it must stay below it.
+pub const MAX_CRANK_TIP_BPS: u32 = 5_000;
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace, Debug)]
 pub struct PoolConfigArgs {
-    pub lock_period_days: u16, // The lock period in days
-    pub yield_rate: u64,       // Yield rate in basis points
-    pub max_nfts_cap: u32,     // Maximum amount of NFTs that can be staked / user
-    pub max_tokens_cap: u64,   // Maximum amount of tokens that can be staked / user
-    pub max_value_cap: u64,    // Maximum combined value (tokens + nfts * nft_value) in the pool
+    /// The lock period in days
+    pub lock_period_days: u16,
+
+    /// Yield per lock term, scaled by `SCALING_FACTOR` (50_000_000 = 5%)
+    pub yield_rate: u64,
+
+    /// Maximum amount of NFTs that can be staked / user
+    pub max_nfts_cap: u32,
+
+    /// Maximum amount of tokens that can be staked / user
+    pub max_tokens_cap: u64,
+
+    /// Maximum combined value (tokens + nfts * nft_value) in the pool
+    pub max_value_cap: u64,
+
+    /// Fixed lock period or staker-chosen lock period
+    pub lock_mode: LockMode,
+
+    /// Shortest lock a staker can choose (flexible pools)
+    pub min_lock_days: u16,
+
+    /// Longest lock a staker can choose (flexible pools)
+    pub max_lock_days: u16,
+
+    /// Yield multiplier at `max_lock_days`, in bps (10_000 = 1x)
+    pub max_boost_bps: u32,
+
+    /// Extra token yield per staked NFT, in bps
+    pub nft_boost_bps_per_nft: u16,
+
+    /// Cap on the NFT holding boost, in bps
+    pub max_nft_boost_bps: u16,
+
+    /// Minimum amount per token position
+    pub min_stake_amount: u64,
+
+    /// Maximum amount per token position, zero for no limit
+    pub max_stake_per_position: u64,
+
+    /// Unstake cooldown in days, zero to release funds at unlock. Liquid
+    /// pools keep the redemption period they were created with.
+    pub unbonding_period_days: u16,
 }
 
 #[account]
@@ -35,6 +82,16 @@ pub struct Config {
     pub bump: u8,                 // PDA bump
     pub authority_vault_bump: u8, // Authority Vault bump
 
+    pub receipt_collection: Pubkey, // Collection for position receipts, owned by this config
+
+    pub crank_tip_lamports: u64, // Tip paid to crankers from a settled position's rent
+
+    pub max_positions_per_user: u16, // Cap on open positions per user, zero for no limit
+
+    pub version: u8, // Layout version, see `Versioned`
+
+    pub emergency: bool, // Halts stakes and allows early principal withdrawals
+
     // Padding
-    pub _padding: [u8; 96],
+    pub _padding: [u8; 52],
 }
@@ -46,4 +46,97 @@ pub enum StakingError {
 
     #[msg("Pool value limit reached")]
     PoolValueLimitReached,
+
+    #[msg("Lock period is not allowed in this pool")]
+    InvalidLockPeriod,
+
+    #[msg("Invalid lock boost curve")]
+    InvalidBoostCurve,
+
+    #[msg("New unlock time must be later than the current one")]
+    LockNotExtended,
+
+    #[msg("Target pool is the position's own pool, omit it to relock in place")]
+    SameTargetPool,
+
+    #[msg("Position cannot be transferred to its current owner")]
+    InvalidTransferRecipient,
+
+    #[msg("Receipt collection not initialized")]
+    ReceiptCollectionNotInitialized,
+
+    #[msg("Receipt collection already initialized")]
+    ReceiptCollectionAlreadyInitialized,
+
+    #[msg("Receipt accounts are missing or invalid")]
+    InvalidReceipt,
+
+    #[msg("Position is held through a receipt")]
+    PositionHasReceipt,
+
+    #[msg("Operation not supported for this pool type")]
+    InvalidPoolType,
+
+    #[msg("Pool must be empty to change its type")]
+    PoolNotEmpty,
+
+    #[msg("Redemption is still unbonding")]
+    RedemptionLocked,
+
+    #[msg("Liquid pool shares and assets must both be zero or both be nonzero")]
+    InvalidShareRatio,
+
+    #[msg("Unstake must be requested before withdrawing from this pool")]
+    CooldownNotStarted,
+
+    #[msg("Unstake cooldown has not elapsed")]
+    CooldownActive,
+
+    #[msg("This pool has no unstake cooldown")]
+    CooldownNotRequired,
+
+    #[msg("Positions cannot be merged")]
+    InvalidMerge,
+
+    #[msg("Crank tip exceeds the allowed share of position rent")]
+    CrankTipTooHigh,
+
+    #[msg("Staker is not on the pool allowlist")]
+    NotAllowlisted,
+
+    #[msg("Staker allowlist cap reached")]
+    AllowlistCapReached,
+
+    #[msg("Stake amount is below the pool minimum")]
+    StakeBelowMinimum,
+
+    #[msg("Stake amount is above the pool per-position maximum")]
+    StakeAboveMaximum,
+
+    #[msg("User has reached the maximum number of open positions")]
+    PositionLimitReached,
+
+    #[msg("Position already uses the current seed scheme")]
+    PositionAlreadyMigrated,
+
+    #[msg("Collection is not accepting new stakes")]
+    CollectionNotActive,
+
+    #[msg("User has reached the NFT limit for this collection")]
+    CollectionNftLimitReached,
+
+    #[msg("User collection stats do not belong to the collection entry")]
+    InvalidCollectionStats,
+
+    #[msg("Account is already on the current layout version")]
+    AccountAlreadyMigrated,
+
+    #[msg("Account is on an older layout version and must be migrated")]
+    StaleAccountVersion,
+
+    #[msg("Staking is halted while the config is in emergency mode")]
+    EmergencyActive,
+
+    #[msg("Config is not in emergency mode")]
+    EmergencyNotActive,
 }
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use super::StakingError;
+use crate::rewards;
+
+/// Share accounting for a liquid pool. One per `Pool` of type `Liquid`.
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct LiquidPool {
+    /// The pool this account backs
+    pub pool: Pubkey,
+
+    /// Fungible share mint, with the pool PDA as mint authority
+    pub share_mint: Pubkey,
+
+    /// Underlying tokens backing the outstanding shares, including accrued yield
+    pub total_assets: u64,
+
+    /// Underlying tokens owed to redemption tickets that are still unbonding
+    pub pending_redemptions: u64,
+
+    /// Last time yield was accrued into `total_assets`
+    pub last_accrual_at: i64,
+
+    /// Token account holding this pool's yield budget. It is funded with a
+    /// plain token transfer, like the authority vault, and is the only source
+    /// of liquid yield, so liquid accrual never draws on standard positions' yield.
+    pub reserve: Pubkey,
+
+    /// Id of the next redemption ticket
+    pub next_ticket_id: u64,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Share mint PDA bump
+    pub share_mint_bump: u8,
+
+    /// Reserve PDA bump
+    pub reserve_bump: u8,
+
+    /// Layout version, see `Versioned`
+    pub version: u8,
+
+    /// Padding for future extensions
+    pub _padding: [u8; 62],
+}
+
+impl LiquidPool {
+    /// Yield accrued on `total_assets` since the last accrual. The pool's
+    /// `yield_rate` is earned once per `lock_period_days`, pro rata by time.
+    pub fn pending_rewards(&self, yield_rate: u64, lock_period_days: u16, now: i64) -> Result<u64> {
+        let elapsed = now.saturating_sub(self.last_accrual_at);
+
+        rewards::period_yield(self.total_assets, yield_rate, lock_period_days, elapsed)
+    }
+
+    /// Shares minted for a deposit of `assets`, rounded down. An empty pool
+    /// mints 1:1. Shares without assets, or assets without shares, have no
+    /// price to mint at.
+    pub fn shares_for_assets(&self, assets: u64, share_supply: u64) -> Result<u64> {
+        if share_supply == 0 && self.total_assets == 0 {
+            return Ok(assets);
+        }
+
+        require!(
+            share_supply > 0 && self.total_assets > 0,
+            StakingError::InvalidShareRatio
+        );
+
+        let shares = (assets as u128)
+            .checked_mul(share_supply as u128)
+            .ok_or(StakingError::ArithmeticOverflow)?
+            .checked_div(self.total_assets as u128)
+            .ok_or(StakingError::ArithmeticOverflow)?
+            .try_into()
+            .map_err(|_| StakingError::ArithmeticOverflow)?;
+
+        Ok(shares)
+    }
+
+    /// Underlying tokens redeemable for `shares`, rounded down
+    pub fn assets_for_shares(&self, shares: u64, share_supply: u64) -> Result<u64> {
+        if share_supply == 0 {
+            return Ok(0);
+        }
+
+        let assets = (shares as u128)
+            .checked_mul(self.total_assets as u128)
+            .ok_or(StakingError::ArithmeticOverflow)?
+            .checked_div(share_supply as u128)
+            .ok_or(StakingError::ArithmeticOverflow)?
+            .try_into()
+            .map_err(|_| StakingError::ArithmeticOverflow)?;
+
+        Ok(assets)
+    }
+}
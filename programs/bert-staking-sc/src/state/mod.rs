@@ -1,13 +1,23 @@
+pub mod collection;
 pub mod config;
 pub mod error;
+pub mod liquid_pool;
 pub mod pool;
 pub mod position;
+pub mod redemption;
 pub mod user;
 pub mod user_pool_stats;
+pub mod version;
+pub mod view;
 
+pub use collection::*;
 pub use config::*;
 pub use error::*;
+pub use liquid_pool::*;
 pub use pool::*;
 pub use position::*;
+pub use redemption::*;
 pub use user::*;
 pub use user_pool_stats::*;
+pub use version::*;
+pub use view::*;
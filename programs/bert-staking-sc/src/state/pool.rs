@@ -1,4 +1,34 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::keccak};
+
+use super::{StakingError, BPS_DENOMINATOR};
+use crate::rewards;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum LockMode {
+    /// Every position locks for the pool's `lock_period_days`
+    Fixed,
+    /// The staker chooses a lock between `min_lock_days` and `max_lock_days`
+    Flexible,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum PoolType {
+    /// Stakes are tracked as per-user positions
+    Standard,
+    /// Stakes mint fungible shares of the pool's liquid pool account
+    Liquid,
+}
+
+/// Proof that a staker is on a pool's allowlist
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AllowlistProof {
+    /// Per-user cap on the staked value in this pool, in tokens. Zero means
+    /// only the pool's own caps apply.
+    pub cap: u64,
+
+    /// Sibling hashes from the leaf up to the root
+    pub proof: Vec<[u8; 32]>,
+}
 
 #[account]
 #[derive(InitSpace, Debug)]
@@ -12,7 +42,7 @@ pub struct Pool {
     /// The lock period in days
     pub lock_period_days: u16,
 
-    /// Yield rate in basis points (e.g., 500 = 5%)
+    /// Yield per lock term, scaled by `SCALING_FACTOR` (50_000_000 = 5%)
     pub yield_rate: u64,
 
     /// Maximum NFTs per user in this pool
@@ -45,6 +75,154 @@ pub struct Pool {
     /// PDA bump
     pub bump: u8,
 
+    /// Whether the lock period is fixed or chosen by the staker
+    pub lock_mode: LockMode,
+
+    /// Shortest lock a staker can choose in a flexible pool
+    pub min_lock_days: u16,
+
+    /// Longest lock a staker can choose in a flexible pool
+    pub max_lock_days: u16,
+
+    /// Yield multiplier in bps applied to a `max_lock_days` lock (10_000 = 1x).
+    /// The multiplier grows linearly from 1x at `min_lock_days`.
+    pub max_boost_bps: u32,
+
+    /// Extra token yield in bps for each NFT the user has staked in this pool
+    pub nft_boost_bps_per_nft: u16,
+
+    /// Cap on the extra token yield from staked NFTs, in bps
+    pub max_nft_boost_bps: u16,
+
+    /// Whether stakes create positions or mint liquid shares
+    pub pool_type: PoolType,
+
+    /// Delay between an unstake or redemption request and the release of
+    /// funds, in days. Zero disables the cooldown for positions.
+    pub unbonding_period_days: u16,
+
+    /// Root of the staker allowlist, as keccak256 leaves of `owner || cap`.
+    /// All zeroes leaves the pool open to everyone. Liquid pools are always
+    /// open, since their shares change hands freely.
+    pub merkle_root: [u8; 32],
+
+    /// Smallest amount a token position can hold
+    pub min_stake_amount: u64,
+
+    /// Largest amount a token position can hold. Zero means no limit.
+    pub max_stake_per_position: u64,
+
+    /// Value of the NFTs staked in the pool, each at its collection's value
+    /// when it was staked
+    pub total_nft_value: u64,
+
+    /// Layout version, see `Versioned`
+    pub version: u8,
+
     /// Padding for future extensions
-    pub _padding: [u8; 56],
+    pub _padding: [u8; 55],
+}
+
+impl Pool {
+    /// Resolves the lock period and yield multiplier for a new position.
+    ///
+    /// Fixed pools ignore the boost curve and only accept their own lock period.
+    pub fn resolve_lock(&self, lock_period_days: Option<u16>) -> Result<(u16, u32)> {
+        match self.lock_mode {
+            LockMode::Fixed => {
+                if let Some(days) = lock_period_days {
+                    require!(
+                        days == self.lock_period_days,
+                        StakingError::InvalidLockPeriod
+                    );
+                }
+
+                Ok((self.lock_period_days, BPS_DENOMINATOR))
+            }
+            LockMode::Flexible => {
+                let days = lock_period_days.ok_or(StakingError::InvalidLockPeriod)?;
+                require!(
+                    days >= self.min_lock_days && days <= self.max_lock_days,
+                    StakingError::InvalidLockPeriod
+                );
+
+                Ok((days, self.boost_multiplier_bps(days)?))
+            }
+        }
+    }
+
+    /// Checks a token position amount against the pool's per-position bounds
+    pub fn check_stake_amount(&self, amount: u64) -> Result<()> {
+        require!(
+            amount >= self.min_stake_amount,
+            StakingError::StakeBelowMinimum
+        );
+        require!(
+            self.max_stake_per_position == 0 || amount <= self.max_stake_per_position,
+            StakingError::StakeAboveMaximum
+        );
+
+        Ok(())
+    }
+
+    /// Whether staking in this pool requires an allowlist proof
+    pub fn is_allowlisted(&self) -> bool {
+        self.merkle_root != [0; 32]
+    }
+
+    /// Checks `owner` against the pool's allowlist and returns their per-user
+    /// value cap. Open pools and zero caps return `u64::MAX`.
+    pub fn allowlist_cap(&self, owner: &Pubkey, allowlist: Option<&AllowlistProof>) -> Result<u64> {
+        if !self.is_allowlisted() {
+            return Ok(u64::MAX);
+        }
+
+        let allowlist = allowlist.ok_or(StakingError::NotAllowlisted)?;
+
+        let mut node = keccak::hashv(&[owner.as_ref(), &allowlist.cap.to_le_bytes()]).to_bytes();
+        for sibling in allowlist.proof.iter() {
+            // Pairs are hashed in sorted order so proofs don't carry a direction
+            node = if node <= *sibling {
+                keccak::hashv(&[&node, sibling]).to_bytes()
+            } else {
+                keccak::hashv(&[sibling, &node]).to_bytes()
+            };
+        }
+
+        require!(node == self.merkle_root, StakingError::NotAllowlisted);
+
+        Ok(match allowlist.cap {
+            0 => u64::MAX,
+            cap => cap,
+        })
+    }
+
+    /// Value staked in the pool, tokens and NFTs alike
+    pub fn total_value(&self) -> Result<u64> {
+        self.total_tokens_staked
+            .checked_add(self.total_nft_value)
+            .ok_or(StakingError::ArithmeticOverflow.into())
+    }
+
+    /// Applies the NFT holding boost to the yield of a token position.
+    /// Disabled when `nft_boost_bps_per_nft` is zero.
+    pub fn apply_nft_boost(&self, yield_value: u64, nfts_staked: u32) -> Result<u64> {
+        let boost_bps = rewards::nft_boost_bps(
+            self.nft_boost_bps_per_nft,
+            nfts_staked,
+            self.max_nft_boost_bps,
+        );
+
+        rewards::apply_boost(yield_value, boost_bps)
+    }
+
+    /// Yield multiplier for a lock of `lock_days` on the pool's boost curve
+    pub fn boost_multiplier_bps(&self, lock_days: u16) -> Result<u32> {
+        rewards::lock_multiplier_bps(
+            self.min_lock_days,
+            self.max_lock_days,
+            self.max_boost_bps,
+            lock_days,
+        )
+    }
 }
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use super::{StakingError, BPS_DENOMINATOR};
+use crate::rewards;
+
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum PositionType {
     NFT,
@@ -10,8 +13,16 @@ pub enum PositionType {
 pub enum PositionStatus {
     Unclaimed,
     Claimed,
+    Unbonding,
 }
 
+/// Positions derived as `[position, owner, mint, id]` for tokens and
+/// `[position, owner, mint, asset, id]` for NFTs
+pub const POSITION_SEED_VERSION_LEGACY: u8 = 0;
+
+/// Positions derived as `[position, owner, pool, id]` for every position type
+pub const POSITION_SEED_VERSION: u8 = 1;
+
 #[account]
 #[derive(InitSpace, Debug)]
 pub struct PositionV4 {
@@ -48,6 +59,94 @@ pub struct PositionV4 {
     /// Last time yield was claimed
     pub last_claimed_at: i64,
 
+    /// Lock period chosen for this position, in days
+    pub lock_period_days: u16,
+
+    /// Yield multiplier in bps from the pool's boost curve (10_000 = 1x)
+    pub yield_multiplier_bps: u32,
+
+    /// Receipt asset that carries the claim rights, if one was minted
+    pub receipt: Pubkey,
+
+    /// Time when the unstake cooldown ends - only set while unbonding
+    pub cooldown_ends_at: i64,
+
+    /// PDA seed scheme this position was derived with
+    pub seed_version: u8,
+
+    /// Whether the NFT is counted in its collection's registry entry. NFTs
+    /// staked before collections were registered are not.
+    pub collection_counted: bool,
+
+    /// Layout version, see `Versioned`
+    pub version: u8,
+
     /// Padding for future extensions
-    pub _padding: [u8; 64],
+    pub _padding: [u8; 15],
+}
+
+impl PositionV4 {
+    /// Second component of the position PDA seeds: the config mint for legacy
+    /// positions, the pool otherwise
+    pub fn seed_key(&self, mint: Pubkey) -> Pubkey {
+        match self.seed_version {
+            POSITION_SEED_VERSION_LEGACY => mint,
+            _ => self.pool,
+        }
+    }
+
+    /// Asset component of the position PDA seeds. Only legacy NFT positions
+    /// are derived with it, and an empty seed hashes identically to an
+    /// omitted one.
+    pub fn asset_seed(&self) -> &[u8] {
+        match (self.seed_version, self.position_type) {
+            (POSITION_SEED_VERSION_LEGACY, PositionType::NFT) => self.asset.as_ref(),
+            _ => &[],
+        }
+    }
+
+    /// Yield multiplier for this position. Positions created before lock
+    /// boosts existed have no multiplier stored and earn the base rate.
+    pub fn multiplier_bps(&self) -> u32 {
+        if self.yield_multiplier_bps == 0 {
+            BPS_DENOMINATOR
+        } else {
+            self.yield_multiplier_bps
+        }
+    }
+
+    /// Yield earned over the full lock term at the given pool yield rate
+    pub fn term_yield(&self, yield_rate: u64) -> Result<u64> {
+        self.term_yield_on(self.amount, yield_rate)
+    }
+
+    /// Term yield earned by `amount` of this position's stake
+    pub fn term_yield_on(&self, amount: u64, yield_rate: u64) -> Result<u64> {
+        rewards::term_yield(amount, yield_rate, self.multiplier_bps())
+    }
+
+    /// Checks the position can release its principal at `now`. Pools with an
+    /// unbonding period require a finished `request_unstake` cooldown first.
+    pub fn check_withdrawable(&self, unbonding_period_days: u16, now: i64) -> Result<()> {
+        require!(now >= self.unlock_time, StakingError::PositionLocked);
+
+        if unbonding_period_days > 0 || self.status == PositionStatus::Unbonding {
+            require!(
+                self.status == PositionStatus::Unbonding,
+                StakingError::CooldownNotStarted
+            );
+            require!(now >= self.cooldown_ends_at, StakingError::CooldownActive);
+        }
+
+        Ok(())
+    }
+
+    /// Share of the term yield accrued between deposit and `now`, capped at unlock
+    pub fn accrued_yield(&self, yield_rate: u64, now: i64) -> Result<u64> {
+        let term_yield = self.term_yield(yield_rate)?;
+        let duration = self.unlock_time.saturating_sub(self.deposit_time);
+        let elapsed = now.saturating_sub(self.deposit_time);
+
+        rewards::pro_rata(term_yield, elapsed, duration)
+    }
 }
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// A pending redemption of liquid pool shares, released after unbonding
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct RedemptionTicket {
+    /// Owner of the redeemed shares
+    pub owner: Pubkey,
+
+    /// Pool the shares were redeemed from
+    pub pool: Pubkey,
+
+    /// Underlying tokens owed, fixed at request time
+    pub amount: u64,
+
+    /// Time when the tokens can be withdrawn
+    pub unlock_time: i64,
+
+    /// Ticket identifier
+    pub id: u64,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Layout version, see `Versioned`
+    pub version: u8,
+
+    /// Padding for future extensions
+    pub _padding: [u8; 31],
+}
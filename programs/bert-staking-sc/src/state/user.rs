@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use super::{PositionV4, StakingError, POSITION_SEED_VERSION_LEGACY};
+
 #[account]
 #[derive(InitSpace, Debug)]
 pub struct UserAccountV3 {
@@ -21,6 +23,54 @@ pub struct UserAccountV3 {
     /// PDA bump
     pub bump: u8,
 
+    /// Wallet approved to claim and relock on the owner's behalf. Proceeds
+    /// always go to the owner.
+    pub delegate: Pubkey,
+
+    /// Number of open positions
+    pub position_count: u32,
+
+    /// Id assigned to the user's next position
+    pub next_position_id: u64,
+
+    /// Layout version, see `Versioned`
+    pub version: u8,
+
     /// Padding for future extensions
-    pub _padding: [u8; 64],
+    pub _padding: [u8; 19],
+}
+
+impl UserAccountV3 {
+    /// Whether `signer` may act on positions of `owner`: the owner itself or
+    /// the delegate the owner approved
+    pub fn is_owner_or_delegate(&self, owner: Pubkey, signer: Pubkey) -> bool {
+        signer == owner || (self.delegate != Pubkey::default() && signer == self.delegate)
+    }
+
+    /// Reserves the next position id and counts the new position as open.
+    /// A `max_positions` of zero disables the limit. Positions still on the
+    /// legacy seeds aren't counted against it until they are migrated.
+    pub fn open_position(&mut self, max_positions: u16) -> Result<u64> {
+        require!(
+            max_positions == 0 || self.position_count < max_positions as u32,
+            StakingError::PositionLimitReached
+        );
+
+        let id = self.next_position_id;
+        self.next_position_id = id.checked_add(1).ok_or(StakingError::ArithmeticOverflow)?;
+        self.position_count = self
+            .position_count
+            .checked_add(1)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        Ok(id)
+    }
+
+    /// Counts `position` as closed. Legacy positions were opened before the
+    /// count was tracked and are only counted once their seeds are migrated.
+    pub fn close_position(&mut self, position: &PositionV4) {
+        if position.seed_version != POSITION_SEED_VERSION_LEGACY {
+            self.position_count = self.position_count.saturating_sub(1);
+        }
+    }
 }
@@ -25,6 +25,9 @@ pub struct UserPoolStatsAccount {
     /// PDA bump
     pub bump: u8,
 
+    /// Layout version, see `Versioned`
+    pub version: u8,
+
     /// Padding for future extensions
-    pub _padding: [u8; 64],
+    pub _padding: [u8; 63],
 }
@@ -0,0 +1,131 @@
+use anchor_lang::{error::ErrorCode, prelude::*, Discriminator, Space};
+
+use super::{
+    CollectionEntry, Config, LiquidPool, LockMode, Pool, PositionV4, RedemptionTicket,
+    StakingError, UserAccountV3, UserCollectionStats, UserPoolStatsAccount, BPS_DENOMINATOR,
+};
+
+/// An account that records the layout version it was written with.
+///
+/// Version 0 is an account type's original layout, from before accounts were
+/// versioned, and `VERSION` grows each time the layout changes. Instructions
+/// refuse accounts on an older version until the type's `migrate_*`
+/// instruction has converted them.
+pub trait Versioned {
+    /// Layout this program reads and writes
+    const VERSION: u8;
+
+    fn version(&self) -> u8;
+
+    fn set_version(&mut self, version: u8);
+
+    /// Whether the account is on the current layout
+    fn is_current(&self) -> bool {
+        self.version() == Self::VERSION
+    }
+}
+
+macro_rules! versioned {
+    ($($account:ty => $version:expr,)*) => {
+        $(
+            impl Versioned for $account {
+                const VERSION: u8 = $version;
+
+                fn version(&self) -> u8 {
+                    self.version
+                }
+
+                fn set_version(&mut self, version: u8) {
+                    self.version = version;
+                }
+            }
+        )*
+    };
+}
+
+versioned! {
+    Config => 1,
+    Pool => 1,
+    PositionV4 => 1,
+    UserAccountV3 => 1,
+    UserPoolStatsAccount => 0,
+    CollectionEntry => 0,
+    UserCollectionStats => 0,
+    LiquidPool => 0,
+    RedemptionTicket => 0,
+}
+
+/// An account type with an in-place migration from older layouts
+pub trait Migrate:
+    Versioned + AccountSerialize + AccountDeserialize + Discriminator + Space
+{
+    /// Fills in fields an older layout didn't have. Bytes added by growing
+    /// the account read as zero. `config` is the account's config, when the
+    /// migration was passed one.
+    fn upgrade(&mut self, from_version: u8, config: Option<&Account<Config>>) -> Result<()>;
+}
+
+/// Config v1 added the receipt collection, crank tip and position limit,
+/// whose zero values are their defaults
+impl Migrate for Config {
+    fn upgrade(&mut self, _from_version: u8, _config: Option<&Account<Config>>) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Pool v1 added lock modes, boosts, pool types, allowlists, stake bounds,
+/// unbonding and the staked NFT value. Migrating grows pools by 72 bytes.
+impl Migrate for Pool {
+    fn upgrade(&mut self, from_version: u8, config: Option<&Account<Config>>) -> Result<()> {
+        if from_version == 0 {
+            // A fixed lock with no boost, as `initialize_pool` creates, unless
+            // the pool's lock terms were set before it was versioned
+            if self.max_boost_bps == 0 {
+                self.lock_mode = LockMode::Fixed;
+                self.min_lock_days = self.lock_period_days;
+                self.max_lock_days = self.lock_period_days;
+                self.max_boost_bps = BPS_DENOMINATOR;
+            }
+
+            // NFTs staked before collections were registered are all worth
+            // the config's NFT value
+            let config = config.ok_or(ErrorCode::ConstraintAccountIsNone)?;
+            require_keys_eq!(config.key(), self.config, ErrorCode::ConstraintHasOne);
+            self.total_nft_value = (self.total_nfts_staked as u64)
+                .checked_mul(config.nft_value_in_tokens)
+                .ok_or(StakingError::ArithmeticOverflow)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Position v1 added the lock period, yield multiplier, receipt, cooldown,
+/// seed version and collection flag. Positions from before collections were
+/// registered stay uncounted.
+impl Migrate for PositionV4 {
+    fn upgrade(&mut self, from_version: u8, _config: Option<&Account<Config>>) -> Result<()> {
+        if from_version == 0 {
+            // Positions from before lock boosts earn the base rate over the
+            // lock they were created with
+            if self.yield_multiplier_bps == 0 {
+                self.yield_multiplier_bps = BPS_DENOMINATOR;
+            }
+            if self.lock_period_days == 0 {
+                let lock_days = self.unlock_time.saturating_sub(self.deposit_time) / 86400;
+                self.lock_period_days = lock_days.clamp(0, u16::MAX as i64) as u16;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// User account v1 added the delegate and position counters. Positions
+/// opened before the counters existed are counted once
+/// `migrate_position_seeds` re-keys them.
+impl Migrate for UserAccountV3 {
+    fn upgrade(&mut self, _from_version: u8, _config: Option<&Account<Config>>) -> Result<()> {
+        Ok(())
+    }
+}
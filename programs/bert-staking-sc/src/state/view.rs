@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+/// Yield projection for a position, returned by `view_position_yield`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PositionYieldView {
+    /// Yield accrued since deposit, as `extend_lock` would settle it now
+    pub pending_yield: u64,
+
+    /// Yield a claim pays once the position unlocks
+    pub projected_yield: u64,
+
+    /// Time when the position can be unlocked
+    pub unlock_time: i64,
+
+    /// Whether the principal can be claimed now, including any cooldown
+    pub is_withdrawable: bool,
+}
+
+/// Room left under a pool's value cap, returned by `view_pool_capacity`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PoolCapacityView {
+    /// Staked value in the pool (tokens + staked NFT values)
+    pub total_value: u64,
+
+    /// The pool's `max_value_cap`
+    pub max_value_cap: u64,
+
+    /// Value that can still be staked before the cap is reached
+    pub remaining_value: u64,
+}
+
+/// Room left for one user in a pool, returned by `view_user_capacity`.
+/// Allowlist caps are not included since they depend on the staker's proof.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UserCapacityView {
+    /// Value the user can still stake, under both the pool's
+    /// `max_tokens_cap` and its remaining `max_value_cap`
+    pub remaining_value: u64,
+
+    /// NFTs the user can still stake in this pool under `max_nfts_cap`
+    pub remaining_pool_nfts: u32,
+
+    /// NFTs the user can still stake across pools under the config's
+    /// `nfts_limit_per_user`
+    pub remaining_nfts: u32,
+
+    /// NFTs the user can still stake from the requested collection under its
+    /// `nfts_limit_per_user`
+    pub remaining_collection_nfts: Option<u32>,
+}
@@ -0,0 +1,1089 @@
+#![allow(dead_code)]
+
+use std::path::Path;
+
+use anchor_lang::{
+    solana_program::{
+        account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction,
+        pubkey::Pubkey,
+    },
+    AccountDeserialize, AccountSerialize, AnchorDeserialize,
+};
+use anchor_spl::{associated_token::spl_associated_token_account, token::spl_token};
+pub use bert_staking_client::pda::*;
+use bert_staking_client::ConfigKeys;
+use bert_staking_sc::{
+    state::{
+        AllowlistProof, Config, LiquidPool, LockMode, Pool, PoolCapacityView, PoolConfigArgs,
+        PositionV4, PositionYieldView, UserAccountV3, UserCapacityView, UserPoolStatsAccount,
+        Versioned,
+    },
+    StakingError,
+};
+use mpl_core::{
+    accounts::BaseAssetV1,
+    instructions::{CreateCollectionV2Builder, CreateV2Builder},
+    types::DataState,
+};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    bpf_loader,
+    clock::Clock,
+    compute_budget::ComputeBudgetInstruction,
+    instruction::InstructionError,
+    program_pack::Pack,
+    rent::Rent,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+
+pub const CONFIG_ID: u64 = 1;
+pub const DECIMALS: u8 = 6;
+pub const ONE_TOKEN: u64 = 10u64.pow(DECIMALS as u32);
+pub const MAX_CAP: u64 = 1_000_000_000 * ONE_TOKEN;
+pub const NFT_VALUE: u64 = 100_000 * ONE_TOKEN;
+pub const NFTS_LIMIT_PER_USER: u8 = 5;
+
+/// 5% per lock term, scaled by `SCALING_FACTOR`
+pub const YIELD_RATE: u64 = 50_000_000;
+pub const LOCK_DAYS: u16 = 7;
+pub const MAX_NFTS_CAP: u32 = 1_000;
+pub const MAX_TOKENS_CAP: u64 = 1_000_000_000 * ONE_TOKEN;
+pub const MAX_VALUE_CAP: u64 = 5_000_000_000 * ONE_TOKEN;
+
+/// Tokens each test user starts with
+pub const USER_TOKENS: u64 = 10_000_000 * ONE_TOKEN;
+
+/// Tokens the admin funds the authority vault with for yield
+pub const YIELD_FUNDS: u64 = 1_000_000 * ONE_TOKEN;
+
+/// Anchor 0.30 entrypoints tie the accounts slice to the account lifetimes,
+/// which `processor!` can't express. Leaking a copy of the slice satisfies
+/// both; the copies share their data with the runtime's accounts.
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    bert_staking_sc::entry(program_id, accounts, data)
+}
+
+/// Asserts that a transaction failed with the given program error
+pub fn assert_staking_error<T: std::fmt::Debug>(
+    result: Result<T, BanksClientError>,
+    error: StakingError,
+) {
+    let expected = u32::from(error);
+    match result.map_err(|err| err.unwrap()) {
+        Err(TransactionError::InstructionError(_, InstructionError::Custom(code))) => {
+            assert_eq!(
+                code, expected,
+                "expected {error:?} ({expected}), got {code}"
+            )
+        }
+        other => panic!("expected {error:?}, got {other:?}"),
+    }
+}
+
+/// Pool config args matching the pool's current settings
+pub fn pool_config_args(pool: &Pool) -> PoolConfigArgs {
+    PoolConfigArgs {
+        lock_period_days: pool.lock_period_days,
+        yield_rate: pool.yield_rate,
+        max_nfts_cap: pool.max_nfts_cap,
+        max_tokens_cap: pool.max_tokens_cap,
+        max_value_cap: pool.max_value_cap,
+        lock_mode: pool.lock_mode,
+        min_lock_days: pool.min_lock_days,
+        max_lock_days: pool.max_lock_days,
+        max_boost_bps: pool.max_boost_bps,
+        nft_boost_bps_per_nft: pool.nft_boost_bps_per_nft,
+        max_nft_boost_bps: pool.max_nft_boost_bps,
+        min_stake_amount: pool.min_stake_amount,
+        max_stake_per_position: pool.max_stake_per_position,
+        unbonding_period_days: pool.unbonding_period_days,
+    }
+}
+
+/// A local bank with the program, mpl-core, a token mint and an NFT
+/// collection. The context payer acts as the config authority. Instructions
+/// are built with the Rust client from `keys`.
+pub struct Harness {
+    pub ctx: ProgramTestContext,
+    pub admin: Keypair,
+    pub mint: Pubkey,
+    pub collection: Pubkey,
+    pub config: Pubkey,
+    pub keys: ConfigKeys,
+    nonce: u64,
+}
+
+impl Harness {
+    pub async fn new() -> Self {
+        let mut program_test = ProgramTest::default();
+        program_test.prefer_bpf(false);
+        program_test.add_program(
+            "bert_staking_sc",
+            bert_staking_sc::ID,
+            processor!(process_instruction),
+        );
+
+        let core = std::fs::read(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("../../tests/fixtures/mpl_core.so"),
+        )
+        .expect("tests/fixtures/mpl_core.so is missing");
+        program_test.add_account(
+            mpl_core::ID,
+            Account {
+                lamports: Rent::default().minimum_balance(core.len()).max(1),
+                data: core,
+                owner: bpf_loader::id(),
+                executable: true,
+                rent_epoch: 0,
+            },
+        );
+
+        let ctx = program_test.start_with_context().await;
+        let admin = ctx.payer.insecure_clone();
+        let keys = ConfigKeys::new(
+            admin.pubkey(),
+            CONFIG_ID,
+            Pubkey::default(),
+            Pubkey::default(),
+        );
+
+        let mut harness = Self {
+            ctx,
+            admin,
+            mint: Pubkey::default(),
+            collection: Pubkey::default(),
+            config: keys.config,
+            keys,
+            nonce: 0,
+        };
+
+        harness.mint = harness.create_mint().await;
+        harness.collection = harness.create_collection().await;
+        harness.keys.mint = harness.mint;
+        harness.keys.collection = harness.collection;
+        harness
+    }
+
+    /// A harness with an initialized config, authority vault funded for
+    /// yield, the collection registered and one fixed-lock pool at index 0
+    pub async fn ready() -> Self {
+        let mut harness = Self::new().await;
+        harness.initialize().await.unwrap();
+        harness.initialize_auth_vault().await.unwrap();
+        harness.fund_yield(YIELD_FUNDS).await;
+        harness
+            .add_collection(NFT_VALUE, NFTS_LIMIT_PER_USER)
+            .await
+            .unwrap();
+        harness
+            .initialize_pool(0, LOCK_DAYS, YIELD_RATE)
+            .await
+            .unwrap();
+        harness
+    }
+
+    pub fn pool(&self, index: u32) -> Pubkey {
+        self.keys.pool(index)
+    }
+
+    pub fn vault(&self) -> Pubkey {
+        self.keys.vault()
+    }
+
+    pub fn authority_vault(&self) -> Pubkey {
+        self.keys.authority_vault()
+    }
+
+    pub fn collection_entry(&self) -> Pubkey {
+        self.keys.collection_entry(&self.collection)
+    }
+
+    pub fn ata(&self, owner: &Pubkey) -> Pubkey {
+        self.keys.token_account(owner)
+    }
+
+    /// Sends `instructions` in one transaction paid by the admin. Each
+    /// transaction carries a distinct compute unit price so that repeating an
+    /// instruction never reuses a signature.
+    pub async fn send(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        self.nonce += 1;
+
+        let mut all = vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            ComputeBudgetInstruction::set_compute_unit_price(self.nonce),
+        ];
+        all.extend_from_slice(instructions);
+
+        let mut all_signers: Vec<&Keypair> = vec![&self.admin];
+        all_signers.extend(signers.iter().filter(|s| s.pubkey() != self.admin.pubkey()));
+
+        let blockhash = self.ctx.banks_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            &all,
+            Some(&self.admin.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+
+        self.ctx.banks_client.process_transaction(tx).await
+    }
+
+    /// Simulates a view instruction and decodes its return data
+    pub async fn simulate<T: AnchorDeserialize>(
+        &mut self,
+        ix: Instruction,
+    ) -> Result<T, BanksClientError> {
+        let blockhash = self.ctx.banks_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.admin.pubkey()),
+            &[&self.admin],
+            blockhash,
+        );
+
+        let simulation = self.ctx.banks_client.simulate_transaction(tx).await?;
+        if let Some(Err(err)) = simulation.result {
+            return Err(BanksClientError::TransactionError(err));
+        }
+
+        let return_data = simulation
+            .simulation_details
+            .and_then(|details| details.return_data)
+            .expect("view instruction set no return data");
+        Ok(T::try_from_slice(&return_data.data).unwrap())
+    }
+
+    pub async fn account<T: AccountDeserialize>(&mut self, address: Pubkey) -> T {
+        let account = self
+            .ctx
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| panic!("account {address} not found"));
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn core_account(&mut self, address: Pubkey) -> BaseAssetV1 {
+        let account = self
+            .ctx
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| panic!("asset {address} not found"));
+        BaseAssetV1::from_bytes(&account.data).unwrap()
+    }
+
+    pub async fn exists(&mut self, address: Pubkey) -> bool {
+        self.ctx
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .is_some()
+    }
+
+    pub async fn token_balance(&mut self, address: Pubkey) -> u64 {
+        let account = self
+            .ctx
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .unwrap();
+        spl_token::state::Account::unpack(&account.data)
+            .unwrap()
+            .amount
+    }
+
+    pub async fn config_account(&mut self) -> Config {
+        self.account(self.config).await
+    }
+
+    pub async fn pool_account(&mut self, index: u32) -> Pool {
+        self.account(self.pool(index)).await
+    }
+
+    pub async fn user_account(&mut self, owner: &Pubkey) -> UserAccountV3 {
+        self.account(self.keys.user_account(owner)).await
+    }
+
+    pub async fn user_pool_stats(&mut self, owner: &Pubkey, index: u32) -> UserPoolStatsAccount {
+        self.account(self.keys.user_pool_stats(owner, index)).await
+    }
+
+    pub async fn position(&mut self, address: Pubkey) -> PositionV4 {
+        self.account(address).await
+    }
+
+    /// Moves the bank clock forward
+    pub async fn warp_days(&mut self, days: i64) {
+        let mut clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp += days * 86_400;
+        self.ctx.set_sysvar(&clock);
+    }
+
+    async fn create_mint(&mut self) -> Pubkey {
+        let mint = Keypair::new();
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+        let admin = self.admin.pubkey();
+
+        self.send(
+            &[
+                system_instruction::create_account(
+                    &admin,
+                    &mint.pubkey(),
+                    rent.minimum_balance(spl_token::state::Mint::LEN),
+                    spl_token::state::Mint::LEN as u64,
+                    &spl_token::id(),
+                ),
+                spl_token::instruction::initialize_mint(
+                    &spl_token::id(),
+                    &mint.pubkey(),
+                    &admin,
+                    None,
+                    DECIMALS,
+                )
+                .unwrap(),
+            ],
+            &[&mint],
+        )
+        .await
+        .unwrap();
+
+        mint.pubkey()
+    }
+
+    async fn create_collection(&mut self) -> Pubkey {
+        let collection = Keypair::new();
+        let ix = CreateCollectionV2Builder::new()
+            .collection(collection.pubkey())
+            .payer(self.admin.pubkey())
+            .name("Bert".to_string())
+            .uri("https://example.com/bert.json".to_string())
+            .instruction();
+
+        self.send(&[ix], &[&collection]).await.unwrap();
+        collection.pubkey()
+    }
+
+    /// Mints a Core asset from the test collection to `owner`
+    pub async fn mint_asset(&mut self, owner: &Pubkey) -> Pubkey {
+        let asset = Keypair::new();
+        let ix = CreateV2Builder::new()
+            .asset(asset.pubkey())
+            .collection(Some(self.collection))
+            .authority(Some(self.admin.pubkey()))
+            .payer(self.admin.pubkey())
+            .owner(Some(*owner))
+            .data_state(DataState::AccountState)
+            .name("Bert #1".to_string())
+            .uri("https://example.com/bert/1.json".to_string())
+            .instruction();
+
+        self.send(&[ix], &[&asset]).await.unwrap();
+        asset.pubkey()
+    }
+
+    /// Creates `owner`'s token account if needed and mints `amount` to it
+    pub async fn mint_tokens(&mut self, owner: &Pubkey, amount: u64) {
+        let admin = self.admin.pubkey();
+        let ata = self.ata(owner);
+
+        let mut ixs = vec![];
+        if !self.exists(ata).await {
+            ixs.push(
+                spl_associated_token_account::instruction::create_associated_token_account(
+                    &admin,
+                    owner,
+                    &self.mint,
+                    &spl_token::id(),
+                ),
+            );
+        }
+        ixs.push(
+            spl_token::instruction::mint_to(
+                &spl_token::id(),
+                &self.mint,
+                &ata,
+                &admin,
+                &[],
+                amount,
+            )
+            .unwrap(),
+        );
+
+        self.send(&ixs, &[]).await.unwrap();
+    }
+
+    /// Mints yield tokens straight into the authority vault
+    pub async fn fund_yield(&mut self, amount: u64) {
+        let admin = self.admin.pubkey();
+        let ix = spl_token::instruction::mint_to(
+            &spl_token::id(),
+            &self.mint,
+            &self.authority_vault(),
+            &admin,
+            &[],
+            amount,
+        )
+        .unwrap();
+
+        self.send(&[ix], &[]).await.unwrap();
+    }
+
+    /// A funded wallet with a user account and `USER_TOKENS` tokens
+    pub async fn new_user(&mut self) -> Keypair {
+        let user = Keypair::new();
+        let admin = self.admin.pubkey();
+
+        self.send(
+            &[system_instruction::transfer(
+                &admin,
+                &user.pubkey(),
+                10_000_000_000,
+            )],
+            &[],
+        )
+        .await
+        .unwrap();
+
+        self.mint_tokens(&user.pubkey(), USER_TOKENS).await;
+        self.initialize_user(&user).await.unwrap();
+        user
+    }
+
+    /// The `initialize` instruction for a config created by `authority`
+    pub fn initialize_ix(&self, authority: &Pubkey) -> Instruction {
+        ConfigKeys::new(*authority, CONFIG_ID, self.mint, self.collection).initialize(
+            CONFIG_ID,
+            *authority,
+            MAX_CAP,
+            NFT_VALUE,
+            NFTS_LIMIT_PER_USER,
+        )
+    }
+
+    /// Creates the config as `initialize` would. Only the hardcoded admin
+    /// can send `initialize`, so the config is written directly with the
+    /// test admin as its authority and only its vault is created on-chain.
+    pub async fn initialize(&mut self) -> Result<(), BanksClientError> {
+        let admin = self.admin.pubkey();
+        let (config, bump) = find_config_pda(&admin, CONFIG_ID);
+
+        self.send(
+            &[
+                spl_associated_token_account::instruction::create_associated_token_account(
+                    &admin,
+                    &config,
+                    &self.mint,
+                    &spl_token::id(),
+                ),
+            ],
+            &[],
+        )
+        .await?;
+
+        self.write_account(
+            config,
+            &Config {
+                id: CONFIG_ID,
+                authority: admin,
+                mint: self.mint,
+                collection: self.collection,
+                vault: self.vault(),
+                authority_vault: Pubkey::default(),
+                nfts_vault: config,
+                admin_withdraw_destination: admin,
+                pool_count: 0,
+                max_cap: MAX_CAP,
+                nft_value_in_tokens: NFT_VALUE,
+                nfts_limit_per_user: NFTS_LIMIT_PER_USER,
+                total_staked_amount: 0,
+                total_nfts_staked: 0,
+                bump,
+                authority_vault_bump: 0,
+                receipt_collection: Pubkey::default(),
+                crank_tip_lamports: 0,
+                max_positions_per_user: 0,
+                version: Config::VERSION,
+                emergency: false,
+                _padding: [0; 52],
+            },
+        )
+        .await;
+
+        Ok(())
+    }
+
+    pub async fn initialize_auth_vault(&mut self) -> Result<(), BanksClientError> {
+        let ix = self.keys.initialize_auth_vault();
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn initialize_pool(
+        &mut self,
+        index: u32,
+        lock_period_days: u16,
+        yield_rate: u64,
+    ) -> Result<(), BanksClientError> {
+        let ix = self.keys.initialize_pool(
+            index,
+            lock_period_days,
+            yield_rate,
+            MAX_NFTS_CAP,
+            MAX_TOKENS_CAP,
+            MAX_VALUE_CAP,
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn initialize_user(&mut self, user: &Keypair) -> Result<(), BanksClientError> {
+        let ix = self.keys.initialize_user(user.pubkey(), 0);
+        self.send(&[ix], &[user]).await
+    }
+
+    pub async fn add_collection(
+        &mut self,
+        nft_value_in_tokens: u64,
+        nfts_limit_per_user: u8,
+    ) -> Result<(), BanksClientError> {
+        let ix = self.keys.admin_add_collection(
+            self.collection,
+            nft_value_in_tokens,
+            nfts_limit_per_user,
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn update_collection(
+        &mut self,
+        nft_value_in_tokens: u64,
+        nfts_limit_per_user: u8,
+        is_active: bool,
+    ) -> Result<(), BanksClientError> {
+        let ix = self.keys.admin_update_collection(
+            self.collection,
+            nft_value_in_tokens,
+            nfts_limit_per_user,
+            is_active,
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn pause_pool(&mut self, index: u32) -> Result<(), BanksClientError> {
+        let ix = self.keys.admin_pause_pool(index);
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn activate_pool(&mut self, index: u32) -> Result<(), BanksClientError> {
+        let ix = self.keys.admin_activate_pool(index);
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn set_pool_config(
+        &mut self,
+        index: u32,
+        config_params: PoolConfigArgs,
+    ) -> Result<(), BanksClientError> {
+        let ix = self.keys.admin_set_pool_config(index, config_params);
+        self.send(&[ix], &[]).await
+    }
+
+    /// Pauses the pool, applies `update` to its current config and reactivates it
+    pub async fn update_pool(&mut self, index: u32, update: impl FnOnce(&mut PoolConfigArgs)) {
+        let pool = self.pool_account(index).await;
+        let mut args = pool_config_args(&pool);
+        update(&mut args);
+
+        self.pause_pool(index).await.unwrap();
+        self.set_pool_config(index, args).await.unwrap();
+        self.activate_pool(index).await.unwrap();
+    }
+
+    pub async fn set_merkle_root(
+        &mut self,
+        index: u32,
+        merkle_root: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        let ix = self.keys.admin_set_merkle_root(index, merkle_root);
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn set_crank_tip(&mut self, tip_lamports: u64) -> Result<(), BanksClientError> {
+        let ix = self.keys.admin_set_crank_tip(tip_lamports);
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn set_position_limit(
+        &mut self,
+        max_positions_per_user: u16,
+    ) -> Result<(), BanksClientError> {
+        let ix = self.keys.admin_set_position_limit(max_positions_per_user);
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn admin_withdraw(&mut self, amount: u64) -> Result<(), BanksClientError> {
+        let ix = self.keys.admin_withdraw_tokens(self.admin.pubkey(), amount);
+        self.send(&[ix], &[]).await
+    }
+
+    /// Id the user's next position will be opened with
+    pub async fn next_position_id(&mut self, owner: &Pubkey) -> u64 {
+        self.user_account(owner).await.next_position_id
+    }
+
+    /// Address the user's next position will be created at in pool `index`
+    pub async fn next_position(&mut self, owner: &Pubkey, index: u32) -> Pubkey {
+        let id = self.next_position_id(owner).await;
+        self.keys.position(owner, index, id)
+    }
+
+    pub async fn stake_token_with(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        amount: u64,
+        lock_period_days: Option<u16>,
+        allowlist: Option<AllowlistProof>,
+    ) -> Result<Pubkey, BanksClientError> {
+        let owner = user.pubkey();
+        let id = self.next_position_id(&owner).await;
+
+        let ix = self
+            .keys
+            .stake_token(owner, index, id, amount, lock_period_days, allowlist, None);
+        self.send(&[ix], &[user])
+            .await
+            .map(|_| self.keys.position(&owner, index, id))
+    }
+
+    pub async fn stake_token(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        amount: u64,
+    ) -> Result<Pubkey, BanksClientError> {
+        self.stake_token_with(user, index, amount, None, None).await
+    }
+
+    pub async fn stake_nft(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        asset: Pubkey,
+    ) -> Result<Pubkey, BanksClientError> {
+        let owner = user.pubkey();
+        let id = self.next_position_id(&owner).await;
+
+        let ix = self
+            .keys
+            .stake_nft(owner, index, id, asset, self.collection, None, None, None);
+        self.send(&[ix], &[user])
+            .await
+            .map(|_| self.keys.position(&owner, index, id))
+    }
+
+    pub async fn claim_token(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        position: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        self.claim_token_as(user, &user.pubkey(), index, position)
+            .await
+    }
+
+    /// Claims `owner`'s position with `claimant` signing
+    pub async fn claim_token_as(
+        &mut self,
+        claimant: &Keypair,
+        owner: &Pubkey,
+        index: u32,
+        position: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let ix = self
+            .keys
+            .claim_position_token(claimant.pubkey(), *owner, index, position, None);
+        self.send(&[ix], &[claimant]).await
+    }
+
+    pub async fn claim_token_partial(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        position: Pubkey,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let owner = user.pubkey();
+        let ix = self
+            .keys
+            .claim_position_token_partial(owner, owner, index, position, None, amount);
+        self.send(&[ix], &[user]).await
+    }
+
+    pub async fn claim_nft(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        position: Pubkey,
+        asset: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let owner = user.pubkey();
+        let collection_counted = self.position(position).await.collection_counted;
+        let ix = self.keys.claim_position_nft(
+            owner,
+            owner,
+            owner,
+            index,
+            position,
+            asset,
+            self.collection,
+            collection_counted,
+            self.admin.pubkey(),
+            None,
+        );
+        self.send(&[ix], &[user]).await
+    }
+
+    pub async fn enable_emergency(&mut self) -> Result<(), BanksClientError> {
+        let ix = self.keys.admin_enable_emergency();
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn disable_emergency(&mut self) -> Result<(), BanksClientError> {
+        let ix = self.keys.admin_disable_emergency();
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn emergency_withdraw_token(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        position: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let owner = user.pubkey();
+        let ix = self
+            .keys
+            .emergency_withdraw_token(owner, owner, index, position, None);
+        self.send(&[ix], &[user]).await
+    }
+
+    pub async fn emergency_withdraw_nft(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        position: Pubkey,
+        asset: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let owner = user.pubkey();
+        let collection_counted = self.position(position).await.collection_counted;
+        let ix = self.keys.emergency_withdraw_nft(
+            owner,
+            owner,
+            owner,
+            index,
+            position,
+            asset,
+            self.collection,
+            collection_counted,
+            self.admin.pubkey(),
+            None,
+        );
+        self.send(&[ix], &[user]).await
+    }
+
+    pub async fn request_unstake(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        position: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let owner = user.pubkey();
+        let ix = self
+            .keys
+            .request_unstake(owner, owner, index, position, None);
+        self.send(&[ix], &[user]).await
+    }
+
+    /// Relocks a position, returning its address afterwards. Moving it into
+    /// `target_index` re-keys it under the user's next position id.
+    pub async fn extend_lock(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        target_index: Option<u32>,
+        position: Pubkey,
+        lock_period_days: Option<u16>,
+    ) -> Result<Pubkey, BanksClientError> {
+        let owner = user.pubkey();
+        let id = self.next_position_id(&owner).await;
+        let target = target_index.map(|target_index| (target_index, id));
+
+        let ix = self
+            .keys
+            .extend_lock(owner, owner, index, target, position, lock_period_days);
+        self.send(&[ix], &[user]).await?;
+        Ok(target
+            .map(|(target_index, id)| self.keys.position(&owner, target_index, id))
+            .unwrap_or(position))
+    }
+
+    /// Moves a token position to `new_owner`, returning its new address
+    pub async fn transfer_position(
+        &mut self,
+        user: &Keypair,
+        new_owner: &Keypair,
+        index: u32,
+        position: Pubkey,
+    ) -> Result<Pubkey, BanksClientError> {
+        let new_owner_key = new_owner.pubkey();
+        let id = self.next_position_id(&new_owner_key).await;
+
+        let ix = self.keys.transfer_position(
+            user.pubkey(),
+            new_owner_key,
+            index,
+            position,
+            id,
+            None,
+            None,
+        );
+        self.send(&[ix], &[user, new_owner])
+            .await
+            .map(|_| self.keys.position(&new_owner_key, index, id))
+    }
+
+    pub async fn split_position(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        position: Pubkey,
+        amount: u64,
+    ) -> Result<Pubkey, BanksClientError> {
+        let owner = user.pubkey();
+        let id = self.next_position_id(&owner).await;
+
+        let ix = self.keys.split_position(owner, index, position, id, amount);
+        self.send(&[ix], &[user])
+            .await
+            .map(|_| self.keys.position(&owner, index, id))
+    }
+
+    pub async fn merge_positions(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        position: Pubkey,
+        other_position: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let ix = self
+            .keys
+            .merge_positions(user.pubkey(), index, position, other_position);
+        self.send(&[ix], &[user]).await
+    }
+
+    /// Re-keys a legacy position in pool `index`, returning its new address
+    pub async fn migrate_position_seeds(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        position: Pubkey,
+    ) -> Result<Pubkey, BanksClientError> {
+        let owner = user.pubkey();
+        let id = self.next_position_id(&owner).await;
+
+        let ix = self
+            .keys
+            .migrate_position_seeds(owner, self.pool(index), position, id);
+        self.send(&[ix], &[user]).await?;
+        Ok(self.keys.position(&owner, index, id))
+    }
+
+    pub async fn initialize_receipt_collection(&mut self) -> Result<Pubkey, BanksClientError> {
+        let receipt_collection = Keypair::new();
+        let ix = self.keys.initialize_receipt_collection(
+            receipt_collection.pubkey(),
+            "Bert Receipts".to_string(),
+            "https://example.com/receipts.json".to_string(),
+        );
+
+        self.send(&[ix], &[&receipt_collection]).await?;
+        self.keys.receipt_collection = receipt_collection.pubkey();
+        Ok(receipt_collection.pubkey())
+    }
+
+    /// Stakes tokens and mints a receipt for the position into the config's
+    /// receipt collection
+    pub async fn stake_token_with_receipt(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        amount: u64,
+        receipt: &Keypair,
+    ) -> Result<Pubkey, BanksClientError> {
+        let owner = user.pubkey();
+        let id = self.next_position_id(&owner).await;
+
+        let ix =
+            self.keys
+                .stake_token(owner, index, id, amount, None, None, Some(receipt.pubkey()));
+        self.send(&[ix], &[user, receipt])
+            .await
+            .map(|_| self.keys.position(&owner, index, id))
+    }
+
+    pub async fn initialize_liquid_pool(
+        &mut self,
+        index: u32,
+        unbonding_period_days: u16,
+    ) -> Result<(), BanksClientError> {
+        let ix = self
+            .keys
+            .initialize_liquid_pool(index, unbonding_period_days);
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn stake_liquid(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let ix = self.keys.stake_liquid(user.pubkey(), index, amount);
+        self.send(&[ix], &[user]).await
+    }
+
+    /// Burns `shares` for a redemption ticket, returning the ticket id
+    pub async fn request_redeem(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        shares: u64,
+    ) -> Result<u64, BanksClientError> {
+        let liquid_pool: LiquidPool = self
+            .account(find_liquid_pool_pda(&self.pool(index)).0)
+            .await;
+        let id = liquid_pool.next_ticket_id;
+
+        let ix = self.keys.request_redeem(user.pubkey(), index, id, shares);
+        self.send(&[ix], &[user]).await.map(|_| id)
+    }
+
+    pub async fn redeem(
+        &mut self,
+        user: &Keypair,
+        index: u32,
+        id: u64,
+    ) -> Result<(), BanksClientError> {
+        let ix = self.keys.redeem(user.pubkey(), index, id);
+        self.send(&[ix], &[user]).await
+    }
+
+    pub async fn view_position_yield(
+        &mut self,
+        owner: &Pubkey,
+        index: u32,
+        position: Pubkey,
+    ) -> Result<PositionYieldView, BanksClientError> {
+        let ix = self.keys.view_position_yield(*owner, index, position);
+        self.simulate(ix).await
+    }
+
+    pub async fn view_pool_capacity(
+        &mut self,
+        index: u32,
+    ) -> Result<PoolCapacityView, BanksClientError> {
+        let ix = self.keys.view_pool_capacity(index);
+        self.simulate(ix).await
+    }
+
+    /// Capacity left for `owner` in pool `index` and, with `collection`, in
+    /// the test collection
+    pub async fn view_user_capacity(
+        &mut self,
+        owner: &Pubkey,
+        index: u32,
+        collection: bool,
+    ) -> Result<UserCapacityView, BanksClientError> {
+        let ix = self
+            .keys
+            .view_user_capacity(*owner, index, collection.then_some(self.collection));
+        self.simulate(ix).await
+    }
+
+    pub async fn migrate_config(&mut self) -> Result<(), BanksClientError> {
+        let ix = self.keys.migrate_config(self.admin.pubkey());
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn migrate_pool(&mut self, index: u32) -> Result<(), BanksClientError> {
+        let ix = self.keys.migrate_pool(self.admin.pubkey(), index);
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn migrate_position(&mut self, position: Pubkey) -> Result<(), BanksClientError> {
+        let ix = self.keys.migrate_position(self.admin.pubkey(), position);
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn migrate_user_account(&mut self, owner: &Pubkey) -> Result<(), BanksClientError> {
+        let ix = self.keys.migrate_user_account(self.admin.pubkey(), *owner);
+        self.send(&[ix], &[]).await
+    }
+
+    /// Deletes an account, as if it had never been created
+    pub async fn remove_account(&mut self, address: Pubkey) {
+        self.ctx.set_account(&address, &Account::default().into());
+    }
+
+    /// Replaces a program account's data, as an older program version would
+    /// have written it, funded for rent at its new size
+    pub async fn set_account_data(&mut self, address: Pubkey, data: Vec<u8>) {
+        let account = Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: bert_staking_sc::ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.ctx.set_account(&address, &account.into());
+    }
+
+    /// Overwrites a program account with `account`
+    pub async fn write_account<T: AccountSerialize>(&mut self, address: Pubkey, account: &T) {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        self.set_account_data(address, data).await;
+    }
+
+    /// Turns pool `index` into a flexible-lock pool with a linear boost curve
+    pub async fn make_flexible(
+        &mut self,
+        index: u32,
+        min_days: u16,
+        max_days: u16,
+        max_boost_bps: u32,
+    ) {
+        self.update_pool(index, |args| {
+            args.lock_mode = LockMode::Flexible;
+            args.min_lock_days = min_days;
+            args.max_lock_days = max_days;
+            args.max_boost_bps = max_boost_bps;
+        })
+        .await;
+    }
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use bert_staking_sc::{state::PositionStatus, StakingError};
+use common::*;
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn halts_stakes_in_every_pool() {
+    let mut h = Harness::ready().await;
+    h.initialize_pool(1, LOCK_DAYS * 2, YIELD_RATE)
+        .await
+        .unwrap();
+    let user = h.new_user().await;
+    let asset = h.mint_asset(&user.pubkey()).await;
+
+    h.enable_emergency().await.unwrap();
+    assert!(h.config_account().await.emergency);
+
+    for index in [0, 1] {
+        let result = h.stake_token(&user, index, ONE_TOKEN).await.map(|_| ());
+        assert_staking_error(result, StakingError::EmergencyActive);
+    }
+    let result = h.stake_nft(&user, 0, asset).await.map(|_| ());
+    assert_staking_error(result, StakingError::EmergencyActive);
+
+    h.disable_emergency().await.unwrap();
+    h.stake_token(&user, 1, ONE_TOKEN).await.unwrap();
+    h.stake_nft(&user, 0, asset).await.unwrap();
+}
+
+#[tokio::test]
+async fn withdraws_locked_tokens_without_yield() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let owner = user.pubkey();
+    let position = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+    let authority_vault = h.authority_vault();
+    let yield_funds = h.token_balance(authority_vault).await;
+
+    h.warp_days(1).await;
+    h.enable_emergency().await.unwrap();
+    h.emergency_withdraw_token(&user, 0, position)
+        .await
+        .unwrap();
+
+    let ata = h.ata(&owner);
+    assert_eq!(h.token_balance(ata).await, USER_TOKENS);
+    assert_eq!(h.token_balance(authority_vault).await, yield_funds);
+    assert_eq!(h.position(position).await.status, PositionStatus::Claimed);
+
+    assert_eq!(h.config_account().await.total_staked_amount, 0);
+    assert_eq!(h.pool_account(0).await.total_tokens_staked, 0);
+    assert_eq!(h.user_pool_stats(&owner, 0).await.total_value, 0);
+
+    let user_account = h.user_account(&owner).await;
+    assert_eq!(user_account.total_staked_value, 0);
+    assert_eq!(user_account.total_claimed_yield, 0);
+    assert_eq!(user_account.position_count, 0);
+}
+
+#[tokio::test]
+async fn returns_locked_nft_without_yield() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let owner = user.pubkey();
+    let asset = h.mint_asset(&owner).await;
+    let position = h.stake_nft(&user, 0, asset).await.unwrap();
+
+    h.enable_emergency().await.unwrap();
+    h.emergency_withdraw_nft(&user, 0, position, asset)
+        .await
+        .unwrap();
+
+    assert_eq!(h.core_account(asset).await.owner, owner);
+    let ata = h.ata(&owner);
+    assert_eq!(h.token_balance(ata).await, USER_TOKENS);
+    assert_eq!(h.position(position).await.status, PositionStatus::Claimed);
+    assert_eq!(h.pool_account(0).await.total_nfts_staked, 0);
+    assert_eq!(h.user_account(&owner).await.total_staked_nfts, 0);
+}
+
+#[tokio::test]
+async fn skips_the_unstake_cooldown() {
+    let mut h = Harness::ready().await;
+    h.update_pool(0, |args| args.unbonding_period_days = 3)
+        .await;
+    let user = h.new_user().await;
+    let position = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+
+    h.enable_emergency().await.unwrap();
+    h.emergency_withdraw_token(&user, 0, position)
+        .await
+        .unwrap();
+
+    let ata = h.ata(&user.pubkey());
+    assert_eq!(h.token_balance(ata).await, USER_TOKENS);
+}
+
+#[tokio::test]
+async fn releases_redemption_tickets_early() {
+    let mut h = Harness::ready().await;
+    h.initialize_liquid_pool(0, 3).await.unwrap();
+    let user = h.new_user().await;
+    h.stake_liquid(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+
+    let share_account =
+        get_associated_token_address(&user.pubkey(), &find_share_mint_pda(&h.pool(0)).0);
+    let shares = h.token_balance(share_account).await;
+    let id = h.request_redeem(&user, 0, shares).await.unwrap();
+
+    h.enable_emergency().await.unwrap();
+    h.redeem(&user, 0, id).await.unwrap();
+
+    let ticket = find_redemption_pda(&user.pubkey(), &h.pool(0), id).0;
+    assert!(!h.exists(ticket).await);
+    let ata = h.ata(&user.pubkey());
+    assert!(h.token_balance(ata).await >= USER_TOKENS - 1);
+}
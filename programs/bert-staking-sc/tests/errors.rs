@@ -0,0 +1,682 @@
+//! One test per `StakingError`. Errors that need state no instruction
+//! sequence produces are reached by writing the accounts directly.
+
+mod common;
+
+use anchor_lang::solana_program::keccak;
+use anchor_spl::associated_token::get_associated_token_address;
+use bert_staking_sc::{
+    state::{AllowlistProof, LiquidPool, LockMode, UserCapacityView},
+    StakingError,
+};
+use common::*;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+fn allowlist_leaf(owner: &Keypair, cap: u64) -> [u8; 32] {
+    keccak::hashv(&[owner.pubkey().as_ref(), &cap.to_le_bytes()]).to_bytes()
+}
+
+#[tokio::test]
+async fn position_locked() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let position = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+
+    h.warp_days(LOCK_DAYS as i64 - 1).await;
+    let result = h.claim_token(&user, 0, position).await;
+    assert_staking_error(result, StakingError::PositionLocked);
+}
+
+#[tokio::test]
+async fn nft_limit_reached() {
+    let mut h = Harness::ready().await;
+    h.update_pool(0, |args| args.max_nfts_cap = 1).await;
+
+    let user = h.new_user().await;
+    let first = h.mint_asset(&user.pubkey()).await;
+    let second = h.mint_asset(&user.pubkey()).await;
+    h.stake_nft(&user, 0, first).await.unwrap();
+
+    let result = h.stake_nft(&user, 0, second).await.map(|_| ());
+    assert_staking_error(result, StakingError::NftLimitReached);
+}
+
+#[tokio::test]
+async fn global_nft_limit_reached() {
+    let mut h = Harness::ready().await;
+    h.update_collection(NFT_VALUE, NFTS_LIMIT_PER_USER + 1, true)
+        .await
+        .unwrap();
+
+    let user = h.new_user().await;
+    for _ in 0..NFTS_LIMIT_PER_USER {
+        let asset = h.mint_asset(&user.pubkey()).await;
+        h.stake_nft(&user, 0, asset).await.unwrap();
+    }
+
+    let asset = h.mint_asset(&user.pubkey()).await;
+    let result = h.stake_nft(&user, 0, asset).await.map(|_| ());
+    assert_staking_error(result, StakingError::GlobalNftLimitReached);
+}
+
+#[tokio::test]
+async fn invalid_amount() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+
+    let result = h.stake_token(&user, 0, 0).await.map(|_| ());
+    assert_staking_error(result, StakingError::InvalidAmount);
+
+    let position = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+    h.warp_days(LOCK_DAYS as i64).await;
+    let result = h
+        .claim_token_partial(&user, 0, position, 1_000 * ONE_TOKEN)
+        .await;
+    assert_staking_error(result, StakingError::InvalidAmount);
+
+    let pool = h.pool_account(0).await;
+    let mut args = pool_config_args(&pool);
+    args.min_stake_amount = 100 * ONE_TOKEN;
+    args.max_stake_per_position = 10 * ONE_TOKEN;
+    h.pause_pool(0).await.unwrap();
+    let result = h.set_pool_config(0, args).await;
+    assert_staking_error(result, StakingError::InvalidAmount);
+}
+
+#[tokio::test]
+async fn invalid_position_type() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let asset = h.mint_asset(&user.pubkey()).await;
+    let position = h.stake_nft(&user, 0, asset).await.unwrap();
+
+    h.warp_days(LOCK_DAYS as i64).await;
+    let result = h.claim_token(&user, 0, position).await;
+    assert_staking_error(result, StakingError::InvalidPositionType);
+}
+
+#[tokio::test]
+async fn user_tokens_limit_cap_reached() {
+    let mut h = Harness::ready().await;
+    h.update_pool(0, |args| args.max_tokens_cap = 1_000 * ONE_TOKEN)
+        .await;
+
+    let user = h.new_user().await;
+    h.stake_token(&user, 0, 600 * ONE_TOKEN).await.unwrap();
+
+    let result = h.stake_token(&user, 0, 600 * ONE_TOKEN).await.map(|_| ());
+    assert_staking_error(result, StakingError::UserTokensLimitCapReached);
+}
+
+#[tokio::test]
+async fn pool_already_paused() {
+    let mut h = Harness::ready().await;
+    h.pause_pool(0).await.unwrap();
+
+    let result = h.pause_pool(0).await;
+    assert_staking_error(result, StakingError::PoolAlreadyPaused);
+
+    let user = h.new_user().await;
+    let result = h.stake_token(&user, 0, ONE_TOKEN).await.map(|_| ());
+    assert_staking_error(result, StakingError::PoolAlreadyPaused);
+}
+
+#[tokio::test]
+async fn pool_already_active() {
+    let mut h = Harness::ready().await;
+
+    let result = h.activate_pool(0).await;
+    assert_staking_error(result, StakingError::PoolAlreadyActive);
+}
+
+#[tokio::test]
+async fn invalid_pool_pause_state() {
+    let mut h = Harness::ready().await;
+    let args = pool_config_args(&h.pool_account(0).await);
+
+    let result = h.set_pool_config(0, args).await;
+    assert_staking_error(result, StakingError::InvalidPoolPauseState);
+}
+
+#[tokio::test]
+async fn insufficient_yield_funds() {
+    let mut h = Harness::ready().await;
+    let admin = h.admin.pubkey();
+    h.mint_tokens(&admin, 0).await;
+    h.admin_withdraw(YIELD_FUNDS).await.unwrap();
+
+    let user = h.new_user().await;
+    let position = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+
+    h.warp_days(LOCK_DAYS as i64).await;
+    let result = h.claim_token(&user, 0, position).await;
+    assert_staking_error(result, StakingError::InsufficientYieldFunds);
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let stranger = h.new_user().await;
+    let position = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+
+    h.warp_days(LOCK_DAYS as i64).await;
+    let result = h
+        .claim_token_as(&stranger, &user.pubkey(), 0, position)
+        .await;
+    assert_staking_error(result, StakingError::Unauthorized);
+}
+
+#[tokio::test]
+async fn initialize_unauthorized() {
+    let mut h = Harness::new().await;
+    let ix = h.initialize_ix(&h.admin.pubkey());
+    let result = h.send(&[ix], &[]).await;
+    assert_staking_error(result, StakingError::Unauthorized);
+}
+
+#[tokio::test]
+async fn authority_vault_already_initialized() {
+    let mut h = Harness::new().await;
+    h.initialize().await.unwrap();
+
+    let mut config = h.config_account().await;
+    config.authority_vault = Pubkey::new_unique();
+    h.write_account(h.config, &config).await;
+
+    let result = h.initialize_auth_vault().await;
+    assert_staking_error(result, StakingError::AuthorityVaultAlreadyInitialized);
+}
+
+#[tokio::test]
+async fn authority_vault_not_initialized() {
+    let mut h = Harness::ready().await;
+    let admin = h.admin.pubkey();
+    h.mint_tokens(&admin, 0).await;
+
+    let mut config = h.config_account().await;
+    config.authority_vault = Pubkey::default();
+    h.write_account(h.config, &config).await;
+
+    let result = h.admin_withdraw(ONE_TOKEN).await;
+    assert_staking_error(result, StakingError::AuthorityVaultNotInitialized);
+}
+
+#[tokio::test]
+async fn arithmetic_overflow() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+
+    let address = h.keys.user_account(&user.pubkey());
+    let mut user_account = h.user_account(&user.pubkey()).await;
+    user_account.next_position_id = u64::MAX;
+    h.write_account(address, &user_account).await;
+
+    let result = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.map(|_| ());
+    assert_staking_error(result, StakingError::ArithmeticOverflow);
+}
+
+#[tokio::test]
+async fn crank_tip_too_high() {
+    let mut h = Harness::ready().await;
+    let result = h.set_crank_tip(u64::MAX).await;
+    assert_staking_error(result, StakingError::CrankTipTooHigh);
+}
+
+#[tokio::test]
+async fn position_already_migrated() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let position = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+
+    // A current position copied to the address its legacy seeds derive
+    let mut account = h.position(position).await;
+    let (legacy, bump) = find_legacy_position_pda(&user.pubkey(), &h.mint, None, account.id);
+    account.bump = bump;
+    h.write_account(legacy, &account).await;
+
+    let result = h.migrate_position_seeds(&user, 0, legacy).await;
+    assert_staking_error(result, StakingError::PositionAlreadyMigrated);
+}
+
+#[tokio::test]
+async fn pool_value_limit_reached() {
+    let mut h = Harness::ready().await;
+    h.update_pool(0, |args| args.max_value_cap = 1_000 * ONE_TOKEN)
+        .await;
+
+    let user = h.new_user().await;
+    let other = h.new_user().await;
+    h.stake_token(&user, 0, 600 * ONE_TOKEN).await.unwrap();
+
+    let result = h.stake_token(&other, 0, 600 * ONE_TOKEN).await.map(|_| ());
+    assert_staking_error(result, StakingError::PoolValueLimitReached);
+
+    // NFTs count at their collection's value
+    h.update_collection(300 * ONE_TOKEN, NFTS_LIMIT_PER_USER, true)
+        .await
+        .unwrap();
+    let first = h.mint_asset(&other.pubkey()).await;
+    let second = h.mint_asset(&other.pubkey()).await;
+    h.stake_nft(&other, 0, first).await.unwrap();
+
+    let result = h.stake_nft(&other, 0, second).await;
+    assert_staking_error(result, StakingError::PoolValueLimitReached);
+}
+
+#[tokio::test]
+async fn invalid_lock_period() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+
+    let result = h
+        .stake_token_with(&user, 0, ONE_TOKEN, Some(LOCK_DAYS + 1), None)
+        .await
+        .map(|_| ());
+    assert_staking_error(result, StakingError::InvalidLockPeriod);
+
+    h.make_flexible(0, 7, 30, 20_000).await;
+    let result = h
+        .stake_token_with(&user, 0, ONE_TOKEN, Some(31), None)
+        .await
+        .map(|_| ());
+    assert_staking_error(result, StakingError::InvalidLockPeriod);
+}
+
+#[tokio::test]
+async fn invalid_boost_curve() {
+    let mut h = Harness::ready().await;
+    let mut args = pool_config_args(&h.pool_account(0).await);
+    args.lock_mode = LockMode::Flexible;
+    args.min_lock_days = 7;
+    args.max_lock_days = 30;
+    args.max_boost_bps = 9_999;
+
+    h.pause_pool(0).await.unwrap();
+    let result = h.set_pool_config(0, args).await;
+    assert_staking_error(result, StakingError::InvalidBoostCurve);
+}
+
+#[tokio::test]
+async fn lock_not_extended() {
+    let mut h = Harness::ready().await;
+    h.make_flexible(0, 7, 30, 20_000).await;
+
+    let user = h.new_user().await;
+    let position = h
+        .stake_token_with(&user, 0, 1_000 * ONE_TOKEN, Some(30), None)
+        .await
+        .unwrap();
+
+    let result = h.extend_lock(&user, 0, None, position, Some(7)).await;
+    assert_staking_error(result, StakingError::LockNotExtended);
+}
+
+#[tokio::test]
+async fn same_target_pool() {
+    let mut h = Harness::ready().await;
+    h.make_flexible(0, 7, 30, 20_000).await;
+
+    let user = h.new_user().await;
+    let position = h
+        .stake_token_with(&user, 0, 1_000 * ONE_TOKEN, Some(7), None)
+        .await
+        .unwrap();
+
+    let result = h.extend_lock(&user, 0, Some(0), position, Some(30)).await;
+    assert_staking_error(result, StakingError::SameTargetPool);
+}
+
+#[tokio::test]
+async fn invalid_transfer_recipient() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let position = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+
+    let result = h
+        .transfer_position(&user, &user, 0, position)
+        .await
+        .map(|_| ());
+    assert_staking_error(result, StakingError::InvalidTransferRecipient);
+}
+
+#[tokio::test]
+async fn receipt_collection_not_initialized() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+
+    let result = h
+        .stake_token_with_receipt(&user, 0, ONE_TOKEN, &Keypair::new())
+        .await
+        .map(|_| ());
+    assert_staking_error(result, StakingError::ReceiptCollectionNotInitialized);
+}
+
+#[tokio::test]
+async fn receipt_collection_already_initialized() {
+    let mut h = Harness::ready().await;
+    h.initialize_receipt_collection().await.unwrap();
+
+    let result = h.initialize_receipt_collection().await.map(|_| ());
+    assert_staking_error(result, StakingError::ReceiptCollectionAlreadyInitialized);
+}
+
+#[tokio::test]
+async fn invalid_receipt() {
+    let mut h = Harness::ready().await;
+    h.initialize_receipt_collection().await.unwrap();
+
+    let user = h.new_user().await;
+    let position = h
+        .stake_token_with_receipt(&user, 0, 1_000 * ONE_TOKEN, &Keypair::new())
+        .await
+        .unwrap();
+
+    h.warp_days(LOCK_DAYS as i64).await;
+    let result = h.claim_token(&user, 0, position).await;
+    assert_staking_error(result, StakingError::InvalidReceipt);
+}
+
+#[tokio::test]
+async fn position_has_receipt() {
+    let mut h = Harness::ready().await;
+    h.initialize_receipt_collection().await.unwrap();
+
+    let user = h.new_user().await;
+    let position = h
+        .stake_token_with_receipt(&user, 0, 1_000 * ONE_TOKEN, &Keypair::new())
+        .await
+        .unwrap();
+
+    let result = h
+        .split_position(&user, 0, position, 100 * ONE_TOKEN)
+        .await
+        .map(|_| ());
+    assert_staking_error(result, StakingError::PositionHasReceipt);
+}
+
+#[tokio::test]
+async fn invalid_pool_type() {
+    let mut h = Harness::ready().await;
+    h.initialize_liquid_pool(0, 3).await.unwrap();
+
+    let user = h.new_user().await;
+    let result = h.stake_token(&user, 0, ONE_TOKEN).await.map(|_| ());
+    assert_staking_error(result, StakingError::InvalidPoolType);
+}
+
+#[tokio::test]
+async fn liquid_pools_stay_open() {
+    let mut h = Harness::ready().await;
+    h.initialize_pool(1, LOCK_DAYS, YIELD_RATE).await.unwrap();
+    h.initialize_liquid_pool(0, 3).await.unwrap();
+
+    // Shares change hands freely, so an allowlist couldn't hold
+    let result = h.set_merkle_root(0, [1; 32]).await;
+    assert_staking_error(result, StakingError::InvalidPoolType);
+
+    h.set_merkle_root(1, [1; 32]).await.unwrap();
+    let result = h.initialize_liquid_pool(1, 3).await;
+    assert_staking_error(result, StakingError::InvalidPoolType);
+}
+
+#[tokio::test]
+async fn pool_not_empty() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    h.stake_token(&user, 0, ONE_TOKEN).await.unwrap();
+
+    let result = h.initialize_liquid_pool(0, 3).await;
+    assert_staking_error(result, StakingError::PoolNotEmpty);
+}
+
+#[tokio::test]
+async fn redemption_locked() {
+    let mut h = Harness::ready().await;
+    h.initialize_liquid_pool(0, 3).await.unwrap();
+
+    let user = h.new_user().await;
+    h.stake_liquid(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+
+    let share_account =
+        get_associated_token_address(&user.pubkey(), &find_share_mint_pda(&h.pool(0)).0);
+    let shares = h.token_balance(share_account).await;
+    let ticket = h.request_redeem(&user, 0, shares).await.unwrap();
+
+    h.warp_days(2).await;
+    let result = h.redeem(&user, 0, ticket).await;
+    assert_staking_error(result, StakingError::RedemptionLocked);
+}
+
+#[tokio::test]
+async fn invalid_share_ratio() {
+    let mut h = Harness::ready().await;
+    h.initialize_liquid_pool(0, 3).await.unwrap();
+
+    let user = h.new_user().await;
+    h.stake_liquid(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+
+    // Shares left outstanding against no assets
+    let address = find_liquid_pool_pda(&h.pool(0)).0;
+    let mut liquid_pool: LiquidPool = h.account(address).await;
+    liquid_pool.total_assets = 0;
+    h.write_account(address, &liquid_pool).await;
+
+    let result = h.stake_liquid(&user, 0, ONE_TOKEN).await;
+    assert_staking_error(result, StakingError::InvalidShareRatio);
+}
+
+#[tokio::test]
+async fn cooldown_not_started() {
+    let mut h = Harness::ready().await;
+    h.update_pool(0, |args| args.unbonding_period_days = 3)
+        .await;
+
+    let user = h.new_user().await;
+    let position = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+
+    h.warp_days(LOCK_DAYS as i64).await;
+    let result = h.claim_token(&user, 0, position).await;
+    assert_staking_error(result, StakingError::CooldownNotStarted);
+}
+
+#[tokio::test]
+async fn cooldown_active() {
+    let mut h = Harness::ready().await;
+    h.update_pool(0, |args| args.unbonding_period_days = 3)
+        .await;
+
+    let user = h.new_user().await;
+    let position = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+
+    h.warp_days(LOCK_DAYS as i64).await;
+    h.request_unstake(&user, 0, position).await.unwrap();
+
+    h.warp_days(2).await;
+    let result = h.claim_token(&user, 0, position).await;
+    assert_staking_error(result, StakingError::CooldownActive);
+}
+
+#[tokio::test]
+async fn cooldown_not_required() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let position = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+
+    h.warp_days(LOCK_DAYS as i64).await;
+    let result = h.request_unstake(&user, 0, position).await;
+    assert_staking_error(result, StakingError::CooldownNotRequired);
+}
+
+#[tokio::test]
+async fn invalid_merge() {
+    let mut h = Harness::ready().await;
+    h.initialize_pool(1, LOCK_DAYS, YIELD_RATE).await.unwrap();
+
+    let user = h.new_user().await;
+    let position = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+    let other = h.stake_token(&user, 1, 1_000 * ONE_TOKEN).await.unwrap();
+
+    let result = h.merge_positions(&user, 0, position, other).await;
+    assert_staking_error(result, StakingError::InvalidMerge);
+}
+
+#[tokio::test]
+async fn not_allowlisted() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let listed = Keypair::new();
+    h.set_merkle_root(0, allowlist_leaf(&listed, 0))
+        .await
+        .unwrap();
+
+    let result = h.stake_token(&user, 0, ONE_TOKEN).await.map(|_| ());
+    assert_staking_error(result, StakingError::NotAllowlisted);
+
+    let proof = AllowlistProof {
+        cap: 0,
+        proof: vec![],
+    };
+    let result = h
+        .stake_token_with(&user, 0, ONE_TOKEN, None, Some(proof))
+        .await
+        .map(|_| ());
+    assert_staking_error(result, StakingError::NotAllowlisted);
+}
+
+#[tokio::test]
+async fn allowlist_cap_reached() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let cap = 1_000 * ONE_TOKEN;
+    h.set_merkle_root(0, allowlist_leaf(&user, cap))
+        .await
+        .unwrap();
+
+    let proof = AllowlistProof { cap, proof: vec![] };
+    h.stake_token_with(&user, 0, cap, None, Some(proof.clone()))
+        .await
+        .unwrap();
+
+    let result = h
+        .stake_token_with(&user, 0, ONE_TOKEN, None, Some(proof))
+        .await
+        .map(|_| ());
+    assert_staking_error(result, StakingError::AllowlistCapReached);
+}
+
+#[tokio::test]
+async fn stake_below_minimum() {
+    let mut h = Harness::ready().await;
+    h.update_pool(0, |args| args.min_stake_amount = 100 * ONE_TOKEN)
+        .await;
+
+    let user = h.new_user().await;
+    let result = h.stake_token(&user, 0, 99 * ONE_TOKEN).await.map(|_| ());
+    assert_staking_error(result, StakingError::StakeBelowMinimum);
+}
+
+#[tokio::test]
+async fn stake_above_maximum() {
+    let mut h = Harness::ready().await;
+    h.update_pool(0, |args| args.max_stake_per_position = 100 * ONE_TOKEN)
+        .await;
+
+    let user = h.new_user().await;
+    let result = h.stake_token(&user, 0, 101 * ONE_TOKEN).await.map(|_| ());
+    assert_staking_error(result, StakingError::StakeAboveMaximum);
+}
+
+#[tokio::test]
+async fn position_limit_reached() {
+    let mut h = Harness::ready().await;
+    h.set_position_limit(1).await.unwrap();
+
+    let user = h.new_user().await;
+    h.stake_token(&user, 0, ONE_TOKEN).await.unwrap();
+
+    let result = h.stake_token(&user, 0, ONE_TOKEN).await.map(|_| ());
+    assert_staking_error(result, StakingError::PositionLimitReached);
+}
+
+#[tokio::test]
+async fn collection_not_active() {
+    let mut h = Harness::ready().await;
+    h.update_collection(NFT_VALUE, NFTS_LIMIT_PER_USER, false)
+        .await
+        .unwrap();
+
+    let user = h.new_user().await;
+    let asset = h.mint_asset(&user.pubkey()).await;
+    let result = h.stake_nft(&user, 0, asset).await.map(|_| ());
+    assert_staking_error(result, StakingError::CollectionNotActive);
+}
+
+#[tokio::test]
+async fn collection_nft_limit_reached() {
+    let mut h = Harness::ready().await;
+    h.update_collection(NFT_VALUE, 1, true).await.unwrap();
+
+    let user = h.new_user().await;
+    let first = h.mint_asset(&user.pubkey()).await;
+    let second = h.mint_asset(&user.pubkey()).await;
+    h.stake_nft(&user, 0, first).await.unwrap();
+
+    let result = h.stake_nft(&user, 0, second).await.map(|_| ());
+    assert_staking_error(result, StakingError::CollectionNftLimitReached);
+}
+
+#[tokio::test]
+async fn invalid_collection_stats() {
+    let mut h = Harness::ready().await;
+    let (owner, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    // Another user's stats can't stand in for the owner's
+    let mut ix = h.keys.view_user_capacity(owner, 0, Some(h.collection));
+    ix.accounts.last_mut().unwrap().pubkey = h.keys.user_collection_stats(&other, &h.collection);
+
+    let result = h.simulate::<UserCapacityView>(ix).await;
+    assert_staking_error(result, StakingError::InvalidCollectionStats);
+}
+
+#[tokio::test]
+async fn account_already_migrated() {
+    let mut h = Harness::ready().await;
+    let result = h.migrate_pool(0).await;
+    assert_staking_error(result, StakingError::AccountAlreadyMigrated);
+}
+
+#[tokio::test]
+async fn stale_account_version() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+
+    let mut config = h.config_account().await;
+    config.version = 0;
+    let address = h.config;
+    h.write_account(address, &config).await;
+
+    let result = h.stake_token(&user, 0, ONE_TOKEN).await.map(|_| ());
+    assert_staking_error(result, StakingError::StaleAccountVersion);
+}
+
+#[tokio::test]
+async fn emergency_active() {
+    let mut h = Harness::ready().await;
+    h.enable_emergency().await.unwrap();
+
+    let user = h.new_user().await;
+    let result = h.stake_token(&user, 0, ONE_TOKEN).await.map(|_| ());
+    assert_staking_error(result, StakingError::EmergencyActive);
+}
+
+#[tokio::test]
+async fn emergency_not_active() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let position = h.stake_token(&user, 0, ONE_TOKEN).await.unwrap();
+
+    let result = h.emergency_withdraw_token(&user, 0, position).await;
+    assert_staking_error(result, StakingError::EmergencyNotActive);
+}
@@ -0,0 +1,367 @@
+//! Runs random sequences of stakes, claims, pauses and pool config changes
+//! against a local bank and checks the staking counters against the open
+//! positions after every step. Set `PROPTEST_CASES` to run more sequences.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use bert_staking_sc::state::CollectionEntry;
+use common::*;
+use proptest::prelude::*;
+use solana_sdk::signature::{Keypair, Signer};
+
+const USERS: usize = 3;
+
+#[derive(Clone, Debug)]
+enum Op {
+    StakeToken {
+        user: usize,
+        amount: u64,
+    },
+    StakeNft {
+        user: usize,
+    },
+    Claim {
+        slot: usize,
+    },
+    ClaimPartial {
+        slot: usize,
+        amount: u64,
+    },
+    Split {
+        slot: usize,
+        amount: u64,
+    },
+    Merge {
+        slot: usize,
+        other: usize,
+    },
+    Transfer {
+        slot: usize,
+        to: usize,
+    },
+    Warp {
+        days: i64,
+    },
+    TogglePause,
+    SetCaps {
+        max_tokens_cap: u64,
+        max_value_cap: u64,
+        min_stake_amount: u64,
+    },
+}
+
+fn tokens() -> impl Strategy<Value = u64> {
+    (1..5_000u64).prop_map(|amount| amount * ONE_TOKEN)
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        4 => (0..USERS, tokens()).prop_map(|(user, amount)| Op::StakeToken { user, amount }),
+        2 => (0..USERS).prop_map(|user| Op::StakeNft { user }),
+        3 => any::<usize>().prop_map(|slot| Op::Claim { slot }),
+        2 => (any::<usize>(), tokens()).prop_map(|(slot, amount)| Op::ClaimPartial { slot, amount }),
+        1 => (any::<usize>(), tokens()).prop_map(|(slot, amount)| Op::Split { slot, amount }),
+        1 => (any::<usize>(), any::<usize>()).prop_map(|(slot, other)| Op::Merge { slot, other }),
+        1 => (any::<usize>(), 0..USERS).prop_map(|(slot, to)| Op::Transfer { slot, to }),
+        3 => (0..=LOCK_DAYS as i64 + 1).prop_map(|days| Op::Warp { days }),
+        1 => Just(Op::TogglePause),
+        1 => (tokens(), tokens(), 0..50u64).prop_map(|(tokens_cap, value_cap, min)| Op::SetCaps {
+            max_tokens_cap: tokens_cap * 4,
+            max_value_cap: value_cap * 100,
+            min_stake_amount: min * ONE_TOKEN,
+        }),
+    ]
+}
+
+/// An open position the sequence created, as the model expects it on chain
+#[derive(Clone, Debug)]
+struct OpenPosition {
+    user: usize,
+    address: Pubkey,
+    asset: Option<Pubkey>,
+    amount: u64,
+}
+
+/// Maps a random slot onto one of the open positions
+fn pick(positions: &[OpenPosition], slot: usize) -> Option<usize> {
+    (!positions.is_empty()).then(|| slot % positions.len())
+}
+
+struct Model {
+    harness: Harness,
+    users: Vec<Keypair>,
+    positions: Vec<OpenPosition>,
+    paused: bool,
+}
+
+impl Model {
+    async fn new() -> Self {
+        let mut harness = Harness::ready().await;
+        harness
+            .update_collection(NFT_VALUE, u8::MAX, true)
+            .await
+            .unwrap();
+
+        let mut users = vec![];
+        for _ in 0..USERS {
+            users.push(harness.new_user().await);
+        }
+
+        Self {
+            harness,
+            users,
+            positions: vec![],
+            paused: false,
+        }
+    }
+
+    /// Applies `op`. Rejected transactions leave the model untouched, since
+    /// a failed transaction must not change any account.
+    async fn apply(&mut self, op: &Op) {
+        let h = &mut self.harness;
+
+        match *op {
+            Op::StakeToken { user, amount } => {
+                if let Ok(address) = h.stake_token(&self.users[user], 0, amount).await {
+                    self.positions.push(OpenPosition {
+                        user,
+                        address,
+                        asset: None,
+                        amount,
+                    });
+                }
+            }
+            Op::StakeNft { user } => {
+                let owner = self.users[user].pubkey();
+                let asset = h.mint_asset(&owner).await;
+                if let Ok(address) = h.stake_nft(&self.users[user], 0, asset).await {
+                    self.positions.push(OpenPosition {
+                        user,
+                        address,
+                        asset: Some(asset),
+                        amount: NFT_VALUE,
+                    });
+                }
+            }
+            Op::Claim { slot } => {
+                let Some(i) = pick(&self.positions, slot) else {
+                    return;
+                };
+                let position = self.positions[i].clone();
+                let user = &self.users[position.user];
+
+                let result = match position.asset {
+                    Some(asset) => h.claim_nft(user, 0, position.address, asset).await,
+                    None => h.claim_token(user, 0, position.address).await,
+                };
+                if result.is_ok() {
+                    self.positions.remove(i);
+                }
+            }
+            Op::ClaimPartial { slot, amount } => {
+                let Some(i) = pick(&self.positions, slot) else {
+                    return;
+                };
+                let position = &mut self.positions[i];
+                let user = &self.users[position.user];
+
+                if h.claim_token_partial(user, 0, position.address, amount)
+                    .await
+                    .is_ok()
+                {
+                    position.amount -= amount;
+                }
+            }
+            Op::Split { slot, amount } => {
+                let Some(i) = pick(&self.positions, slot) else {
+                    return;
+                };
+                let position = &mut self.positions[i];
+                let user = position.user;
+
+                if let Ok(address) = h
+                    .split_position(&self.users[user], 0, position.address, amount)
+                    .await
+                {
+                    position.amount -= amount;
+                    self.positions.push(OpenPosition {
+                        user,
+                        address,
+                        asset: None,
+                        amount,
+                    });
+                }
+            }
+            Op::Merge { slot, other } => {
+                let (Some(i), Some(j)) =
+                    (pick(&self.positions, slot), pick(&self.positions, other))
+                else {
+                    return;
+                };
+                let position = self.positions[i].clone();
+                let other = self.positions[j].clone();
+
+                if h.merge_positions(
+                    &self.users[position.user],
+                    0,
+                    position.address,
+                    other.address,
+                )
+                .await
+                .is_ok()
+                {
+                    self.positions[i].amount += other.amount;
+                    self.positions.remove(j);
+                }
+            }
+            Op::Transfer { slot, to } => {
+                let Some(i) = pick(&self.positions, slot) else {
+                    return;
+                };
+                let position = self.positions[i].clone();
+
+                // NFT transfers need the collection accounts, which the
+                // harness only passes for token positions
+                if position.asset.is_some() {
+                    return;
+                }
+
+                if let Ok(address) = h
+                    .transfer_position(
+                        &self.users[position.user],
+                        &self.users[to],
+                        0,
+                        position.address,
+                    )
+                    .await
+                {
+                    self.positions[i] = OpenPosition {
+                        user: to,
+                        address,
+                        ..position
+                    };
+                }
+            }
+            Op::Warp { days } => h.warp_days(days).await,
+            Op::TogglePause => {
+                let result = if self.paused {
+                    h.activate_pool(0).await
+                } else {
+                    h.pause_pool(0).await
+                };
+                result.unwrap();
+                self.paused = !self.paused;
+            }
+            Op::SetCaps {
+                max_tokens_cap,
+                max_value_cap,
+                min_stake_amount,
+            } => {
+                let mut args = pool_config_args(&h.pool_account(0).await);
+                args.max_tokens_cap = max_tokens_cap;
+                args.max_value_cap = max_value_cap;
+                args.min_stake_amount = min_stake_amount;
+
+                if !self.paused {
+                    h.pause_pool(0).await.unwrap();
+                }
+                h.set_pool_config(0, args).await.unwrap();
+                if !self.paused {
+                    h.activate_pool(0).await.unwrap();
+                }
+            }
+        }
+    }
+
+    /// Checks every counter against the positions the model holds open
+    async fn check_invariants(&mut self) {
+        let h = &mut self.harness;
+
+        let mut token_total = 0;
+        let mut nft_total = 0;
+        let mut value_total = 0;
+        for position in self.positions.iter() {
+            let account = h.position(position.address).await;
+            assert_eq!(account.amount, position.amount, "{position:?}");
+            assert_eq!(account.owner, self.users[position.user].pubkey());
+
+            match position.asset {
+                Some(_) => nft_total += 1,
+                None => token_total += position.amount,
+            }
+            value_total += position.amount;
+        }
+
+        // Principal sits in the vault, yield only ever leaves the authority vault
+        let vault = h.vault();
+        assert_eq!(h.token_balance(vault).await, token_total);
+
+        let pool = h.pool_account(0).await;
+        assert_eq!(pool.total_tokens_staked, token_total);
+        assert_eq!(pool.total_nfts_staked, nft_total);
+        assert_eq!(pool.is_paused, self.paused);
+
+        let authority_vault = h.authority_vault();
+        assert_eq!(
+            h.token_balance(authority_vault).await,
+            YIELD_FUNDS - pool.lifetime_claimed_yield
+        );
+
+        let config = h.config_account().await;
+        assert_eq!(config.total_staked_amount, value_total);
+
+        let collection_entry = h.collection_entry();
+        let entry: CollectionEntry = h.account(collection_entry).await;
+        assert_eq!(entry.total_nfts_staked, nft_total);
+
+        let mut claimed_yield = 0;
+        for (index, user) in self.users.iter().enumerate() {
+            let owner = user.pubkey();
+            let open = self.positions.iter().filter(|p| p.user == index);
+
+            let tokens: u64 = open
+                .clone()
+                .filter(|p| p.asset.is_none())
+                .map(|p| p.amount)
+                .sum();
+            let nfts = open.clone().filter(|p| p.asset.is_some()).count() as u32;
+            let value: u64 = open.clone().map(|p| p.amount).sum();
+
+            let account = h.user_account(&owner).await;
+            assert_eq!(account.total_staked_token_amount, tokens);
+            assert_eq!(account.total_staked_nfts, nfts);
+            assert_eq!(account.total_staked_value, value);
+            assert_eq!(account.position_count as usize, open.count());
+
+            let stats_address = h.keys.user_pool_stats(&owner, 0);
+            if h.exists(stats_address).await {
+                let stats = h.user_pool_stats(&owner, 0).await;
+                assert_eq!(stats.tokens_staked, tokens);
+                assert_eq!(stats.nfts_staked, nfts);
+                assert_eq!(stats.total_value, value);
+                claimed_yield += stats.claimed_yield;
+            } else {
+                assert_eq!(value, 0);
+            }
+        }
+
+        assert_eq!(claimed_yield, pool.lifetime_claimed_yield);
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(8))]
+
+    #[test]
+    fn accounting_invariants_hold(ops in prop::collection::vec(op(), 1..32)) {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let mut model = Model::new().await;
+            for op in ops.iter() {
+                model.apply(op).await;
+                model.check_invariants().await;
+            }
+        });
+    }
+}
@@ -0,0 +1,347 @@
+mod common;
+
+use bert_staking_sc::{
+    state::{PositionStatus, PositionType},
+    StakingError,
+};
+use common::*;
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn initializes_config_vaults_and_pool() {
+    let mut h = Harness::ready().await;
+
+    let config = h.config_account().await;
+    assert_eq!(config.authority, h.admin.pubkey());
+    assert_eq!(config.mint, h.mint);
+    assert_eq!(config.vault, h.vault());
+    assert_eq!(config.authority_vault, h.authority_vault());
+    assert_eq!(config.nft_value_in_tokens, NFT_VALUE);
+    assert_eq!(config.total_staked_amount, 0);
+
+    let pool = h.pool_account(0).await;
+    assert_eq!(pool.config, h.config);
+    assert_eq!(pool.lock_period_days, LOCK_DAYS);
+    assert_eq!(pool.yield_rate, YIELD_RATE);
+    assert!(!pool.is_paused);
+
+    let vault = h.authority_vault();
+    assert_eq!(h.token_balance(vault).await, YIELD_FUNDS);
+
+    let user = h.new_user().await;
+    let user_account = h.user_account(&user.pubkey()).await;
+    assert_eq!(user_account.config, h.config);
+    assert_eq!(user_account.total_staked_value, 0);
+    assert_eq!(user_account.next_position_id, 0);
+}
+
+#[tokio::test]
+async fn stakes_and_claims_tokens_with_yield() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let owner = user.pubkey();
+    let amount = 1_000 * ONE_TOKEN;
+
+    let position = h.stake_token(&user, 0, amount).await.unwrap();
+
+    let staked = h.position(position).await;
+    assert_eq!(staked.owner, owner);
+    assert_eq!(staked.amount, amount);
+    assert_eq!(staked.position_type, PositionType::Token);
+    assert_eq!(staked.status, PositionStatus::Unclaimed);
+    assert_eq!(staked.lock_period_days, LOCK_DAYS);
+
+    let vault = h.vault();
+    assert_eq!(h.token_balance(vault).await, amount);
+    assert_eq!(h.pool_account(0).await.total_tokens_staked, amount);
+    assert_eq!(h.user_pool_stats(&owner, 0).await.tokens_staked, amount);
+    assert_eq!(h.user_account(&owner).await.total_staked_value, amount);
+    assert_eq!(h.config_account().await.total_staked_amount, amount);
+
+    h.warp_days(LOCK_DAYS as i64).await;
+    h.claim_token(&user, 0, position).await.unwrap();
+
+    let yield_value = amount * YIELD_RATE / 1_000_000_000;
+    let ata = h.ata(&owner);
+    assert_eq!(h.token_balance(ata).await, USER_TOKENS + yield_value);
+    assert_eq!(h.token_balance(vault).await, 0);
+    let authority_vault = h.authority_vault();
+    assert_eq!(
+        h.token_balance(authority_vault).await,
+        YIELD_FUNDS - yield_value
+    );
+
+    assert_eq!(h.position(position).await.status, PositionStatus::Claimed);
+
+    let pool = h.pool_account(0).await;
+    assert_eq!(pool.total_tokens_staked, 0);
+    assert_eq!(pool.lifetime_tokens_staked, amount);
+    assert_eq!(pool.lifetime_claimed_yield, yield_value);
+
+    let stats = h.user_pool_stats(&owner, 0).await;
+    assert_eq!(stats.tokens_staked, 0);
+    assert_eq!(stats.total_value, 0);
+    assert_eq!(stats.claimed_yield, yield_value);
+
+    let user_account = h.user_account(&owner).await;
+    assert_eq!(user_account.total_staked_value, 0);
+    assert_eq!(user_account.total_claimed_yield, yield_value);
+    assert_eq!(user_account.position_count, 0);
+    assert_eq!(h.config_account().await.total_staked_amount, 0);
+}
+
+#[tokio::test]
+async fn partially_claims_tokens() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let owner = user.pubkey();
+    let amount = 1_000 * ONE_TOKEN;
+    let withdrawn = 400 * ONE_TOKEN;
+
+    let position = h.stake_token(&user, 0, amount).await.unwrap();
+    h.warp_days(LOCK_DAYS as i64).await;
+    h.claim_token_partial(&user, 0, position, withdrawn)
+        .await
+        .unwrap();
+
+    let remaining = h.position(position).await;
+    assert_eq!(remaining.amount, amount - withdrawn);
+    assert_eq!(remaining.status, PositionStatus::Unclaimed);
+
+    let yield_value = withdrawn * YIELD_RATE / 1_000_000_000;
+    let ata = h.ata(&owner);
+    assert_eq!(
+        h.token_balance(ata).await,
+        USER_TOKENS - amount + withdrawn + yield_value
+    );
+    assert_eq!(
+        h.pool_account(0).await.total_tokens_staked,
+        amount - withdrawn
+    );
+    assert_eq!(h.user_account(&owner).await.position_count, 1);
+}
+
+#[tokio::test]
+async fn stakes_and_claims_nft_with_yield() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let owner = user.pubkey();
+    let asset = h.mint_asset(&owner).await;
+
+    let position = h.stake_nft(&user, 0, asset).await.unwrap();
+
+    let staked = h.position(position).await;
+    assert_eq!(staked.position_type, PositionType::NFT);
+    assert_eq!(staked.asset, asset);
+    assert_eq!(staked.amount, NFT_VALUE);
+
+    let held = h.core_account(asset).await;
+    assert_eq!(held.owner, h.config);
+
+    let pool = h.pool_account(0).await;
+    assert_eq!(pool.total_nfts_staked, 1);
+    assert_eq!(h.user_pool_stats(&owner, 0).await.nfts_staked, 1);
+    assert_eq!(h.user_account(&owner).await.total_staked_nfts, 1);
+
+    h.warp_days(LOCK_DAYS as i64).await;
+    h.claim_nft(&user, 0, position, asset).await.unwrap();
+
+    let returned = h.core_account(asset).await;
+    assert_eq!(returned.owner, owner);
+
+    let yield_value = NFT_VALUE * YIELD_RATE / 1_000_000_000;
+    let ata = h.ata(&owner);
+    assert_eq!(h.token_balance(ata).await, USER_TOKENS + yield_value);
+
+    assert_eq!(h.position(position).await.status, PositionStatus::Claimed);
+    assert_eq!(h.pool_account(0).await.total_nfts_staked, 0);
+    assert_eq!(h.user_pool_stats(&owner, 0).await.nfts_staked, 0);
+
+    let user_account = h.user_account(&owner).await;
+    assert_eq!(user_account.total_staked_nfts, 0);
+    assert_eq!(user_account.total_staked_value, 0);
+    assert_eq!(user_account.position_count, 0);
+}
+
+#[tokio::test]
+async fn claims_after_unstake_cooldown() {
+    let mut h = Harness::ready().await;
+    h.update_pool(0, |args| args.unbonding_period_days = 3)
+        .await;
+
+    let user = h.new_user().await;
+    let position = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+
+    h.warp_days(LOCK_DAYS as i64).await;
+    h.request_unstake(&user, 0, position).await.unwrap();
+    assert_eq!(h.position(position).await.status, PositionStatus::Unbonding);
+
+    h.warp_days(3).await;
+    h.claim_token(&user, 0, position).await.unwrap();
+    assert_eq!(h.position(position).await.status, PositionStatus::Claimed);
+}
+
+#[tokio::test]
+async fn extends_lock_in_place() {
+    let mut h = Harness::ready().await;
+    h.make_flexible(0, 7, 30, 20_000).await;
+
+    let user = h.new_user().await;
+    let owner = user.pubkey();
+    let amount = 1_000 * ONE_TOKEN;
+    let position = h
+        .stake_token_with(&user, 0, amount, Some(7), None)
+        .await
+        .unwrap();
+
+    h.warp_days(7).await;
+    h.extend_lock(&user, 0, None, position, Some(30))
+        .await
+        .unwrap();
+
+    let extended = h.position(position).await;
+    assert_eq!(extended.pool, h.pool(0));
+    assert_eq!(extended.lock_period_days, 30);
+
+    // The settled yield is recorded once, and the stake stays where it was
+    let ata = h.ata(&owner);
+    let yield_value = h.token_balance(ata).await - (USER_TOKENS - amount);
+    assert!(yield_value > 0);
+
+    let pool = h.pool_account(0).await;
+    assert_eq!(pool.total_tokens_staked, amount);
+    assert_eq!(pool.lifetime_claimed_yield, yield_value);
+
+    let stats = h.user_pool_stats(&owner, 0).await;
+    assert_eq!(stats.tokens_staked, amount);
+    assert_eq!(stats.claimed_yield, yield_value);
+    assert_eq!(
+        h.user_account(&owner).await.total_claimed_yield,
+        yield_value
+    );
+}
+
+#[tokio::test]
+async fn claims_after_moving_pools() {
+    let mut h = Harness::ready().await;
+    h.initialize_pool(1, LOCK_DAYS, YIELD_RATE).await.unwrap();
+    h.make_flexible(0, 7, 30, 20_000).await;
+    h.make_flexible(1, 7, 60, 20_000).await;
+
+    let user = h.new_user().await;
+    let owner = user.pubkey();
+    let amount = 1_000 * ONE_TOKEN;
+    let position = h
+        .stake_token_with(&user, 0, amount, Some(7), None)
+        .await
+        .unwrap();
+
+    h.warp_days(7).await;
+    let moved = h
+        .extend_lock(&user, 0, Some(1), position, Some(60))
+        .await
+        .unwrap();
+
+    // The position is re-keyed under the target pool and the old one is gone
+    assert_ne!(moved, position);
+    assert_eq!(moved, h.keys.position(&owner, 1, 1));
+    assert!(!h.exists(position).await);
+    let extended = h.position(moved).await;
+    assert_eq!(extended.pool, h.pool(1));
+    assert_eq!(extended.lock_period_days, 60);
+    assert_eq!(h.pool_account(0).await.total_tokens_staked, 0);
+    assert_eq!(h.pool_account(1).await.total_tokens_staked, amount);
+    assert_eq!(h.user_account(&owner).await.position_count, 1);
+
+    h.warp_days(60).await;
+    h.claim_token(&user, 1, moved).await.unwrap();
+    assert_eq!(h.position(moved).await.status, PositionStatus::Claimed);
+    assert_eq!(h.pool_account(1).await.total_tokens_staked, 0);
+}
+
+#[tokio::test]
+async fn splits_merges_and_transfers_positions() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let recipient = h.new_user().await;
+    let amount = 1_000 * ONE_TOKEN;
+
+    let position = h.stake_token(&user, 0, amount).await.unwrap();
+    let split = h
+        .split_position(&user, 0, position, 300 * ONE_TOKEN)
+        .await
+        .unwrap();
+    assert_eq!(h.position(position).await.amount, 700 * ONE_TOKEN);
+    assert_eq!(h.position(split).await.amount, 300 * ONE_TOKEN);
+    assert_eq!(h.user_account(&user.pubkey()).await.position_count, 2);
+
+    h.merge_positions(&user, 0, position, split).await.unwrap();
+    assert_eq!(h.position(position).await.amount, amount);
+    assert!(!h.exists(split).await);
+    assert_eq!(h.user_account(&user.pubkey()).await.position_count, 1);
+
+    let moved = h
+        .transfer_position(&user, &recipient, 0, position)
+        .await
+        .unwrap();
+    assert!(!h.exists(position).await);
+    assert_eq!(h.position(moved).await.owner, recipient.pubkey());
+    assert_eq!(h.user_pool_stats(&user.pubkey(), 0).await.tokens_staked, 0);
+    assert_eq!(
+        h.user_pool_stats(&recipient.pubkey(), 0)
+            .await
+            .tokens_staked,
+        amount
+    );
+
+    h.warp_days(LOCK_DAYS as i64).await;
+    h.claim_token(&recipient, 0, moved).await.unwrap();
+}
+
+#[tokio::test]
+async fn merging_keeps_the_later_unlock() {
+    let mut h = Harness::ready().await;
+    h.make_flexible(0, 7, 30, 20_000).await;
+
+    let user = h.new_user().await;
+    let unlocked = h
+        .stake_token_with(&user, 0, 1_000 * ONE_TOKEN, Some(7), None)
+        .await
+        .unwrap();
+    h.warp_days(7).await;
+
+    // A fresh 30 day lock folded into a large unlocked position stays locked
+    let locked = h
+        .stake_token_with(&user, 0, ONE_TOKEN, Some(30), None)
+        .await
+        .unwrap();
+    let unlock_time = h.position(locked).await.unlock_time;
+
+    h.merge_positions(&user, 0, unlocked, locked).await.unwrap();
+    assert_eq!(h.position(unlocked).await.unlock_time, unlock_time);
+
+    // An amount-weighted unlock would have freed both within the hour
+    h.warp_days(1).await;
+    let result = h.claim_token(&user, 0, unlocked).await;
+    assert_staking_error(result, StakingError::PositionLocked);
+
+    h.warp_days(29).await;
+    h.claim_token(&user, 0, unlocked).await.unwrap();
+}
+
+#[tokio::test]
+async fn admin_withdraws_yield_funds() {
+    let mut h = Harness::ready().await;
+    let admin = h.admin.pubkey();
+    h.mint_tokens(&admin, 0).await;
+
+    h.admin_withdraw(YIELD_FUNDS / 4).await.unwrap();
+
+    let ata = h.ata(&admin);
+    assert_eq!(h.token_balance(ata).await, YIELD_FUNDS / 4);
+    let authority_vault = h.authority_vault();
+    assert_eq!(
+        h.token_balance(authority_vault).await,
+        YIELD_FUNDS - YIELD_FUNDS / 4
+    );
+}
@@ -0,0 +1,280 @@
+mod common;
+
+use anchor_lang::{AccountSerialize, Space};
+use bert_staking_sc::{
+    state::{
+        Config, LockMode, Pool, PoolType, PositionV4, Versioned, BPS_DENOMINATOR,
+        POSITION_SEED_VERSION_LEGACY,
+    },
+    StakingError,
+};
+use common::*;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+/// Bytes pool version 1 added to the end of the account
+const POOL_V1_GROWTH: usize = 72;
+
+/// Offset of the config's version byte, fixed since configs were versioned
+const CONFIG_VERSION_OFFSET: usize = 321;
+
+/// Rewrites pool `index` as the original program stored it, without the
+/// fields version 1 added
+async fn downgrade_pool(h: &mut Harness, index: u32) -> Pubkey {
+    let address = h.pool(index);
+    let mut pool = h.pool_account(index).await;
+    pool.lock_mode = LockMode::Fixed;
+    pool.min_lock_days = 0;
+    pool.max_lock_days = 0;
+    pool.max_boost_bps = 0;
+    pool.nft_boost_bps_per_nft = 0;
+    pool.max_nft_boost_bps = 0;
+    pool.pool_type = PoolType::Standard;
+    pool.unbonding_period_days = 0;
+    pool.merkle_root = [0; 32];
+    pool.min_stake_amount = 0;
+    pool.max_stake_per_position = 0;
+    pool.version = 0;
+
+    let mut data = Vec::new();
+    pool.try_serialize(&mut data).unwrap();
+    data.truncate(data.len() - POOL_V1_GROWTH);
+    h.set_account_data(address, data).await;
+    address
+}
+
+/// Copies a token position to the legacy seeds, as the program stored it
+/// before positions were derived from their pool and counted
+async fn downgrade_position_seeds(h: &mut Harness, user: &Keypair, position: Pubkey) -> Pubkey {
+    let owner = user.pubkey();
+    let mut legacy = h.position(position).await;
+    let (address, bump) = Pubkey::find_program_address(
+        &[
+            b"position",
+            owner.as_ref(),
+            h.mint.as_ref(),
+            &legacy.id.to_le_bytes(),
+        ],
+        &bert_staking_sc::ID,
+    );
+    legacy.seed_version = POSITION_SEED_VERSION_LEGACY;
+    legacy.bump = bump;
+    h.write_account(address, &legacy).await;
+
+    let user_account = h.keys.user_account(&owner);
+    let mut counts = h.user_account(&owner).await;
+    counts.position_count -= 1;
+    h.write_account(user_account, &counts).await;
+    address
+}
+
+#[tokio::test]
+async fn creates_accounts_on_the_current_version() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let position = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+
+    assert!(h.config_account().await.is_current());
+    assert!(h.pool_account(0).await.is_current());
+    assert!(h.user_account(&user.pubkey()).await.is_current());
+    assert!(h.position(position).await.is_current());
+}
+
+#[tokio::test]
+async fn migrates_original_pool_layout() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let asset = h.mint_asset(&user.pubkey()).await;
+    h.stake_nft(&user, 0, asset).await.unwrap();
+    let pool = downgrade_pool(&mut h, 0).await;
+
+    // The original layout is too short to even deserialize
+    let result = h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await;
+    assert!(result.is_err());
+
+    h.migrate_pool(0).await.unwrap();
+
+    let account = h.ctx.banks_client.get_account(pool).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), 8 + Pool::INIT_SPACE);
+
+    let migrated = h.pool_account(0).await;
+    assert_eq!(migrated.version, Pool::VERSION);
+    assert_eq!(migrated.lock_mode, LockMode::Fixed);
+    assert_eq!(migrated.lock_period_days, LOCK_DAYS);
+    assert_eq!(migrated.min_lock_days, LOCK_DAYS);
+    assert_eq!(migrated.max_lock_days, LOCK_DAYS);
+    assert_eq!(migrated.max_boost_bps, BPS_DENOMINATOR);
+    assert_eq!(migrated.yield_rate, YIELD_RATE);
+
+    // NFTs staked before the registry count at the config's NFT value
+    let nft_value = h.config_account().await.nft_value_in_tokens;
+    assert_eq!(migrated.total_nfts_staked, 1);
+    assert_eq!(migrated.total_nft_value, nft_value);
+
+    h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+}
+
+#[tokio::test]
+async fn keeps_lock_terms_set_before_versioning() {
+    let mut h = Harness::ready().await;
+    h.make_flexible(0, 7, 30, 20_000).await;
+
+    let mut pool = h.pool_account(0).await;
+    pool.version = 0;
+    let address = h.pool(0);
+    h.write_account(address, &pool).await;
+
+    h.migrate_pool(0).await.unwrap();
+
+    let migrated = h.pool_account(0).await;
+    assert!(migrated.is_current());
+    assert_eq!(migrated.lock_mode, LockMode::Flexible);
+    assert_eq!(migrated.min_lock_days, 7);
+    assert_eq!(migrated.max_lock_days, 30);
+    assert_eq!(migrated.max_boost_bps, 20_000);
+}
+
+#[tokio::test]
+async fn migrates_original_position_layout() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let amount = 1_000 * ONE_TOKEN;
+    let position = h.stake_token(&user, 0, amount).await.unwrap();
+
+    let mut stale = h.position(position).await;
+    stale.lock_period_days = 0;
+    stale.yield_multiplier_bps = 0;
+    stale.version = 0;
+    h.write_account(position, &stale).await;
+
+    h.warp_days(LOCK_DAYS as i64).await;
+    let result = h.claim_token(&user, 0, position).await;
+    assert_staking_error(result, StakingError::StaleAccountVersion);
+
+    h.migrate_position(position).await.unwrap();
+
+    let migrated = h.position(position).await;
+    assert_eq!(migrated.version, PositionV4::VERSION);
+    assert_eq!(migrated.lock_period_days, LOCK_DAYS);
+    assert_eq!(migrated.yield_multiplier_bps, BPS_DENOMINATOR);
+
+    // The migrated position pays the base rate over its lock
+    h.claim_token(&user, 0, position).await.unwrap();
+    let ata = h.ata(&user.pubkey());
+    assert_eq!(
+        h.token_balance(ata).await,
+        USER_TOKENS + amount * YIELD_RATE / 1_000_000_000
+    );
+}
+
+#[tokio::test]
+async fn migrates_config_and_user_account_in_place() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    h.stake_token(&user, 0, 1_000 * ONE_TOKEN).await.unwrap();
+    let user_account = h.keys.user_account(&user.pubkey());
+
+    let mut config = h.config_account().await;
+    config.version = 0;
+    let config_address = h.config;
+    h.write_account(config_address, &config).await;
+
+    let mut stale = h.user_account(&user.pubkey()).await;
+    stale.version = 0;
+    h.write_account(user_account, &stale).await;
+
+    h.migrate_config().await.unwrap();
+    h.migrate_user_account(&user.pubkey()).await.unwrap();
+
+    let migrated = h.config_account().await;
+    assert!(migrated.is_current());
+    assert_eq!(migrated.total_staked_amount, config.total_staked_amount);
+
+    let migrated = h.user_account(&user.pubkey()).await;
+    assert!(migrated.is_current());
+    assert_eq!(migrated.total_staked_value, stale.total_staked_value);
+    assert_eq!(migrated.position_count, stale.position_count);
+}
+
+#[tokio::test]
+async fn keeps_the_config_version_in_place() {
+    let mut h = Harness::ready().await;
+    h.enable_emergency().await.unwrap();
+
+    // Fields added after versioning come out of the padding behind it
+    let config = h.config;
+    let account = h
+        .ctx
+        .banks_client
+        .get_account(config)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data[CONFIG_VERSION_OFFSET], Config::VERSION);
+    assert_eq!(account.data[CONFIG_VERSION_OFFSET + 1], 1);
+}
+
+#[tokio::test]
+async fn refuses_accounts_of_another_type() {
+    let mut h = Harness::ready().await;
+    let config = h.config;
+
+    let result = h.migrate_position(config).await;
+    assert!(result.is_err());
+    assert!(h.config_account().await.is_current());
+}
+
+#[tokio::test]
+async fn counts_legacy_positions_once_migrated() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let owner = user.pubkey();
+    let amount = 1_000 * ONE_TOKEN;
+
+    let position = h.stake_token(&user, 0, amount).await.unwrap();
+    let claimed = downgrade_position_seeds(&mut h, &user, position).await;
+    let position = h.stake_token(&user, 0, amount).await.unwrap();
+    let migrated = downgrade_position_seeds(&mut h, &user, position).await;
+    h.stake_token(&user, 0, amount).await.unwrap();
+    assert_eq!(h.user_account(&owner).await.position_count, 1);
+
+    // Claiming an uncounted legacy position leaves the count alone
+    h.warp_days(LOCK_DAYS as i64).await;
+    h.claim_token(&user, 0, claimed).await.unwrap();
+    assert_eq!(h.user_account(&owner).await.position_count, 1);
+
+    // Migrating counts it, and claiming it afterwards releases it
+    let migrated = h.migrate_position_seeds(&user, 0, migrated).await.unwrap();
+    assert_eq!(h.user_account(&owner).await.position_count, 2);
+
+    h.claim_token(&user, 0, migrated).await.unwrap();
+    assert_eq!(h.user_account(&owner).await.position_count, 1);
+}
+
+#[tokio::test]
+async fn claims_nfts_staked_before_the_registry() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let owner = user.pubkey();
+    let asset = h.mint_asset(&owner).await;
+    let position = h.stake_nft(&user, 0, asset).await.unwrap();
+
+    // The config's own collection was never registered, so the NFT was
+    // never counted in it
+    let mut legacy = h.position(position).await;
+    legacy.collection_counted = false;
+    h.write_account(position, &legacy).await;
+
+    let collection_entry = h.collection_entry();
+    h.remove_account(collection_entry).await;
+    h.remove_account(h.keys.user_collection_stats(&owner, &h.collection))
+        .await;
+
+    h.warp_days(LOCK_DAYS as i64).await;
+    h.claim_nft(&user, 0, position, asset).await.unwrap();
+
+    assert_eq!(h.core_account(asset).await.owner, owner);
+    assert_eq!(h.pool_account(0).await.total_nft_value, 0);
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use common::*;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+#[tokio::test]
+async fn projects_position_yield_until_claim() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let owner = user.pubkey();
+    let amount = 1_000 * ONE_TOKEN;
+    let term_yield = amount * YIELD_RATE / 1_000_000_000;
+
+    let position = h.stake_token(&user, 0, amount).await.unwrap();
+    let staked = h.position(position).await;
+
+    let view = h.view_position_yield(&owner, 0, position).await.unwrap();
+    assert_eq!(view.pending_yield, 0);
+    assert_eq!(view.projected_yield, term_yield);
+    assert_eq!(view.unlock_time, staked.unlock_time);
+    assert!(!view.is_withdrawable);
+
+    h.warp_days(3).await;
+    let view = h.view_position_yield(&owner, 0, position).await.unwrap();
+    assert_eq!(view.pending_yield, term_yield * 3 / LOCK_DAYS as u64);
+    assert!(!view.is_withdrawable);
+
+    h.warp_days(LOCK_DAYS as i64).await;
+    let view = h.view_position_yield(&owner, 0, position).await.unwrap();
+    assert_eq!(view.pending_yield, term_yield);
+    assert!(view.is_withdrawable);
+
+    // The projection matches what the claim pays
+    h.claim_token(&user, 0, position).await.unwrap();
+    let ata = h.ata(&owner);
+    assert_eq!(
+        h.token_balance(ata).await,
+        USER_TOKENS + view.projected_yield
+    );
+}
+
+#[tokio::test]
+async fn reports_remaining_pool_capacity() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let amount = 1_000 * ONE_TOKEN;
+
+    let view = h.view_pool_capacity(0).await.unwrap();
+    assert_eq!(view.total_value, 0);
+    assert_eq!(view.max_value_cap, MAX_VALUE_CAP);
+    assert_eq!(view.remaining_value, MAX_VALUE_CAP);
+
+    h.stake_token(&user, 0, amount).await.unwrap();
+    let asset = h.mint_asset(&user.pubkey()).await;
+    h.stake_nft(&user, 0, asset).await.unwrap();
+
+    let view = h.view_pool_capacity(0).await.unwrap();
+    assert_eq!(view.total_value, amount + NFT_VALUE);
+    assert_eq!(view.remaining_value, MAX_VALUE_CAP - amount - NFT_VALUE);
+}
+
+#[tokio::test]
+async fn reports_remaining_user_capacity() {
+    let mut h = Harness::ready().await;
+
+    // Users who never staked have the full caps
+    let stranger = Pubkey::new_unique();
+    let view = h.view_user_capacity(&stranger, 0, true).await.unwrap();
+    assert_eq!(view.remaining_value, MAX_TOKENS_CAP);
+    assert_eq!(view.remaining_pool_nfts, MAX_NFTS_CAP);
+    assert_eq!(view.remaining_nfts, NFTS_LIMIT_PER_USER as u32);
+    assert_eq!(
+        view.remaining_collection_nfts,
+        Some(NFTS_LIMIT_PER_USER as u32)
+    );
+
+    let user = h.new_user().await;
+    let owner = user.pubkey();
+    let amount = 1_000 * ONE_TOKEN;
+    h.stake_token(&user, 0, amount).await.unwrap();
+    let asset = h.mint_asset(&owner).await;
+    h.stake_nft(&user, 0, asset).await.unwrap();
+
+    let view = h.view_user_capacity(&owner, 0, true).await.unwrap();
+    assert_eq!(view.remaining_value, MAX_TOKENS_CAP - amount - NFT_VALUE);
+    assert_eq!(view.remaining_pool_nfts, MAX_NFTS_CAP - 1);
+    assert_eq!(view.remaining_nfts, NFTS_LIMIT_PER_USER as u32 - 1);
+    assert_eq!(
+        view.remaining_collection_nfts,
+        Some(NFTS_LIMIT_PER_USER as u32 - 1)
+    );
+
+    let view = h.view_user_capacity(&owner, 0, false).await.unwrap();
+    assert_eq!(view.remaining_collection_nfts, None);
+}
+
+#[tokio::test]
+async fn user_capacity_is_bounded_by_the_pool() {
+    let mut h = Harness::ready().await;
+    let user = h.new_user().await;
+    let other = h.new_user().await;
+    let amount = 1_000 * ONE_TOKEN;
+
+    h.update_pool(0, |args| args.max_value_cap = 1_500 * ONE_TOKEN)
+        .await;
+    h.stake_token(&other, 0, amount).await.unwrap();
+
+    let view = h
+        .view_user_capacity(&user.pubkey(), 0, false)
+        .await
+        .unwrap();
+    assert_eq!(view.remaining_value, 500 * ONE_TOKEN);
+}
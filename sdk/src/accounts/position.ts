@@ -6,41 +6,24 @@ import { BN } from "@coral-xyz/anchor";
 import { PositionIdl } from "../types";
 
 /**
- * Fetch a position account for a given owner and mint
+ * Fetch a position account for a given owner, pool and id
  */
 export async function fetchPositionRpc(
   owner: PublicKey,
-  mint: PublicKey,
-  id: number | null = null,
-  asset: PublicKey | null = null,
+  pool: PublicKey,
+  id: number,
   program: Program<BertStakingSc>
 ): Promise<PositionIdl | null> {
   try {
-    let positionPda: PublicKey;
-
-    if (asset) {
-      [positionPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("position"),
-          owner.toBuffer(),
-          mint.toBuffer(),
-          asset.toBuffer(),
-          new BN(id || 0).toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
-    } else {
-      // For token positions, use ID-based PDA
-      [positionPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("position"),
-          owner.toBuffer(),
-          mint.toBuffer(),
-          new BN(id || 0).toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
-    }
+    const [positionPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("position"),
+        owner.toBuffer(),
+        pool.toBuffer(),
+        new BN(id).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
 
     // Fetch the account
     const position = await program.account.positionV4.fetchNullable(
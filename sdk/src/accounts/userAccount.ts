@@ -83,3 +83,15 @@ export async function fetchUserAccountByAddressRpc(
     return null;
   }
 }
+
+/**
+ * Fetch the id the program will assign to the owner's next position
+ */
+export async function fetchNextPositionIdRpc(
+  address: PublicKey,
+  program: Program<BertStakingSc>
+): Promise<number> {
+  const userAccount = await program.account.userAccountV3.fetch(address);
+
+  return userAccount.nextPositionId.toNumber();
+}
@@ -93,16 +93,16 @@ export type BertStakingSc = {
       "args": []
     },
     {
-      "name": "adminPausePool",
+      "name": "adminAddCollection",
       "discriminator": [
-        74,
-        116,
-        13,
-        230,
-        101,
-        103,
-        117,
-        68
+        149,
+        8,
+        158,
+        209,
+        5,
+        203,
+        132,
+        128
       ],
       "accounts": [
         {
@@ -115,7 +115,6 @@ export type BertStakingSc = {
         },
         {
           "name": "config",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -143,17 +142,26 @@ export type BertStakingSc = {
           }
         },
         {
-          "name": "pool",
+          "name": "collection"
+        },
+        {
+          "name": "collectionEntry",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  99,
                   111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
                   111,
-                  108
+                  110
                 ]
               },
               {
@@ -162,31 +170,42 @@ export type BertStakingSc = {
               },
               {
                 "kind": "account",
-                "path": "pool.index",
-                "account": "pool"
+                "path": "collection"
               }
             ]
           }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "nftValueInTokens",
+          "type": "u64"
+        },
+        {
+          "name": "nftsLimitPerUser",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "adminSetPoolConfig",
+      "name": "adminDisableEmergency",
       "discriminator": [
-        87,
-        181,
-        217,
-        7,
-        183,
-        23,
-        15,
-        140
+        229,
+        17,
+        74,
+        68,
+        200,
+        109,
+        190,
+        121
       ],
       "accounts": [
         {
           "name": "authority",
-          "writable": true,
           "signer": true,
           "relations": [
             "config"
@@ -220,56 +239,73 @@ export type BertStakingSc = {
               }
             ]
           }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "adminEnableEmergency",
+      "discriminator": [
+        62,
+        244,
+        149,
+        23,
+        63,
+        223,
+        86,
+        126
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
         },
         {
-          "name": "pool",
+          "name": "config",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  111,
+                  99,
                   111,
-                  108
+                  110,
+                  102,
+                  105,
+                  103
                 ]
               },
               {
                 "kind": "account",
-                "path": "config"
+                "path": "config.authority",
+                "account": "config"
               },
               {
                 "kind": "account",
-                "path": "pool.index",
-                "account": "pool"
+                "path": "config.id",
+                "account": "config"
               }
             ]
           }
         }
       ],
-      "args": [
-        {
-          "name": "configParams",
-          "type": {
-            "defined": {
-              "name": "poolConfigArgs"
-            }
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "adminWithdrawTokens",
+      "name": "adminPausePool",
       "discriminator": [
-        214,
-        62,
-        163,
-        202,
-        229,
-        204,
-        126,
-        142
+        74,
+        116,
+        13,
+        230,
+        101,
+        103,
+        117,
+        68
       ],
       "accounts": [
         {
@@ -282,6 +318,7 @@ export type BertStakingSc = {
         },
         {
           "name": "config",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -309,28 +346,17 @@ export type BertStakingSc = {
           }
         },
         {
-          "name": "authorityVault",
+          "name": "pool",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  97,
-                  117,
-                  116,
-                  104,
+                  112,
                   111,
-                  114,
-                  105,
-                  116,
-                  121,
-                  95,
-                  118,
-                  97,
-                  117,
-                  108,
-                  116
+                  111,
+                  108
                 ]
               },
               {
@@ -339,145 +365,92 @@ export type BertStakingSc = {
               },
               {
                 "kind": "account",
-                "path": "config.mint",
-                "account": "config"
+                "path": "pool.index",
+                "account": "pool"
               }
             ]
-          },
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "adminSetCrankTip",
+      "discriminator": [
+        16,
+        2,
+        89,
+        94,
+        233,
+        160,
+        164,
+        202
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
           "relations": [
             "config"
           ]
         },
         {
-          "name": "adminWithdrawDestination",
+          "name": "config",
           "writable": true,
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "config.admin_withdraw_destination",
-                "account": "config"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
-                  95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
               },
               {
                 "kind": "account",
-                "path": "config.mint",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
                 "account": "config"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         }
       ],
       "args": [
         {
-          "name": "amount",
+          "name": "tipLamports",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "claimPositionNft",
+      "name": "adminSetMerkleRoot",
       "discriminator": [
-        199,
-        237,
-        5,
-        82,
-        92,
-        33,
-        149,
-        229
+        32,
+        139,
+        56,
+        134,
+        208,
+        106,
+        221,
+        126
       ],
       "accounts": [
         {
-          "name": "owner",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "payer",
+          "name": "authority",
           "writable": true,
-          "signer": true
+          "signer": true,
+          "relations": [
+            "config"
+          ]
         },
         {
           "name": "config",
@@ -533,227 +506,363 @@ export type BertStakingSc = {
               }
             ]
           }
+        }
+      ],
+      "args": [
+        {
+          "name": "merkleRoot",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "adminSetPoolConfig",
+      "discriminator": [
+        87,
+        181,
+        217,
+        7,
+        183,
+        23,
+        15,
+        140
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
         },
         {
-          "name": "userAccount",
+          "name": "config",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  117,
-                  115,
-                  101,
-                  114
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
               },
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "config.authority",
+                "account": "config"
               },
               {
                 "kind": "account",
-                "path": "config"
+                "path": "config.id",
+                "account": "config"
               }
             ]
           }
         },
         {
-          "name": "userPoolStats",
+          "name": "pool",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  117,
-                  115,
-                  101,
-                  114,
-                  95,
                   112,
                   111,
                   111,
-                  108,
-                  95,
-                  115,
-                  116,
-                  97,
-                  116,
-                  115
+                  108
                 ]
               },
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "config"
               },
               {
                 "kind": "account",
-                "path": "pool"
+                "path": "pool.index",
+                "account": "pool"
               }
             ]
           }
+        }
+      ],
+      "args": [
+        {
+          "name": "configParams",
+          "type": {
+            "defined": {
+              "name": "poolConfigArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "adminSetPositionLimit",
+      "discriminator": [
+        5,
+        168,
+        128,
+        3,
+        3,
+        176,
+        111,
+        146
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
         },
         {
-          "name": "position",
+          "name": "config",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  99,
                   111,
-                  115,
-                  105,
-                  116,
+                  110,
+                  102,
                   105,
-                  111,
-                  110
+                  103
                 ]
               },
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "config.authority",
+                "account": "config"
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "maxPositionsPerUser",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "adminUpdateCollection",
+      "discriminator": [
+        24,
+        76,
+        159,
+        235,
+        152,
+        121,
+        253,
+        111
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
               },
               {
                 "kind": "account",
-                "path": "asset"
+                "path": "config.authority",
+                "account": "config"
               },
               {
                 "kind": "account",
-                "path": "position.id",
-                "account": "positionV4"
+                "path": "config.id",
+                "account": "config"
               }
             ]
-          }
-        },
-        {
-          "name": "collection",
+          },
           "relations": [
-            "config"
+            "collectionEntry"
           ]
         },
         {
-          "name": "updateAuthority"
+          "name": "collectionEntry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "collection_entry.collection",
+                "account": "collectionEntry"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "nftValueInTokens",
+          "type": "u64"
         },
         {
-          "name": "asset",
-          "writable": true
+          "name": "nftsLimitPerUser",
+          "type": "u8"
         },
         {
-          "name": "mint",
-          "docs": [
-            "Token mint."
-          ],
+          "name": "isActive",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "adminWithdrawTokens",
+      "discriminator": [
+        214,
+        62,
+        163,
+        202,
+        229,
+        204,
+        126,
+        142
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
           "relations": [
             "config"
           ]
         },
         {
-          "name": "tokenAccount",
-          "writable": true,
+          "name": "config",
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "owner"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
-                  95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "vault",
+          "name": "authorityVault",
           "writable": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
               {
                 "kind": "account",
                 "path": "config"
               },
+              {
+                "kind": "account",
+                "path": "config.mint",
+                "account": "config"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "adminWithdrawDestination",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config.admin_withdraw_destination",
+                "account": "config"
+              },
               {
                 "kind": "const",
                 "value": [
@@ -793,7 +902,8 @@ export type BertStakingSc = {
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "config.mint",
+                "account": "config"
               }
             ],
             "program": {
@@ -835,46 +945,6 @@ export type BertStakingSc = {
             }
           }
         },
-        {
-          "name": "authorityVault",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121,
-                  95,
-                  118,
-                  97,
-                  117,
-                  108,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "config"
-              },
-              {
-                "kind": "account",
-                "path": "mint"
-              }
-            ]
-          }
-        },
-        {
-          "name": "coreProgram",
-          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
-        },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
@@ -882,29 +952,43 @@ export type BertStakingSc = {
         {
           "name": "associatedTokenProgram",
           "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "claimPositionToken",
+      "name": "claimPositionNft",
       "discriminator": [
-        95,
-        57,
-        238,
-        130,
-        192,
-        73,
-        77,
-        124
+        199,
+        237,
+        5,
+        82,
+        92,
+        33,
+        149,
+        229
       ],
       "accounts": [
+        {
+          "name": "claimant",
+          "docs": [
+            "The position owner or their delegate, or the receipt holder for",
+            "receipt-backed positions"
+          ],
+          "writable": true,
+          "signer": true
+        },
         {
           "name": "owner",
+          "writable": true
+        },
+        {
+          "name": "payer",
           "writable": true,
           "signer": true
         },
@@ -935,7 +1019,10 @@ export type BertStakingSc = {
                 "account": "config"
               }
             ]
-          }
+          },
+          "relations": [
+            "collectionEntry"
+          ]
         },
         {
           "name": "pool",
@@ -1048,7 +1135,12 @@ export type BertStakingSc = {
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "position.seed_key(mint",
+                "account": "positionV4"
+              },
+              {
+                "kind": "account",
+                "path": "position"
               },
               {
                 "kind": "account",
@@ -1060,109 +1152,115 @@ export type BertStakingSc = {
         },
         {
           "name": "collection",
-          "relations": [
-            "config"
-          ]
-        },
-        {
-          "name": "mint",
           "docs": [
-            "Token mint."
+            "collection without one, and the asset"
           ],
           "relations": [
-            "config"
+            "collectionEntry"
           ]
         },
         {
-          "name": "tokenAccount",
+          "name": "collectionEntry",
+          "docs": [
+            "Registry entry of the collection, required unless the NFT was staked",
+            "before collections were registered"
+          ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "config"
               },
+              {
+                "kind": "account",
+                "path": "collection"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userCollectionStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
+                  117,
+                  115,
                   101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
+                  114,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
                 ]
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "collectionEntry"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
+        {
+          "name": "updateAuthority"
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Token mint."
+          ],
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "docs": [
+            "Recipient token account, checked against the claim recipient"
+          ],
+          "writable": true
+        },
         {
           "name": "vault",
           "writable": true,
@@ -1289,6 +1387,23 @@ export type BertStakingSc = {
             ]
           }
         },
+        {
+          "name": "receipt",
+          "docs": [
+            "Receipt asset, required for receipt-backed positions"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "receiptCollection",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "coreProgram",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
@@ -1305,23 +1420,30 @@ export type BertStakingSc = {
       "args": []
     },
     {
-      "name": "initialize",
+      "name": "claimPositionToken",
       "discriminator": [
-        175,
-        175,
-        109,
-        31,
-        13,
-        152,
-        155,
-        237
+        95,
+        57,
+        238,
+        130,
+        192,
+        73,
+        77,
+        124
       ],
       "accounts": [
         {
-          "name": "authority",
+          "name": "claimant",
+          "docs": [
+            "The position owner or their delegate, or the receipt holder for",
+            "receipt-backed positions"
+          ],
           "writable": true,
           "signer": true
         },
+        {
+          "name": "owner"
+        },
         {
           "name": "config",
           "writable": true,
@@ -1340,72 +1462,216 @@ export type BertStakingSc = {
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "config.authority",
+                "account": "config"
               },
               {
-                "kind": "arg",
-                "path": "id"
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
               }
             ]
           }
         },
         {
-          "name": "mint"
-        },
-        {
-          "name": "collection"
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          }
         },
         {
-          "name": "vault",
+          "name": "userAccount",
           "writable": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
               {
                 "kind": "account",
-                "path": "config"
+                "path": "owner"
               },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userPoolStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
+                  117,
+                  115,
                   101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
+                  114,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
                 ]
               },
               {
                 "kind": "account",
-                "path": "mint"
-              }
-            ],
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "position.seed_key(mint",
+                "account": "positionV4"
+              },
+              {
+                "kind": "account",
+                "path": "position"
+              },
+              {
+                "kind": "account",
+                "path": "position.id",
+                "account": "positionV4"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collection",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Token mint."
+          ],
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "docs": [
+            "Recipient token account, checked against the claim recipient"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
             "program": {
               "kind": "const",
               "value": [
@@ -1446,62 +1712,98 @@ export type BertStakingSc = {
           }
         },
         {
-          "name": "nftsVault"
-        },
-        {
-          "name": "adminWithdrawDestination"
+          "name": "authorityVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "receipt",
+          "docs": [
+            "Receipt asset, required for receipt-backed positions"
+          ],
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "tokenProgram"
+          "name": "receiptCollection",
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        }
-      ],
-      "args": [
-        {
-          "name": "id",
-          "type": "u64"
+          "name": "coreProgram",
+          "optional": true,
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
         },
         {
-          "name": "maxCap",
-          "type": "u64"
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
         {
-          "name": "nftValueInTokens",
-          "type": "u64"
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         },
         {
-          "name": "nftsLimitPerUser",
-          "type": "u8"
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "initializeAuthVault",
+      "name": "claimPositionTokenPartial",
       "discriminator": [
-        69,
-        42,
-        152,
-        197,
-        7,
-        14,
-        88,
-        250
+        4,
+        10,
+        248,
+        12,
+        122,
+        129,
+        85,
+        58
       ],
       "accounts": [
         {
-          "name": "authority",
+          "name": "claimant",
+          "docs": [
+            "The position owner or their delegate, or the receipt holder for",
+            "receipt-backed positions"
+          ],
           "writable": true,
-          "signer": true,
-          "relations": [
-            "config"
-          ]
+          "signer": true
+        },
+        {
+          "name": "owner"
         },
         {
           "name": "config",
@@ -1533,34 +1835,17 @@ export type BertStakingSc = {
           }
         },
         {
-          "name": "mint",
-          "relations": [
-            "config"
-          ]
-        },
-        {
-          "name": "authorityVault",
+          "name": "pool",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  97,
-                  117,
-                  116,
-                  104,
+                  112,
                   111,
-                  114,
-                  105,
-                  116,
-                  121,
-                  95,
-                  118,
-                  97,
-                  117,
-                  108,
-                  116
+                  111,
+                  108
                 ]
               },
               {
@@ -1569,114 +1854,5375 @@ export type BertStakingSc = {
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "pool.index",
+                "account": "pool"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "tokenProgram"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "initializePool",
-      "discriminator": [
-        95,
-        180,
-        10,
-        172,
-        84,
-        174,
-        232,
-        40
-      ],
-      "accounts": [
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
         {
-          "name": "authority",
+          "name": "userPoolStats",
           "writable": true,
-          "signer": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "position.seed_key(mint",
+                "account": "positionV4"
+              },
+              {
+                "kind": "account",
+                "path": "position"
+              },
+              {
+                "kind": "account",
+                "path": "position.id",
+                "account": "positionV4"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collection",
           "relations": [
             "config"
           ]
         },
         {
-          "name": "config",
+          "name": "mint",
+          "docs": [
+            "Token mint."
+          ],
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "docs": [
+            "Recipient token account, checked against the claim recipient"
+          ],
           "writable": true
         },
         {
-          "name": "pool",
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "authorityVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "receipt",
+          "docs": [
+            "Receipt asset, required for receipt-backed positions"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "receiptCollection",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "coreProgram",
+          "optional": true,
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "crankClaim",
+      "discriminator": [
+        193,
+        62,
+        163,
+        14,
+        168,
+        236,
+        179,
+        103
+      ],
+      "accounts": [
+        {
+          "name": "cranker",
+          "docs": [
+            "Anyone can settle a matured position and earn the crank tip"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "owner",
+          "docs": [
+            "position's rent refund minus the tip."
+          ],
+          "writable": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userPoolStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Token mint."
+          ],
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "authorityVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "emergencyWithdrawNft",
+      "discriminator": [
+        195,
+        105,
+        213,
+        105,
+        28,
+        89,
+        124,
+        8
+      ],
+      "accounts": [
+        {
+          "name": "claimant",
+          "docs": [
+            "The position owner or their delegate, or the receipt holder for",
+            "receipt-backed positions"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "owner",
+          "writable": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          },
+          "relations": [
+            "collectionEntry"
+          ]
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userPoolStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "position.seed_key(mint",
+                "account": "positionV4"
+              },
+              {
+                "kind": "account",
+                "path": "position"
+              },
+              {
+                "kind": "account",
+                "path": "position.id",
+                "account": "positionV4"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collection",
+          "docs": [
+            "collection without one, and the asset"
+          ],
+          "relations": [
+            "collectionEntry"
+          ]
+        },
+        {
+          "name": "collectionEntry",
+          "docs": [
+            "Registry entry of the collection, required unless the NFT was staked",
+            "before collections were registered"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "collection"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userCollectionStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "collectionEntry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "updateAuthority"
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Token mint."
+          ],
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "docs": [
+            "Recipient token account, checked against the claim recipient"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "authorityVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "receipt",
+          "docs": [
+            "Receipt asset, required for receipt-backed positions"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "receiptCollection",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "coreProgram",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "emergencyWithdrawToken",
+      "discriminator": [
+        223,
+        158,
+        99,
+        222,
+        87,
+        171,
+        16,
+        162
+      ],
+      "accounts": [
+        {
+          "name": "claimant",
+          "docs": [
+            "The position owner or their delegate, or the receipt holder for",
+            "receipt-backed positions"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "owner"
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userPoolStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "position.seed_key(mint",
+                "account": "positionV4"
+              },
+              {
+                "kind": "account",
+                "path": "position"
+              },
+              {
+                "kind": "account",
+                "path": "position.id",
+                "account": "positionV4"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collection",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Token mint."
+          ],
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "docs": [
+            "Recipient token account, checked against the claim recipient"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "authorityVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "receipt",
+          "docs": [
+            "Receipt asset, required for receipt-backed positions"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "receiptCollection",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "coreProgram",
+          "optional": true,
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "extendLock",
+      "discriminator": [
+        68,
+        151,
+        140,
+        144,
+        139,
+        122,
+        118,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "The position owner or their delegate. Settled yield still goes only",
+            "to the owner's token account."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "owner",
+          "docs": [
+            "Receives the rent of a position closed by a pool move."
+          ],
+          "writable": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "docs": [
+            "Pool the position currently belongs to"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetPool",
+          "docs": [
+            "Pool the position moves into. Omit it to relock in the same pool."
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "target_pool.index",
+                "account": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userPoolStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetUserPoolStats",
+          "docs": [
+            "Required with `target_pool`"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "targetPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "writable": true
+        },
+        {
+          "name": "newPosition",
+          "docs": [
+            "The position re-keyed under `target_pool`, required with it. Position",
+            "seeds carry the pool, so a moved position gets the owner's next id",
+            "there and the old account is closed."
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "targetPool"
+              },
+              {
+                "kind": "account",
+                "path": "user_account.next_position_id",
+                "account": "userAccountV3"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Token mint."
+          ],
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "authorityVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "lockPeriodDays",
+          "type": {
+            "option": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initialize",
+      "discriminator": [
+        175,
+        175,
+        109,
+        31,
+        13,
+        152,
+        155,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "address": "DsqQPGmhhySWUFaWDEDVifLGUfe3DwnZ7MnVJcNW5Ykv"
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "arg",
+                "path": "id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "collection"
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "nftsVault"
+        },
+        {
+          "name": "adminWithdrawDestination"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "id",
+          "type": "u64"
+        },
+        {
+          "name": "maxCap",
+          "type": "u64"
+        },
+        {
+          "name": "nftValueInTokens",
+          "type": "u64"
+        },
+        {
+          "name": "nftsLimitPerUser",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "initializeAuthVault",
+      "discriminator": [
+        69,
+        42,
+        152,
+        197,
+        7,
+        14,
+        88,
+        250
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "authorityVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeLiquidPool",
+      "discriminator": [
+        224,
+        57,
+        125,
+        248,
+        170,
+        180,
+        211,
+        81
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "liquidPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "shareMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  104,
+                  97,
+                  114,
+                  101,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reserve",
+          "docs": [
+            "Yield budget of the liquid pool"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  95,
+                  114,
+                  101,
+                  115,
+                  101,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Token mint."
+          ],
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "unbondingPeriodDays",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "initializePool",
+      "discriminator": [
+        95,
+        180,
+        10,
+        172,
+        84,
+        174,
+        232,
+        40
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "arg",
+                "path": "index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lockPeriodDays",
+          "type": "u16"
+        },
+        {
+          "name": "yieldRate",
+          "type": "u64"
+        },
+        {
+          "name": "maxNftsCap",
+          "type": "u32"
+        },
+        {
+          "name": "maxTokensCap",
+          "type": "u64"
+        },
+        {
+          "name": "maxValueCap",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeReceiptCollection",
+      "discriminator": [
+        41,
+        175,
+        80,
+        23,
+        35,
+        4,
+        100,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "receiptCollection",
+          "docs": [
+            "The new receipt collection asset"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "coreProgram",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "initiateUser",
+      "discriminator": [
+        32,
+        210,
+        131,
+        53,
+        204,
+        197,
+        220,
+        19
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "mergePositions",
+      "discriminator": [
+        115,
+        76,
+        72,
+        172,
+        120,
+        248,
+        70,
+        208
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "docs": [
+            "Position that absorbs the other one"
+          ],
+          "writable": true
+        },
+        {
+          "name": "otherPosition",
+          "docs": [
+            "Position merged into `position` and closed"
+          ],
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateConfig",
+      "discriminator": [
+        92,
+        131,
+        58,
+        105,
+        210,
+        154,
+        224,
+        193
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Pays for any space the current layout adds"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "account",
+          "docs": [
+            "its discriminator"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "The account's config, required to migrate pools from the original",
+            "layout. Migrate the config first."
+          ],
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePool",
+      "discriminator": [
+        55,
+        170,
+        171,
+        123,
+        210,
+        69,
+        39,
+        172
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Pays for any space the current layout adds"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "account",
+          "docs": [
+            "its discriminator"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "The account's config, required to migrate pools from the original",
+            "layout. Migrate the config first."
+          ],
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePosition",
+      "discriminator": [
+        15,
+        132,
+        59,
+        50,
+        199,
+        6,
+        251,
+        46
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Pays for any space the current layout adds"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "account",
+          "docs": [
+            "its discriminator"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "The account's config, required to migrate pools from the original",
+            "layout. Migrate the config first."
+          ],
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePositionSeeds",
+      "discriminator": [
+        202,
+        218,
+        11,
+        232,
+        80,
+        192,
+        251,
+        173
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "docs": [
+            "Position derived with the legacy seeds, closed once migrated"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              },
+              {
+                "kind": "account",
+                "path": "position"
+              },
+              {
+                "kind": "account",
+                "path": "position.id",
+                "account": "positionV4"
+              }
+            ]
+          }
+        },
+        {
+          "name": "newPosition",
+          "docs": [
+            "The same position derived as `[position, owner, pool, id]`"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "position.pool",
+                "account": "positionV4"
+              },
+              {
+                "kind": "account",
+                "path": "user_account.next_position_id",
+                "account": "userAccountV3"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Token mint."
+          ],
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateUserAccount",
+      "discriminator": [
+        198,
+        152,
+        90,
+        54,
+        134,
+        206,
+        92,
+        198
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Pays for any space the current layout adds"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "account",
+          "docs": [
+            "its discriminator"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "The account's config, required to migrate pools from the original",
+            "layout. Migrate the config first."
+          ],
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redeem",
+      "discriminator": [
+        184,
+        12,
+        86,
+        149,
+        70,
+        196,
+        97,
+        225
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "ticket"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          },
+          "relations": [
+            "liquidPool",
+            "ticket"
+          ]
+        },
+        {
+          "name": "liquidPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "ticket",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  100,
+                  101,
+                  109,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              },
+              {
+                "kind": "account",
+                "path": "ticket.id",
+                "account": "redemptionTicket"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Token mint."
+          ],
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestRedeem",
+      "discriminator": [
+        105,
+        49,
+        44,
+        38,
+        207,
+        241,
+        33,
+        173
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          },
+          "relations": [
+            "liquidPool"
+          ]
+        },
+        {
+          "name": "liquidPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "shareMint",
+          "writable": true,
+          "relations": [
+            "liquidPool"
+          ]
+        },
+        {
+          "name": "shareAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "ticket",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  100,
+                  101,
+                  109,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              },
+              {
+                "kind": "account",
+                "path": "liquid_pool.next_ticket_id",
+                "account": "liquidPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Token mint."
+          ],
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "reserve",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  95,
+                  114,
+                  101,
+                  115,
+                  101,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "shares",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "requestUnstake",
+      "discriminator": [
+        44,
+        154,
+        110,
+        253,
+        160,
+        202,
+        54,
+        34
+      ],
+      "accounts": [
+        {
+          "name": "claimant",
+          "docs": [
+            "The position owner or their delegate, or the receipt holder for",
+            "receipt-backed positions"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "writable": true
+        },
+        {
+          "name": "userAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "position.owner",
+                "account": "positionV4"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "receipt",
+          "docs": [
+            "Receipt asset, required for receipt-backed positions"
+          ],
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setDelegate",
+      "discriminator": [
+        242,
+        30,
+        46,
+        76,
+        108,
+        235,
+        128,
+        181
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "splitPosition",
+      "discriminator": [
+        172,
+        241,
+        221,
+        138,
+        161,
+        29,
+        253,
+        42
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "writable": true
+        },
+        {
+          "name": "newPosition",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              },
+              {
+                "kind": "account",
+                "path": "user_account.next_position_id",
+                "account": "userAccountV3"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "stakeLiquid",
+      "discriminator": [
+        212,
+        161,
+        148,
+        67,
+        2,
+        41,
+        43,
+        254
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          },
+          "relations": [
+            "liquidPool"
+          ]
+        },
+        {
+          "name": "liquidPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "shareMint",
+          "writable": true,
+          "relations": [
+            "liquidPool"
+          ]
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Token mint."
+          ],
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "shareAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "reserve",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  95,
+                  114,
+                  101,
+                  115,
+                  101,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "stakeNft",
+      "discriminator": [
+        38,
+        27,
+        66,
+        46,
+        69,
+        65,
+        151,
+        219
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "asset"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          },
+          "relations": [
+            "collectionEntry"
+          ]
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userPoolStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              },
+              {
+                "kind": "account",
+                "path": "user_account.next_position_id",
+                "account": "userAccountV3"
+              }
+            ]
+          }
+        },
+        {
+          "name": "asset",
+          "writable": true
+        },
+        {
+          "name": "nftVaultOwner"
+        },
+        {
+          "name": "collection",
+          "writable": true,
+          "relations": [
+            "collectionEntry"
+          ]
+        },
+        {
+          "name": "collectionEntry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "collection"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userCollectionStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "collectionEntry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "coreProgram",
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "receipt",
+          "docs": [
+            "Optional receipt asset to mint for this position"
+          ],
+          "writable": true,
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "receiptCollection",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "lockPeriodDays",
+          "type": {
+            "option": "u16"
+          }
+        },
+        {
+          "name": "allowlist",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "allowlistProof"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "stakeToken",
+      "discriminator": [
+        191,
+        127,
+        193,
+        101,
+        37,
+        96,
+        87,
+        211
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userPoolStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              },
+              {
+                "kind": "account",
+                "path": "user_account.next_position_id",
+                "account": "userAccountV3"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vault",
           "writable": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  111,
-                  111,
-                  108
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
                 ]
               },
               {
                 "kind": "account",
-                "path": "config"
-              },
-              {
-                "kind": "arg",
-                "path": "index"
+                "path": "mint"
               }
-            ]
-          }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          },
+          "relations": [
+            "config"
+          ]
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
+          "name": "receipt",
+          "docs": [
+            "Optional receipt asset to mint for this position"
+          ],
+          "writable": true,
+          "signer": true,
+          "optional": true
+        },
         {
-          "name": "index",
-          "type": "u32"
+          "name": "receiptCollection",
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "lockPeriodDays",
-          "type": "u16"
+          "name": "coreProgram",
+          "optional": true,
+          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
         },
         {
-          "name": "yieldRate",
-          "type": "u64"
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
         {
-          "name": "maxNftsCap",
-          "type": "u32"
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         },
         {
-          "name": "maxTokensCap",
-          "type": "u64"
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
-          "name": "maxValueCap",
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
           "type": "u64"
+        },
+        {
+          "name": "lockPeriodDays",
+          "type": {
+            "option": "u16"
+          }
+        },
+        {
+          "name": "allowlist",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "allowlistProof"
+              }
+            }
+          }
         }
       ]
     },
     {
-      "name": "initiateUser",
+      "name": "transferPosition",
       "discriminator": [
-        32,
-        210,
-        131,
-        53,
-        204,
-        197,
-        220,
-        19
+        139,
+        130,
+        102,
+        147,
+        135,
+        77,
+        113,
+        222
       ],
       "accounts": [
         {
@@ -1684,6 +7230,14 @@ export type BertStakingSc = {
           "writable": true,
           "signer": true
         },
+        {
+          "name": "newOwner",
+          "docs": [
+            "The receiving wallet co-signs, since the position counts against its limits.",
+            "It must already have a user account."
+          ],
+          "signer": true
+        },
         {
           "name": "config",
           "pda": {
@@ -1710,7 +7264,10 @@ export type BertStakingSc = {
                 "account": "config"
               }
             ]
-          }
+          },
+          "relations": [
+            "collectionEntry"
+          ]
         },
         {
           "name": "pool",
@@ -1727,18 +7284,104 @@ export type BertStakingSc = {
               },
               {
                 "kind": "account",
-                "path": "config"
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "newUserAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "newOwner"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userPoolStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
               },
               {
                 "kind": "account",
-                "path": "pool.index",
-                "account": "pool"
+                "path": "pool"
               }
             ]
           }
         },
         {
-          "name": "userAccount",
+          "name": "newUserPoolStats",
           "writable": true,
           "pda": {
             "seeds": [
@@ -1748,95 +7391,102 @@ export type BertStakingSc = {
                   117,
                   115,
                   101,
-                  114
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
                 ]
               },
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "newOwner"
               },
               {
                 "kind": "account",
-                "path": "config"
+                "path": "pool"
               }
             ]
           }
         },
         {
-          "name": "mint",
-          "relations": [
-            "config"
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "stakeNft",
-      "discriminator": [
-        38,
-        27,
-        66,
-        46,
-        69,
-        65,
-        151,
-        219
-      ],
-      "accounts": [
-        {
-          "name": "owner",
-          "writable": true,
-          "signer": true,
-          "relations": [
-            "asset"
-          ]
+          "name": "position",
+          "writable": true
         },
         {
-          "name": "config",
+          "name": "newPosition",
+          "docs": [
+            "The position re-keyed under the new owner"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
+                  112,
                   111,
-                  110,
-                  102,
+                  115,
                   105,
-                  103
+                  116,
+                  105,
+                  111,
+                  110
                 ]
               },
               {
                 "kind": "account",
-                "path": "config.authority",
-                "account": "config"
+                "path": "newOwner"
               },
               {
                 "kind": "account",
-                "path": "config.id",
-                "account": "config"
+                "path": "pool"
+              },
+              {
+                "kind": "account",
+                "path": "new_user_account.next_position_id",
+                "account": "userAccountV3"
               }
             ]
           }
         },
         {
-          "name": "pool",
+          "name": "asset",
+          "docs": [
+            "Staked asset, required for NFT positions"
+          ],
+          "optional": true
+        },
+        {
+          "name": "collectionEntry",
+          "docs": [
+            "Registry entry of the asset's collection, required for NFT positions",
+            "unless the NFT was staked before collections were registered"
+          ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  99,
                   111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
                   111,
-                  108
+                  110
                 ]
               },
               {
@@ -1845,15 +7495,16 @@ export type BertStakingSc = {
               },
               {
                 "kind": "account",
-                "path": "pool.index",
-                "account": "pool"
+                "path": "collection_entry.collection",
+                "account": "collectionEntry"
               }
             ]
           }
         },
         {
-          "name": "userAccount",
+          "name": "userCollectionStats",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -1862,7 +7513,24 @@ export type BertStakingSc = {
                   117,
                   115,
                   101,
-                  114
+                  114,
+                  95,
+                  99,
+                  111,
+                  108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
                 ]
               },
               {
@@ -1871,14 +7539,15 @@ export type BertStakingSc = {
               },
               {
                 "kind": "account",
-                "path": "config"
+                "path": "collectionEntry"
               }
             ]
           }
         },
         {
-          "name": "userPoolStats",
+          "name": "newUserCollectionStats",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -1889,10 +7558,16 @@ export type BertStakingSc = {
                   101,
                   114,
                   95,
-                  112,
-                  111,
+                  99,
                   111,
                   108,
+                  108,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
                   95,
                   115,
                   116,
@@ -1903,121 +7578,111 @@ export type BertStakingSc = {
               },
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "newOwner"
               },
               {
                 "kind": "account",
-                "path": "pool"
+                "path": "collectionEntry"
               }
             ]
           }
         },
         {
-          "name": "position",
-          "writable": true,
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "viewPoolCapacity",
+      "discriminator": [
+        95,
+        184,
+        0,
+        117,
+        117,
+        213,
+        34,
+        177
+      ],
+      "accounts": [
+        {
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  99,
                   111,
-                  115,
-                  105,
-                  116,
+                  110,
+                  102,
                   105,
-                  111,
-                  110
+                  103
                 ]
               },
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "config.authority",
+                "account": "config"
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
               },
               {
                 "kind": "account",
-                "path": "asset"
+                "path": "config"
               },
               {
-                "kind": "arg",
-                "path": "id"
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
               }
             ]
           }
-        },
-        {
-          "name": "asset",
-          "writable": true
-        },
-        {
-          "name": "nftVaultOwner"
-        },
-        {
-          "name": "collection",
-          "writable": true,
-          "relations": [
-            "config"
-          ]
-        },
-        {
-          "name": "coreProgram",
-          "address": "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
-        },
-        {
-          "name": "mint",
-          "relations": [
-            "config"
-          ]
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "id",
-          "type": "u64"
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "poolCapacityView"
         }
-      ]
+      }
     },
     {
-      "name": "stakeToken",
-      "discriminator": [
-        191,
-        127,
-        193,
-        101,
-        37,
-        96,
-        87,
-        211
-      ],
-      "accounts": [
-        {
-          "name": "owner",
-          "writable": true,
-          "signer": true
-        },
+      "name": "viewPositionYield",
+      "discriminator": [
+        213,
+        160,
+        241,
+        245,
+        83,
+        187,
+        254,
+        156
+      ],
+      "accounts": [
         {
           "name": "config",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -2046,7 +7711,6 @@ export type BertStakingSc = {
         },
         {
           "name": "pool",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -2070,34 +7734,8 @@ export type BertStakingSc = {
             ]
           }
         },
-        {
-          "name": "userAccount",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  117,
-                  115,
-                  101,
-                  114
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "owner"
-              },
-              {
-                "kind": "account",
-                "path": "config"
-              }
-            ]
-          }
-        },
         {
           "name": "userPoolStats",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -2122,7 +7760,8 @@ export type BertStakingSc = {
               },
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "position.owner",
+                "account": "positionV4"
               },
               {
                 "kind": "account",
@@ -2132,251 +7771,168 @@ export type BertStakingSc = {
           }
         },
         {
-          "name": "position",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  112,
-                  111,
-                  115,
-                  105,
-                  116,
-                  105,
-                  111,
-                  110
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "owner"
-              },
-              {
-                "kind": "account",
-                "path": "mint"
-              },
-              {
-                "kind": "arg",
-                "path": "id"
-              }
-            ]
-          }
-        },
-        {
-          "name": "mint"
-        },
+          "name": "position"
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "positionYieldView"
+        }
+      }
+    },
+    {
+      "name": "viewUserCapacity",
+      "discriminator": [
+        55,
+        230,
+        102,
+        75,
+        103,
+        65,
+        240,
+        161
+      ],
+      "accounts": [
         {
-          "name": "tokenAccount",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "owner"
-              },
-              {
-                "kind": "const",
-                "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
-                  95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "mint"
-              }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
-          }
+          "name": "owner"
         },
         {
-          "name": "vault",
-          "writable": true,
+          "name": "config",
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "config"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
-                  95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
               },
               {
                 "kind": "account",
-                "path": "mint"
-              }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+                "path": "config.authority",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "config.id",
+                "account": "config"
+              }
+            ]
           },
           "relations": [
-            "config"
+            "collectionEntry"
           ]
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "pool",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pool.index",
+                "account": "pool"
+              }
+            ]
+          }
         },
         {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+          "name": "userAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "userPoolStats",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "pool"
+              }
+            ]
+          }
         },
         {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "id",
-          "type": "u64"
+          "name": "collectionEntry",
+          "docs": [
+            "Registered collection to report the per-collection limit for"
+          ],
+          "optional": true
         },
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "userCollectionStats",
+          "docs": [
+            "address and read only if they have been created"
+          ],
+          "optional": true
         }
-      ]
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "userCapacityView"
+        }
+      }
     }
   ],
   "accounts": [
@@ -2406,6 +7962,19 @@ export type BertStakingSc = {
         0
       ]
     },
+    {
+      "name": "collectionEntry",
+      "discriminator": [
+        27,
+        142,
+        48,
+        42,
+        147,
+        62,
+        205,
+        3
+      ]
+    },
     {
       "name": "config",
       "discriminator": [
@@ -2419,6 +7988,19 @@ export type BertStakingSc = {
         130
       ]
     },
+    {
+      "name": "liquidPool",
+      "discriminator": [
+        40,
+        115,
+        22,
+        112,
+        167,
+        145,
+        143,
+        156
+      ]
+    },
     {
       "name": "pool",
       "discriminator": [
@@ -2445,6 +8027,19 @@ export type BertStakingSc = {
         36
       ]
     },
+    {
+      "name": "redemptionTicket",
+      "discriminator": [
+        134,
+        239,
+        22,
+        113,
+        25,
+        135,
+        201,
+        241
+      ]
+    },
     {
       "name": "userAccountV3",
       "discriminator": [
@@ -2459,97 +8054,298 @@ export type BertStakingSc = {
       ]
     },
     {
-      "name": "userPoolStatsAccount",
-      "discriminator": [
-        23,
-        136,
-        131,
-        110,
-        30,
-        91,
-        112,
-        74
-      ]
-    }
-  ],
-  "errors": [
+      "name": "userCollectionStats",
+      "discriminator": [
+        41,
+        121,
+        69,
+        130,
+        203,
+        84,
+        98,
+        3
+      ]
+    },
+    {
+      "name": "userPoolStatsAccount",
+      "discriminator": [
+        23,
+        136,
+        131,
+        110,
+        30,
+        91,
+        112,
+        74
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "positionLocked",
+      "msg": "The staking position is still locked"
+    },
+    {
+      "code": 6001,
+      "name": "nftLimitReached",
+      "msg": "NFT limit per user for this pool reached"
+    },
+    {
+      "code": 6002,
+      "name": "globalNftLimitReached",
+      "msg": "Global NFT limit per user reached"
+    },
+    {
+      "code": 6003,
+      "name": "invalidAmount",
+      "msg": "Invalid staking amount"
+    },
+    {
+      "code": 6004,
+      "name": "arithmeticOverflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6005,
+      "name": "invalidPositionType",
+      "msg": "Invalid position type"
+    },
+    {
+      "code": 6006,
+      "name": "userTokensLimitCapReached",
+      "msg": "Tokens limit per user reached"
+    },
+    {
+      "code": 6007,
+      "name": "poolAlreadyPaused",
+      "msg": "Pool paused"
+    },
+    {
+      "code": 6008,
+      "name": "poolAlreadyActive",
+      "msg": "Pool is already active"
+    },
+    {
+      "code": 6009,
+      "name": "invalidPoolPauseState",
+      "msg": "You can only set pool config if the pool is paused"
+    },
+    {
+      "code": 6010,
+      "name": "insufficientYieldFunds",
+      "msg": "Insufficient funds in yield vault for rewards"
+    },
+    {
+      "code": 6011,
+      "name": "authorityVaultAlreadyInitialized",
+      "msg": "Authority vault already initialized"
+    },
+    {
+      "code": 6012,
+      "name": "authorityVaultNotInitialized",
+      "msg": "Authority vault not initialized"
+    },
+    {
+      "code": 6013,
+      "name": "unauthorized",
+      "msg": "Unauthorized Operation"
+    },
+    {
+      "code": 6014,
+      "name": "poolValueLimitReached",
+      "msg": "Pool value limit reached"
+    },
+    {
+      "code": 6015,
+      "name": "invalidLockPeriod",
+      "msg": "Lock period is not allowed in this pool"
+    },
+    {
+      "code": 6016,
+      "name": "invalidBoostCurve",
+      "msg": "Invalid lock boost curve"
+    },
+    {
+      "code": 6017,
+      "name": "lockNotExtended",
+      "msg": "New unlock time must be later than the current one"
+    },
+    {
+      "code": 6018,
+      "name": "sameTargetPool",
+      "msg": "Target pool is the position's own pool, omit it to relock in place"
+    },
+    {
+      "code": 6019,
+      "name": "invalidTransferRecipient",
+      "msg": "Position cannot be transferred to its current owner"
+    },
+    {
+      "code": 6020,
+      "name": "receiptCollectionNotInitialized",
+      "msg": "Receipt collection not initialized"
+    },
+    {
+      "code": 6021,
+      "name": "receiptCollectionAlreadyInitialized",
+      "msg": "Receipt collection already initialized"
+    },
+    {
+      "code": 6022,
+      "name": "invalidReceipt",
+      "msg": "Receipt accounts are missing or invalid"
+    },
+    {
+      "code": 6023,
+      "name": "positionHasReceipt",
+      "msg": "Position is held through a receipt"
+    },
+    {
+      "code": 6024,
+      "name": "invalidPoolType",
+      "msg": "Operation not supported for this pool type"
+    },
+    {
+      "code": 6025,
+      "name": "poolNotEmpty",
+      "msg": "Pool must be empty to change its type"
+    },
+    {
+      "code": 6026,
+      "name": "redemptionLocked",
+      "msg": "Redemption is still unbonding"
+    },
+    {
+      "code": 6027,
+      "name": "invalidShareRatio",
+      "msg": "Liquid pool shares and assets must both be zero or both be nonzero"
+    },
+    {
+      "code": 6028,
+      "name": "cooldownNotStarted",
+      "msg": "Unstake must be requested before withdrawing from this pool"
+    },
+    {
+      "code": 6029,
+      "name": "cooldownActive",
+      "msg": "Unstake cooldown has not elapsed"
+    },
+    {
+      "code": 6030,
+      "name": "cooldownNotRequired",
+      "msg": "This pool has no unstake cooldown"
+    },
     {
-      "code": 6000,
-      "name": "positionLocked",
-      "msg": "The staking position is still locked"
+      "code": 6031,
+      "name": "invalidMerge",
+      "msg": "Positions cannot be merged"
     },
     {
-      "code": 6001,
-      "name": "nftLimitReached",
-      "msg": "NFT limit per user for this pool reached"
+      "code": 6032,
+      "name": "crankTipTooHigh",
+      "msg": "Crank tip exceeds the allowed share of position rent"
     },
     {
-      "code": 6002,
-      "name": "globalNftLimitReached",
-      "msg": "Global NFT limit per user reached"
+      "code": 6033,
+      "name": "notAllowlisted",
+      "msg": "Staker is not on the pool allowlist"
     },
     {
-      "code": 6003,
-      "name": "invalidAmount",
-      "msg": "Invalid staking amount"
+      "code": 6034,
+      "name": "allowlistCapReached",
+      "msg": "Staker allowlist cap reached"
     },
     {
-      "code": 6004,
-      "name": "arithmeticOverflow",
-      "msg": "Arithmetic overflow"
+      "code": 6035,
+      "name": "stakeBelowMinimum",
+      "msg": "Stake amount is below the pool minimum"
     },
     {
-      "code": 6005,
-      "name": "invalidPositionType",
-      "msg": "Invalid position type"
+      "code": 6036,
+      "name": "stakeAboveMaximum",
+      "msg": "Stake amount is above the pool per-position maximum"
     },
     {
-      "code": 6006,
-      "name": "userTokensLimitCapReached",
-      "msg": "Tokens limit per user reached"
+      "code": 6037,
+      "name": "positionLimitReached",
+      "msg": "User has reached the maximum number of open positions"
     },
     {
-      "code": 6007,
-      "name": "poolAlreadyPaused",
-      "msg": "Pool paused"
+      "code": 6038,
+      "name": "positionAlreadyMigrated",
+      "msg": "Position already uses the current seed scheme"
     },
     {
-      "code": 6008,
-      "name": "poolAlreadyActive",
-      "msg": "Pool is already active"
+      "code": 6039,
+      "name": "collectionNotActive",
+      "msg": "Collection is not accepting new stakes"
     },
     {
-      "code": 6009,
-      "name": "invalidPoolPauseState",
-      "msg": "You can only set pool config if the pool is paused"
+      "code": 6040,
+      "name": "collectionNftLimitReached",
+      "msg": "User has reached the NFT limit for this collection"
     },
     {
-      "code": 6010,
-      "name": "insufficientYieldFunds",
-      "msg": "Insufficient funds in yield vault for rewards"
+      "code": 6041,
+      "name": "invalidCollectionStats",
+      "msg": "User collection stats do not belong to the collection entry"
     },
     {
-      "code": 6011,
-      "name": "authorityVaultAlreadyInitialized",
-      "msg": "Authority vault already initialized"
+      "code": 6042,
+      "name": "accountAlreadyMigrated",
+      "msg": "Account is already on the current layout version"
     },
     {
-      "code": 6012,
-      "name": "authorityVaultNotInitialized",
-      "msg": "Authority vault not initialized"
+      "code": 6043,
+      "name": "staleAccountVersion",
+      "msg": "Account is on an older layout version and must be migrated"
     },
     {
-      "code": 6013,
-      "name": "unauthorized",
-      "msg": "Unauthorized Operation"
+      "code": 6044,
+      "name": "emergencyActive",
+      "msg": "Staking is halted while the config is in emergency mode"
     },
     {
-      "code": 6014,
-      "name": "poolValueLimitReached",
-      "msg": "Pool value limit reached"
+      "code": 6045,
+      "name": "emergencyNotActive",
+      "msg": "Config is not in emergency mode"
     }
   ],
   "types": [
+    {
+      "name": "allowlistProof",
+      "docs": [
+        "Proof that a staker is on a pool's allowlist"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cap",
+            "docs": [
+              "Per-user cap on the staked value in this pool, in tokens. Zero means",
+              "only the pool's own caps apply."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "proof",
+            "docs": [
+              "Sibling hashes from the leaf up to the root"
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "baseAssetV1",
       "type": {
@@ -2628,6 +8424,86 @@ export type BertStakingSc = {
         ]
       }
     },
+    {
+      "name": "collectionEntry",
+      "docs": [
+        "A Core collection accepted for NFT staking under a config"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "docs": [
+              "Parent config reference"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "collection",
+            "docs": [
+              "The registered collection"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "nftValueInTokens",
+            "docs": [
+              "Value of each NFT from this collection, in tokens"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "nftsLimitPerUser",
+            "docs": [
+              "Maximum NFTs from this collection per user"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "isActive",
+            "docs": [
+              "Whether new stakes from this collection are accepted. Staked NFTs can",
+              "always be claimed."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "totalNftsStaked",
+            "docs": [
+              "Current NFTs from this collection staked across all pools"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Layout version, see `Versioned`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "docs": [
+              "Padding for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                31
+              ]
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "config",
       "type": {
@@ -2650,59 +8526,207 @@ export type BertStakingSc = {
             "type": "pubkey"
           },
           {
-            "name": "vault",
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "authorityVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "nftsVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "adminWithdrawDestination",
+            "type": "pubkey"
+          },
+          {
+            "name": "poolCount",
+            "type": "u32"
+          },
+          {
+            "name": "maxCap",
+            "type": "u64"
+          },
+          {
+            "name": "nftValueInTokens",
+            "type": "u64"
+          },
+          {
+            "name": "nftsLimitPerUser",
+            "type": "u8"
+          },
+          {
+            "name": "totalStakedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "totalNftsStaked",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "authorityVaultBump",
+            "type": "u8"
+          },
+          {
+            "name": "receiptCollection",
+            "type": "pubkey"
+          },
+          {
+            "name": "crankTipLamports",
+            "type": "u64"
+          },
+          {
+            "name": "maxPositionsPerUser",
+            "type": "u16"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "emergency",
+            "type": "bool"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                52
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "key",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "uninitialized"
+          },
+          {
+            "name": "assetV1"
+          },
+          {
+            "name": "hashedAssetV1"
+          },
+          {
+            "name": "pluginHeaderV1"
+          },
+          {
+            "name": "pluginRegistryV1"
+          },
+          {
+            "name": "collectionV1"
+          }
+        ]
+      }
+    },
+    {
+      "name": "liquidPool",
+      "docs": [
+        "Share accounting for a liquid pool. One per `Pool` of type `Liquid`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "docs": [
+              "The pool this account backs"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "authorityVault",
+            "name": "shareMint",
+            "docs": [
+              "Fungible share mint, with the pool PDA as mint authority"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "nftsVault",
-            "type": "pubkey"
+            "name": "totalAssets",
+            "docs": [
+              "Underlying tokens backing the outstanding shares, including accrued yield"
+            ],
+            "type": "u64"
           },
           {
-            "name": "adminWithdrawDestination",
-            "type": "pubkey"
+            "name": "pendingRedemptions",
+            "docs": [
+              "Underlying tokens owed to redemption tickets that are still unbonding"
+            ],
+            "type": "u64"
           },
           {
-            "name": "poolCount",
-            "type": "u32"
+            "name": "lastAccrualAt",
+            "docs": [
+              "Last time yield was accrued into `total_assets`"
+            ],
+            "type": "i64"
           },
           {
-            "name": "maxCap",
-            "type": "u64"
+            "name": "reserve",
+            "docs": [
+              "Token account holding this pool's yield budget. It is funded with a",
+              "plain token transfer, like the authority vault, and is the only source",
+              "of liquid yield, so liquid accrual never draws on standard positions' yield."
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "nftValueInTokens",
+            "name": "nextTicketId",
+            "docs": [
+              "Id of the next redemption ticket"
+            ],
             "type": "u64"
           },
           {
-            "name": "nftsLimitPerUser",
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
             "type": "u8"
           },
           {
-            "name": "totalStakedAmount",
-            "type": "u64"
-          },
-          {
-            "name": "totalNftsStaked",
-            "type": "u64"
+            "name": "shareMintBump",
+            "docs": [
+              "Share mint PDA bump"
+            ],
+            "type": "u8"
           },
           {
-            "name": "bump",
+            "name": "reserveBump",
+            "docs": [
+              "Reserve PDA bump"
+            ],
             "type": "u8"
           },
           {
-            "name": "authorityVaultBump",
+            "name": "version",
+            "docs": [
+              "Layout version, see `Versioned`"
+            ],
             "type": "u8"
           },
           {
             "name": "padding",
+            "docs": [
+              "Padding for future extensions"
+            ],
             "type": {
               "array": [
                 "u8",
-                96
+                62
               ]
             }
           }
@@ -2710,27 +8734,15 @@ export type BertStakingSc = {
       }
     },
     {
-      "name": "key",
+      "name": "lockMode",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "uninitialized"
-          },
-          {
-            "name": "assetV1"
-          },
-          {
-            "name": "hashedAssetV1"
-          },
-          {
-            "name": "pluginHeaderV1"
-          },
-          {
-            "name": "pluginRegistryV1"
+            "name": "fixed"
           },
           {
-            "name": "collectionV1"
+            "name": "flexible"
           }
         ]
       }
@@ -2764,7 +8776,7 @@ export type BertStakingSc = {
           {
             "name": "yieldRate",
             "docs": [
-              "Yield rate in basis points (e.g., 500 = 5%)"
+              "Yield per lock term, scaled by `SCALING_FACTOR` (50_000_000 = 5%)"
             ],
             "type": "u64"
           },
@@ -2838,6 +8850,115 @@ export type BertStakingSc = {
             ],
             "type": "u8"
           },
+          {
+            "name": "lockMode",
+            "docs": [
+              "Whether the lock period is fixed or chosen by the staker"
+            ],
+            "type": {
+              "defined": {
+                "name": "lockMode"
+              }
+            }
+          },
+          {
+            "name": "minLockDays",
+            "docs": [
+              "Shortest lock a staker can choose in a flexible pool"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxLockDays",
+            "docs": [
+              "Longest lock a staker can choose in a flexible pool"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxBoostBps",
+            "docs": [
+              "Yield multiplier in bps applied to a `max_lock_days` lock (10_000 = 1x).",
+              "The multiplier grows linearly from 1x at `min_lock_days`."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "nftBoostBpsPerNft",
+            "docs": [
+              "Extra token yield in bps for each NFT the user has staked in this pool"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxNftBoostBps",
+            "docs": [
+              "Cap on the extra token yield from staked NFTs, in bps"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "poolType",
+            "docs": [
+              "Whether stakes create positions or mint liquid shares"
+            ],
+            "type": {
+              "defined": {
+                "name": "poolType"
+              }
+            }
+          },
+          {
+            "name": "unbondingPeriodDays",
+            "docs": [
+              "Delay between an unstake or redemption request and the release of",
+              "funds, in days. Zero disables the cooldown for positions."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "merkleRoot",
+            "docs": [
+              "Root of the staker allowlist, as keccak256 leaves of `owner || cap`.",
+              "All zeroes leaves the pool open to everyone. Liquid pools are always",
+              "open, since their shares change hands freely."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "minStakeAmount",
+            "docs": [
+              "Smallest amount a token position can hold"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxStakePerPosition",
+            "docs": [
+              "Largest amount a token position can hold. Zero means no limit."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalNftValue",
+            "docs": [
+              "Value of the NFTs staked in the pool, each at its collection's value",
+              "when it was staked"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Layout version, see `Versioned`"
+            ],
+            "type": "u8"
+          },
           {
             "name": "padding",
             "docs": [
@@ -2846,7 +8967,7 @@ export type BertStakingSc = {
             "type": {
               "array": [
                 "u8",
-                56
+                55
               ]
             }
           }
@@ -2854,29 +8975,158 @@ export type BertStakingSc = {
       }
     },
     {
-      "name": "poolConfigArgs",
+      "name": "poolCapacityView",
+      "docs": [
+        "Room left under a pool's value cap, returned by `view_pool_capacity`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalValue",
+            "docs": [
+              "Staked value in the pool (tokens + staked NFT values)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxValueCap",
+            "docs": [
+              "The pool's `max_value_cap`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "remainingValue",
+            "docs": [
+              "Value that can still be staked before the cap is reached"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "poolConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lockPeriodDays",
+            "docs": [
+              "The lock period in days"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "yieldRate",
+            "docs": [
+              "Yield per lock term, scaled by `SCALING_FACTOR` (50_000_000 = 5%)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxNftsCap",
+            "docs": [
+              "Maximum amount of NFTs that can be staked / user"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "maxTokensCap",
+            "docs": [
+              "Maximum amount of tokens that can be staked / user"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxValueCap",
+            "docs": [
+              "Maximum combined value (tokens + nfts * nft_value) in the pool"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lockMode",
+            "docs": [
+              "Fixed lock period or staker-chosen lock period"
+            ],
+            "type": {
+              "defined": {
+                "name": "lockMode"
+              }
+            }
+          },
+          {
+            "name": "minLockDays",
+            "docs": [
+              "Shortest lock a staker can choose (flexible pools)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxLockDays",
+            "docs": [
+              "Longest lock a staker can choose (flexible pools)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxBoostBps",
+            "docs": [
+              "Yield multiplier at `max_lock_days`, in bps (10_000 = 1x)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "nftBoostBpsPerNft",
+            "docs": [
+              "Extra token yield per staked NFT, in bps"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxNftBoostBps",
+            "docs": [
+              "Cap on the NFT holding boost, in bps"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "minStakeAmount",
+            "docs": [
+              "Minimum amount per token position"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxStakePerPosition",
+            "docs": [
+              "Maximum amount per token position, zero for no limit"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unbondingPeriodDays",
+            "docs": [
+              "Unstake cooldown in days, zero to release funds at unlock. Liquid",
+              "pools keep the redemption period they were created with."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "poolType",
       "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "lockPeriodDays",
-            "type": "u16"
-          },
-          {
-            "name": "yieldRate",
-            "type": "u64"
-          },
-          {
-            "name": "maxNftsCap",
-            "type": "u32"
-          },
+        "kind": "enum",
+        "variants": [
           {
-            "name": "maxTokensCap",
-            "type": "u64"
+            "name": "standard"
           },
           {
-            "name": "maxValueCap",
-            "type": "u64"
+            "name": "liquid"
           }
         ]
       }
@@ -2891,6 +9141,9 @@ export type BertStakingSc = {
           },
           {
             "name": "claimed"
+          },
+          {
+            "name": "unbonding"
           }
         ]
       }
@@ -2999,6 +9252,167 @@ export type BertStakingSc = {
             ],
             "type": "i64"
           },
+          {
+            "name": "lockPeriodDays",
+            "docs": [
+              "Lock period chosen for this position, in days"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "yieldMultiplierBps",
+            "docs": [
+              "Yield multiplier in bps from the pool's boost curve (10_000 = 1x)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "receipt",
+            "docs": [
+              "Receipt asset that carries the claim rights, if one was minted"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "cooldownEndsAt",
+            "docs": [
+              "Time when the unstake cooldown ends - only set while unbonding"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "seedVersion",
+            "docs": [
+              "PDA seed scheme this position was derived with"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "collectionCounted",
+            "docs": [
+              "Whether the NFT is counted in its collection's registry entry. NFTs",
+              "staked before collections were registered are not."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Layout version, see `Versioned`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "docs": [
+              "Padding for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                15
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "positionYieldView",
+      "docs": [
+        "Yield projection for a position, returned by `view_position_yield`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pendingYield",
+            "docs": [
+              "Yield accrued since deposit, as `extend_lock` would settle it now"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "projectedYield",
+            "docs": [
+              "Yield a claim pays once the position unlocks"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unlockTime",
+            "docs": [
+              "Time when the position can be unlocked"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "isWithdrawable",
+            "docs": [
+              "Whether the principal can be claimed now, including any cooldown"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "redemptionTicket",
+      "docs": [
+        "A pending redemption of liquid pool shares, released after unbonding"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the redeemed shares"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pool",
+            "docs": [
+              "Pool the shares were redeemed from"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Underlying tokens owed, fixed at request time"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unlockTime",
+            "docs": [
+              "Time when the tokens can be withdrawn"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "id",
+            "docs": [
+              "Ticket identifier"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Layout version, see `Versioned`"
+            ],
+            "type": "u8"
+          },
           {
             "name": "padding",
             "docs": [
@@ -3007,7 +9421,7 @@ export type BertStakingSc = {
             "type": {
               "array": [
                 "u8",
-                64
+                31
               ]
             }
           }
@@ -3084,6 +9498,138 @@ export type BertStakingSc = {
             ],
             "type": "u8"
           },
+          {
+            "name": "delegate",
+            "docs": [
+              "Wallet approved to claim and relock on the owner's behalf. Proceeds",
+              "always go to the owner."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "positionCount",
+            "docs": [
+              "Number of open positions"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "nextPositionId",
+            "docs": [
+              "Id assigned to the user's next position"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Layout version, see `Versioned`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "docs": [
+              "Padding for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                19
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "userCapacityView",
+      "docs": [
+        "Room left for one user in a pool, returned by `view_user_capacity`.",
+        "Allowlist caps are not included since they depend on the staker's proof."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "remainingValue",
+            "docs": [
+              "Value the user can still stake, under both the pool's",
+              "`max_tokens_cap` and its remaining `max_value_cap`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "remainingPoolNfts",
+            "docs": [
+              "NFTs the user can still stake in this pool under `max_nfts_cap`"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "remainingNfts",
+            "docs": [
+              "NFTs the user can still stake across pools under the config's",
+              "`nfts_limit_per_user`"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "remainingCollectionNfts",
+            "docs": [
+              "NFTs the user can still stake from the requested collection under its",
+              "`nfts_limit_per_user`"
+            ],
+            "type": {
+              "option": "u32"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "userCollectionStats",
+      "docs": [
+        "A separate PDA for each user's NFT count in a registered collection"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "docs": [
+              "The user these stats belong to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "collectionEntry",
+            "docs": [
+              "The collection entry these stats are for"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "nftsStaked",
+            "docs": [
+              "NFTs from the collection currently staked by the user"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Layout version, see `Versioned`"
+            ],
+            "type": "u8"
+          },
           {
             "name": "padding",
             "docs": [
@@ -3092,7 +9638,7 @@ export type BertStakingSc = {
             "type": {
               "array": [
                 "u8",
-                64
+                15
               ]
             }
           }
@@ -3156,6 +9702,13 @@ export type BertStakingSc = {
             ],
             "type": "u8"
           },
+          {
+            "name": "version",
+            "docs": [
+              "Layout version, see `Versioned`"
+            ],
+            "type": "u8"
+          },
           {
             "name": "padding",
             "docs": [
@@ -3164,7 +9717,7 @@ export type BertStakingSc = {
             "type": {
               "array": [
                 "u8",
-                64
+                63
               ]
             }
           }
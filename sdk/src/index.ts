@@ -25,7 +25,58 @@ import {
   adminSetPoolConfigInstruction,
   adminPausePoolInstruction,
   adminActivatePoolInstruction,
+  adminSetMerkleRootInstruction,
   adminWithdrawTokenInstruction,
+  extendLockInstruction,
+  ExtendLockParams,
+  transferPositionInstruction,
+  TransferPositionParams,
+  initializeReceiptCollectionInstruction,
+  InitializeReceiptCollectionParams,
+  initializeLiquidPoolInstruction,
+  InitializeLiquidPoolParams,
+  stakeLiquidInstruction,
+  StakeLiquidParams,
+  requestRedeemInstruction,
+  RequestRedeemParams,
+  redeemInstruction,
+  RedeemParams,
+  requestUnstakeInstruction,
+  RequestUnstakeParams,
+  splitPositionInstruction,
+  SplitPositionParams,
+  mergePositionsInstruction,
+  MergePositionsParams,
+  setDelegateInstruction,
+  SetDelegateParams,
+  crankClaimInstruction,
+  CrankClaimParams,
+  adminSetCrankTipInstruction,
+  AdminSetCrankTipParams,
+  adminSetPositionLimitInstruction,
+  AdminSetPositionLimitParams,
+  migratePositionSeedsInstruction,
+  MigratePositionSeedsParams,
+  migrateConfigInstruction,
+  MigrateConfigParams,
+  migratePoolInstruction,
+  MigratePoolParams,
+  migratePositionInstruction,
+  MigratePositionParams,
+  migrateUserAccountInstruction,
+  MigrateUserAccountParams,
+  adminSetEmergencyInstruction,
+  AdminSetEmergencyParams,
+  adminAddCollectionInstruction,
+  AdminAddCollectionParams,
+  adminUpdateCollectionInstruction,
+  AdminUpdateCollectionParams,
+  viewPositionYield,
+  ViewPositionYieldParams,
+  viewPoolCapacity,
+  ViewPoolCapacityParams,
+  viewUserCapacity,
+  ViewUserCapacityParams,
 } from "./instructions";
 
 // Import account functions
@@ -37,6 +88,7 @@ import {
   fetchPositionsByOwnerRpc,
   fetchUserAccountRpc,
   fetchUserAccountByAddressRpc,
+  fetchNextPositionIdRpc,
   fetchPoolRpc,
   fetchPoolByAddressRpc,
   fetchPoolsByConfigRpc,
@@ -44,7 +96,7 @@ import {
   fetchUserPoolStatsByAddressRpc,
   fetchUserPoolStatsByUserRpc,
 } from "./accounts";
-import { PoolConfigArgs, PositionType } from "./types";
+import { AllowlistProof, PoolConfigArgs, PositionType } from "./types";
 import { getStandardLockPeriodDays, PoolConfigParams } from "./utils";
 
 // Export types
@@ -464,6 +516,10 @@ export class BertStakingSDK {
     authority,
     configId,
     positionId,
+    lockPeriodDays,
+    receipt,
+    receiptCollection,
+    allowlist,
     poolIndex,
     mint,
     collection,
@@ -474,6 +530,10 @@ export class BertStakingSDK {
     authority: PublicKey;
     configId?: number;
     positionId?: number;
+    lockPeriodDays?: number;
+    receipt?: PublicKey;
+    receiptCollection?: PublicKey;
+    allowlist?: AllowlistProof;
     poolIndex: number;
     mint: PublicKey;
     collection: PublicKey;
@@ -486,6 +546,10 @@ export class BertStakingSDK {
       owner,
       configId,
       positionId,
+      lockPeriodDays,
+      receipt,
+      receiptCollection,
+      allowlist,
       poolIndex,
       authority,
       mint,
@@ -503,6 +567,10 @@ export class BertStakingSDK {
     authority,
     configId,
     positionId,
+    lockPeriodDays,
+    receipt,
+    receiptCollection,
+    allowlist,
     poolIndex,
     mint,
     collection,
@@ -513,6 +581,10 @@ export class BertStakingSDK {
     authority: PublicKey;
     configId?: number;
     positionId?: number;
+    lockPeriodDays?: number;
+    receipt?: PublicKey;
+    receiptCollection?: PublicKey;
+    allowlist?: AllowlistProof;
     poolIndex: number;
     mint: PublicKey;
     collection: PublicKey;
@@ -526,6 +598,10 @@ export class BertStakingSDK {
       authority,
       configId,
       positionId,
+      lockPeriodDays,
+      receipt,
+      receiptCollection,
+      allowlist,
       poolIndex,
       mint,
       collection,
@@ -554,6 +630,10 @@ export class BertStakingSDK {
     authority,
     configId,
     positionId,
+    lockPeriodDays,
+    receipt,
+    receiptCollection,
+    allowlist,
     owner,
     tokenMint,
     amount,
@@ -565,6 +645,10 @@ export class BertStakingSDK {
     authority: PublicKey;
     configId?: number;
     positionId?: number;
+    lockPeriodDays?: number;
+    receipt?: PublicKey;
+    receiptCollection?: PublicKey;
+    allowlist?: AllowlistProof;
     tokenMint: PublicKey;
     amount: number | BN;
     poolIndex: number;
@@ -577,6 +661,10 @@ export class BertStakingSDK {
       owner,
       configId,
       positionId,
+      lockPeriodDays,
+      receipt,
+      receiptCollection,
+      allowlist,
       authority,
       tokenMint,
       amount,
@@ -594,6 +682,10 @@ export class BertStakingSDK {
     owner,
     configId,
     positionId,
+    lockPeriodDays,
+    receipt,
+    receiptCollection,
+    allowlist,
     tokenMint,
     amount,
     poolIndex,
@@ -605,6 +697,10 @@ export class BertStakingSDK {
     tokenMint: PublicKey;
     configId?: number;
     positionId?: number;
+    lockPeriodDays?: number;
+    receipt?: PublicKey;
+    receiptCollection?: PublicKey;
+    allowlist?: AllowlistProof;
     amount: number | BN;
     poolIndex: number;
     tokenAccount?: PublicKey;
@@ -618,6 +714,10 @@ export class BertStakingSDK {
       tokenMint,
       configId,
       positionId,
+      lockPeriodDays,
+      receipt,
+      receiptCollection,
+      allowlist,
       amount,
       poolIndex,
       tokenAccount,
@@ -644,6 +744,7 @@ export class BertStakingSDK {
   async claimTokenPosition({
     authority = this.provider.publicKey,
     owner,
+    claimant,
     positionPda,
     configId,
     tokenMint,
@@ -652,9 +753,13 @@ export class BertStakingSDK {
     vault,
     positionId = 0,
     poolIndex,
+    receipt,
+    receiptCollection,
+    amount,
   }: {
     authority?: PublicKey;
     owner: PublicKey;
+    claimant?: PublicKey;
     configId?: number;
     positionPda?: PublicKey;
     tokenMint: PublicKey;
@@ -663,12 +768,16 @@ export class BertStakingSDK {
     vault?: PublicKey;
     positionId?: number;
     poolIndex: number;
+    receipt?: PublicKey;
+    receiptCollection?: PublicKey;
+    amount?: number | BN;
   }): Promise<TransactionInstruction> {
     return claimTokenPositionInstruction({
       program: this.program,
       sdk: this,
       authority,
       owner,
+      claimant,
       positionPda,
       tokenMint,
       tokenAccount,
@@ -677,6 +786,9 @@ export class BertStakingSDK {
       configId,
       positionId,
       poolIndex,
+      receipt,
+      receiptCollection,
+      amount,
     });
   }
 
@@ -686,6 +798,7 @@ export class BertStakingSDK {
   async claimTokenPositionRpc({
     authority = this.provider.publicKey,
     owner,
+    claimant,
     positionPda,
     configId = 0,
     tokenMint,
@@ -694,9 +807,13 @@ export class BertStakingSDK {
     vault,
     positionId = 0,
     poolIndex,
+    receipt,
+    receiptCollection,
+    amount,
   }: {
     authority?: PublicKey;
     owner: PublicKey;
+    claimant?: PublicKey;
     configId?: number;
     positionPda?: PublicKey;
     tokenMint: PublicKey;
@@ -705,12 +822,16 @@ export class BertStakingSDK {
     vault?: PublicKey;
     positionId?: number;
     poolIndex: number;
+    receipt?: PublicKey;
+    receiptCollection?: PublicKey;
+    amount?: number | BN;
   }): Promise<string> {
     const ix = await claimTokenPositionInstruction({
       program: this.program,
       sdk: this,
       authority,
       owner,
+      claimant,
       positionPda,
       tokenMint,
       tokenAccount,
@@ -719,6 +840,9 @@ export class BertStakingSDK {
       configId,
       positionId,
       poolIndex,
+      receipt,
+      receiptCollection,
+      amount,
     });
 
     const tx = new Transaction();
@@ -741,6 +865,7 @@ export class BertStakingSDK {
   async claimNftPosition({
     authority = this.provider.publicKey,
     owner,
+    claimant,
     payer,
     positionPda,
     configId = 0,
@@ -752,9 +877,12 @@ export class BertStakingSDK {
     updateAuthority,
     vault,
     poolIndex,
+    receipt,
+    receiptCollection,
   }: {
     authority?: PublicKey;
     owner: PublicKey;
+    claimant?: PublicKey;
     payer: PublicKey;
     configId?: number;
     positionId?: number;
@@ -766,12 +894,15 @@ export class BertStakingSDK {
     updateAuthority: PublicKey;
     vault?: PublicKey;
     poolIndex: number;
+    receipt?: PublicKey;
+    receiptCollection?: PublicKey;
   }): Promise<TransactionInstruction> {
     return claimNftPositionInstruction({
       program: this.program,
       sdk: this,
       authority,
       owner,
+      claimant,
       payer,
       positionPda,
       positionId,
@@ -783,6 +914,8 @@ export class BertStakingSDK {
       vault,
       configId,
       poolIndex,
+      receipt,
+      receiptCollection,
     });
   }
 
@@ -792,6 +925,7 @@ export class BertStakingSDK {
   async claimNftPositionRpc({
     authority = this.provider.publicKey,
     owner,
+    claimant,
     payer,
     positionPda,
     configId = 0,
@@ -803,9 +937,12 @@ export class BertStakingSDK {
     updateAuthority,
     vault,
     poolIndex,
+    receipt,
+    receiptCollection,
   }: {
     authority?: PublicKey;
     owner: PublicKey;
+    claimant?: PublicKey;
     payer: PublicKey;
     configId?: number;
     positionId?: number;
@@ -817,12 +954,15 @@ export class BertStakingSDK {
     updateAuthority: PublicKey;
     vault?: PublicKey;
     poolIndex: number;
+    receipt?: PublicKey;
+    receiptCollection?: PublicKey;
   }): Promise<string> {
     const ix = await claimNftPositionInstruction({
       program: this.program,
       sdk: this,
       authority,
       owner,
+      claimant,
       payer,
       positionId,
       positionPda,
@@ -834,6 +974,8 @@ export class BertStakingSDK {
       vault,
       configId,
       poolIndex,
+      receipt,
+      receiptCollection,
     });
 
     const tx = new Transaction();
@@ -850,6 +992,512 @@ export class BertStakingSDK {
     return "";
   }
 
+  /**
+   * Creates an instruction to relock a position into a longer lock
+   */
+  async extendLock(
+    params: Omit<ExtendLockParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return extendLockInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to relock a position into a longer lock
+   */
+  async extendLockRpc(
+    params: Omit<ExtendLockParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.extendLock(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to move a position to another wallet
+   */
+  async transferPosition(
+    params: Omit<TransferPositionParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return transferPositionInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to move a position to another wallet
+   */
+  async transferPositionRpc(
+    params: Omit<TransferPositionParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.transferPosition(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to create the position receipt collection
+   */
+  async initializeReceiptCollection(
+    params: Omit<InitializeReceiptCollectionParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return initializeReceiptCollectionInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to create the position receipt collection
+   */
+  async initializeReceiptCollectionRpc(
+    params: Omit<InitializeReceiptCollectionParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.initializeReceiptCollection(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to turn an empty pool into a liquid pool
+   */
+  async initializeLiquidPool(
+    params: Omit<InitializeLiquidPoolParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return initializeLiquidPoolInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to turn an empty pool into a liquid pool
+   */
+  async initializeLiquidPoolRpc(
+    params: Omit<InitializeLiquidPoolParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.initializeLiquidPool(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to deposit tokens into a liquid pool for shares
+   */
+  async stakeLiquid(
+    params: Omit<StakeLiquidParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return stakeLiquidInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to deposit tokens into a liquid pool for shares
+   */
+  async stakeLiquidRpc(
+    params: Omit<StakeLiquidParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.stakeLiquid(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to burn liquid pool shares for a redemption ticket
+   */
+  async requestRedeem(
+    params: Omit<RequestRedeemParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return requestRedeemInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to burn liquid pool shares for a redemption ticket
+   */
+  async requestRedeemRpc(
+    params: Omit<RequestRedeemParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.requestRedeem(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to withdraw an unbonded redemption ticket
+   */
+  async redeem(
+    params: Omit<RedeemParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return redeemInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to withdraw an unbonded redemption ticket
+   */
+  async redeemRpc(
+    params: Omit<RedeemParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.redeem(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to start the unstake cooldown of an unlocked position
+   */
+  async requestUnstake(
+    params: Omit<RequestUnstakeParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return requestUnstakeInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to start the unstake cooldown of an unlocked position
+   */
+  async requestUnstakeRpc(
+    params: Omit<RequestUnstakeParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.requestUnstake(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to split part of a token position into a new position
+   */
+  async splitPosition(
+    params: Omit<SplitPositionParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return splitPositionInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to split part of a token position into a new position
+   */
+  async splitPositionRpc(
+    params: Omit<SplitPositionParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.splitPosition(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to merge two token positions in the same pool
+   */
+  async mergePositions(
+    params: Omit<MergePositionsParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return mergePositionsInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to merge two token positions in the same pool
+   */
+  async mergePositionsRpc(
+    params: Omit<MergePositionsParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.mergePositions(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to approve or revoke the owner's claim delegate
+   */
+  async setDelegate(
+    params: Omit<SetDelegateParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return setDelegateInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to approve or revoke the owner's claim delegate
+   */
+  async setDelegateRpc(
+    params: Omit<SetDelegateParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.setDelegate(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to settle a matured token position to its owner
+   */
+  async crankClaim(
+    params: Omit<CrankClaimParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return crankClaimInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to settle a matured token position to its owner
+   */
+  async crankClaimRpc(
+    params: Omit<CrankClaimParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.crankClaim(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to set the tip paid to crankers
+   */
+  async adminSetCrankTip(
+    params: Omit<AdminSetCrankTipParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return adminSetCrankTipInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to set the tip paid to crankers
+   */
+  async adminSetCrankTipRpc(
+    params: Omit<AdminSetCrankTipParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.adminSetCrankTip(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to cap the number of open positions per user
+   */
+  async adminSetPositionLimit(
+    params: Omit<AdminSetPositionLimitParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return adminSetPositionLimitInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to cap the number of open positions per user
+   */
+  async adminSetPositionLimitRpc(
+    params: Omit<AdminSetPositionLimitParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.adminSetPositionLimit(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to re-key a legacy position under the owner, pool and id seeds
+   */
+  async migratePositionSeeds(
+    params: Omit<MigratePositionSeedsParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return migratePositionSeedsInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to re-key a legacy position under the owner, pool and id seeds
+   */
+  async migratePositionSeedsRpc(
+    params: Omit<MigratePositionSeedsParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.migratePositionSeeds(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to bring the config up to the current layout version
+   */
+  async migrateConfig(
+    params: Omit<MigrateConfigParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return migrateConfigInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to bring the config up to the current layout version
+   */
+  async migrateConfigRpc(
+    params: Omit<MigrateConfigParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.migrateConfig(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to bring a pool up to the current layout version
+   */
+  async migratePool(
+    params: Omit<MigratePoolParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return migratePoolInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to bring a pool up to the current layout version
+   */
+  async migratePoolRpc(
+    params: Omit<MigratePoolParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.migratePool(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to bring a position up to the current layout version
+   */
+  async migratePosition(
+    params: Omit<MigratePositionParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return migratePositionInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to bring a position up to the current layout version
+   */
+  async migratePositionRpc(
+    params: Omit<MigratePositionParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.migratePosition(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to bring a user account up to the current layout version
+   */
+  async migrateUserAccount(
+    params: Omit<MigrateUserAccountParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return migrateUserAccountInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to bring a user account up to the current layout version
+   */
+  async migrateUserAccountRpc(
+    params: Omit<MigrateUserAccountParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.migrateUserAccount(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to enter or leave emergency mode
+   */
+  async adminSetEmergency(
+    params: Omit<AdminSetEmergencyParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return adminSetEmergencyInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to enter or leave emergency mode
+   */
+  async adminSetEmergencyRpc(
+    params: Omit<AdminSetEmergencyParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.adminSetEmergency(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to register a collection as stakeable
+   */
+  async adminAddCollection(
+    params: Omit<AdminAddCollectionParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return adminAddCollectionInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to register a collection as stakeable
+   */
+  async adminAddCollectionRpc(
+    params: Omit<AdminAddCollectionParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.adminAddCollection(params);
+    return this.sendInstruction(ix);
+  }
+
+  /**
+   * Creates an instruction to update a registered collection
+   */
+  async adminUpdateCollection(
+    params: Omit<AdminUpdateCollectionParams, "program" | "pda">
+  ): Promise<TransactionInstruction> {
+    return adminUpdateCollectionInstruction({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Creates an RPC call to update a registered collection
+   */
+  async adminUpdateCollectionRpc(
+    params: Omit<AdminUpdateCollectionParams, "program" | "pda">
+  ): Promise<string> {
+    const ix = await this.adminUpdateCollection(params);
+    return this.sendInstruction(ix);
+  }
+
   /**
    * Fetches a config account for a given authority
    */
@@ -865,20 +1513,14 @@ export class BertStakingSDK {
   }
 
   /**
-   * Fetches a position account for a given owner and mint
+   * Fetches a position account for a given owner, pool and id
    * @param owner The owner public key
-   * @param id For token positions, the position ID
-   * @param mint The token mint public key
-   * @param asset For NFT positions, the asset public key
+   * @param pool The pool public key
+   * @param id ID the program assigned to the position
    * @returns The position account if found, null otherwise
    */
-  async fetchPosition(
-    owner: PublicKey,
-    id: number,
-    mint: PublicKey,
-    asset?: PublicKey
-  ) {
-    return fetchPositionRpc(owner, mint, id, asset || null, this.program);
+  async fetchPosition(owner: PublicKey, pool: PublicKey, id: number) {
+    return fetchPositionRpc(owner, pool, id, this.program);
   }
 
   /**
@@ -909,6 +1551,13 @@ export class BertStakingSDK {
     return fetchUserAccountByAddressRpc(userAccountAddress, this.program);
   }
 
+  /**
+   * Fetches the id the program will assign to a user's next position
+   */
+  async fetchNextPositionId(userAccountAddress: PublicKey) {
+    return fetchNextPositionIdRpc(userAccountAddress, this.program);
+  }
+
   /**
    * Fetches a pool by config and index
    */
@@ -923,6 +1572,45 @@ export class BertStakingSDK {
     return fetchPoolByAddressRpc(poolAddress, this.program);
   }
 
+  /**
+   * Simulates a projection of a position's pending and unlock yield
+   */
+  async viewPositionYield(
+    params: Omit<ViewPositionYieldParams, "program" | "pda">
+  ) {
+    return viewPositionYield({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Simulates a report of the room left under a pool's value cap
+   */
+  async viewPoolCapacity(
+    params: Omit<ViewPoolCapacityParams, "program" | "pda">
+  ) {
+    return viewPoolCapacity({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
+  /**
+   * Simulates a report of the room left for a user in a pool
+   */
+  async viewUserCapacity(
+    params: Omit<ViewUserCapacityParams, "program" | "pda">
+  ) {
+    return viewUserCapacity({
+      program: this.program,
+      pda: this.pda,
+      ...params,
+    });
+  }
+
   /**
    * Fetches all pools for a config
    */
@@ -1125,6 +1813,43 @@ export class BertStakingSDK {
     return "";
   }
 
+  /**
+   * Creates an instruction to set a pool's staker allowlist root
+   */
+  async adminSetMerkleRoot({
+    authority,
+    configId,
+    poolIndex,
+    merkleRoot,
+  }: {
+    authority: PublicKey;
+    configId?: number;
+    poolIndex: number;
+    merkleRoot: number[];
+  }): Promise<TransactionInstruction> {
+    return adminSetMerkleRootInstruction({
+      program: this.program,
+      pda: this.pda,
+      authority,
+      configId,
+      poolIndex,
+      merkleRoot,
+    });
+  }
+
+  /**
+   * Creates an RPC call to set a pool's staker allowlist root
+   */
+  async adminSetMerkleRootRpc(params: {
+    authority: PublicKey;
+    configId?: number;
+    poolIndex: number;
+    merkleRoot: number[];
+  }): Promise<string> {
+    const ix = await this.adminSetMerkleRoot(params);
+    return this.sendInstruction(ix);
+  }
+
   /**
    * Creates an instruction to withdraw tokens
    */
@@ -1202,6 +1927,24 @@ export class BertStakingSDK {
     return "";
   }
 
+  /**
+   * Sends a single instruction signed by the provider wallet
+   */
+  private async sendInstruction(ix: TransactionInstruction): Promise<string> {
+    const tx = new Transaction();
+
+    const latestBlockhash = await this.provider.connection.getLatestBlockhash();
+    tx.recentBlockhash = latestBlockhash.blockhash;
+
+    tx.add(ix);
+
+    if (this.provider.sendAndConfirm) {
+      return await this.provider.sendAndConfirm(tx);
+    }
+
+    return "";
+  }
+
   /**
    * Static method to create the SDK from a Connection and wallet
    */
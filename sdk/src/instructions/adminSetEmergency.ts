@@ -0,0 +1,36 @@
+import { Program, web3 } from "@coral-xyz/anchor";
+import { BertStakingSc } from "../idl";
+import { BertStakingPda } from "../pda";
+
+export type AdminSetEmergencyParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  enabled: boolean; // Enter or leave emergency mode
+  configId?: number;
+};
+
+/**
+ * Create an instruction to enter or leave emergency mode. While it is on,
+ * stakes are halted and positions can be withdrawn early without yield.
+ */
+export async function adminSetEmergencyInstruction({
+  program,
+  pda,
+  authority,
+  enabled,
+  configId = 0,
+}: AdminSetEmergencyParams): Promise<web3.TransactionInstruction> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+
+  const method = enabled
+    ? program.methods.adminEnableEmergency()
+    : program.methods.adminDisableEmergency();
+
+  return method
+    .accountsStrict({
+      authority,
+      config: configPda,
+    })
+    .instruction();
+}
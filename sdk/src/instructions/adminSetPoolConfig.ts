@@ -49,6 +49,15 @@ export async function adminSetPoolConfigInstruction({
     maxTokensCap,
     maxValueCap,
     yieldRate,
+    lockMode: poolConfigArgs.lockMode ?? { fixed: {} },
+    minLockDays: poolConfigArgs.minLockDays ?? 0,
+    maxLockDays: poolConfigArgs.maxLockDays ?? 0,
+    maxBoostBps: poolConfigArgs.maxBoostBps ?? 0,
+    nftBoostBpsPerNft: poolConfigArgs.nftBoostBpsPerNft ?? 0,
+    maxNftBoostBps: poolConfigArgs.maxNftBoostBps ?? 0,
+    unbondingPeriodDays: poolConfigArgs.unbondingPeriodDays ?? 0,
+    minStakeAmount: new BN(poolConfigArgs.minStakeAmount ?? 0),
+    maxStakePerPosition: new BN(poolConfigArgs.maxStakePerPosition ?? 0),
   };
 
   return program.methods
@@ -118,3 +127,32 @@ export async function adminActivatePoolInstruction({
     })
     .instruction();
 }
+
+/**
+ * Creates an instruction to set a pool's staker allowlist root
+ */
+export async function adminSetMerkleRootInstruction({
+  program,
+  pda,
+  authority,
+  configId = 0,
+  poolIndex,
+  merkleRoot,
+}: Omit<AdminSetPoolConfigParams, "poolConfigArgs"> & {
+  merkleRoot: number[]; // 32 bytes, all zeroes opens the pool
+}): Promise<TransactionInstruction> {
+  // Find Config PDA
+  const [configPda] = pda.findConfigPda(authority, configId);
+
+  // Find Pool PDA
+  const [poolPda] = pda.findPoolPda(configPda, poolIndex);
+
+  return program.methods
+    .adminSetMerkleRoot(merkleRoot)
+    .accountsStrict({
+      authority,
+      config: configPda,
+      pool: poolPda,
+    })
+    .instruction();
+}
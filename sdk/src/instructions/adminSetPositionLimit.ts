@@ -0,0 +1,32 @@
+import { Program, web3 } from "@coral-xyz/anchor";
+import { BertStakingSc } from "../idl";
+import { BertStakingPda } from "../pda";
+
+export type AdminSetPositionLimitParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  maxPositionsPerUser: number; // Zero removes the limit
+  configId?: number;
+};
+
+/**
+ * Create an instruction to cap the number of open positions per user
+ */
+export async function adminSetPositionLimitInstruction({
+  program,
+  pda,
+  authority,
+  maxPositionsPerUser,
+  configId = 0,
+}: AdminSetPositionLimitParams): Promise<web3.TransactionInstruction> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+
+  return program.methods
+    .adminSetPositionLimit(maxPositionsPerUser)
+    .accountsStrict({
+      authority,
+      config: configPda,
+    })
+    .instruction();
+}
@@ -13,8 +13,9 @@ export type ClaimNftPositionParams = {
   sdk: BertStakingSDK;
   authority: web3.PublicKey;
   owner: web3.PublicKey;
+  claimant?: web3.PublicKey; // Signer of the claim, defaults to the owner
   payer: web3.PublicKey;
-  positionPda?: web3.PublicKey;
+  positionPda?: web3.PublicKey; // Required for legacy positions, see findLegacyPositionPda
   asset: web3.PublicKey;
   tokenMint: web3.PublicKey;
   tokenAccount?: web3.PublicKey;
@@ -25,6 +26,9 @@ export type ClaimNftPositionParams = {
   configId?: number;
   positionId?: number;
   poolIndex: number; // Index of the pool to use for claiming
+  receipt?: web3.PublicKey; // Receipt asset, for receipt-backed positions
+  receiptCollection?: web3.PublicKey; // Required with `receipt`
+  emergency?: boolean; // Return the asset without yield in emergency mode
 };
 
 /**
@@ -35,6 +39,7 @@ export async function claimNftPositionInstruction({
   sdk,
   authority,
   owner,
+  claimant = owner,
   payer,
   positionPda,
   asset,
@@ -46,14 +51,16 @@ export async function claimNftPositionInstruction({
   configId = 0,
   positionId = 0,
   poolIndex,
+  receipt,
+  receiptCollection,
+  emergency = false,
 }: ClaimNftPositionParams): Promise<web3.TransactionInstruction> {
   // Get authority from config using the configId
   const [configPda] = sdk.pda.findConfigPda(authority, configId);
   const [userPda] = sdk.pda.findUserAccountPda(owner, configPda);
 
-  // The program enforces `has_one = collection` on the config account, so the
-  // collection passed to the instruction must match the one stored in config.
-  // If the caller didn't supply one, fetch it from the config.
+  // NFTs staked before collections were registered come from the config's
+  // own collection. If the caller didn't supply one, fetch it from the config.
   let collectionKey = collection;
   if (!collectionKey) {
     const configAccount = await program.account.config.fetchNullable(configPda);
@@ -65,23 +72,31 @@ export async function claimNftPositionInstruction({
     collectionKey = configAccount.collection;
   }
 
-  // Calculate position PDA if not provided
-  const positionAddress =
-    positionPda ||
-    sdk.pda.findNftPositionPda(owner, tokenMint, asset, positionId)[0];
-
   // Find Pool PDA with the pool index
   const [poolPda] = sdk.pda.findPoolPda(configPda, poolIndex);
 
+  // Calculate position PDA if not provided
+  const positionAddress =
+    positionPda || sdk.pda.findPositionPda(owner, poolPda, positionId)[0];
+
   // Find User Pool Stats PDA
   const [userPoolStatsPda] = sdk.pda.findUserPoolStatsPda(owner, poolPda);
 
-  // Derive user token account if not provided
+  // NFTs counted in their collection's registry entry are released from it
+  const position = await program.account.positionV4.fetch(positionAddress);
+  const collectionEntryPda = position.collectionCounted
+    ? sdk.pda.findCollectionEntryPda(configPda, collectionKey)[0]
+    : null;
+  const userCollectionStatsPda = collectionEntryPda
+    ? sdk.pda.findUserCollectionStatsPda(owner, collectionEntryPda)[0]
+    : null;
+
+  // Proceeds go to the claimant: the owner, or the holder of the receipt
   const userTokenAccount =
     tokenAccount ||
     getAssociatedTokenAddressSync(
       tokenMint,
-      owner,
+      claimant,
       true,
       TOKEN_PROGRAM_ID,
       ASSOCIATED_TOKEN_PROGRAM_ID
@@ -104,9 +119,13 @@ export async function claimNftPositionInstruction({
     tokenMint
   )[0];
 
-  return program.methods
-    .claimPositionNft()
+  const method = emergency
+    ? program.methods.emergencyWithdrawNft()
+    : program.methods.claimPositionNft();
+
+  return method
     .accountsStrict({
+      claimant,
       owner,
       payer,
       config: configPda,
@@ -115,12 +134,16 @@ export async function claimNftPositionInstruction({
       userPoolStats: userPoolStatsPda,
       position: positionAddress,
       collection: collectionKey,
+      collectionEntry: collectionEntryPda,
+      userCollectionStats: userCollectionStatsPda,
       updateAuthority,
       asset,
       mint: tokenMint,
       tokenAccount: userTokenAccount,
       vault: vaultTokenAccount,
       authorityVault: authorityVaultAccount,
+      receipt: receipt ?? null,
+      receiptCollection: receipt ? receiptCollection : null,
       coreProgram: CORE_PROGRAM_ID,
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -6,13 +6,15 @@ import {
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { BertStakingSDK } from "..";
+import { CORE_PROGRAM_ID } from "../utils";
 
 export type ClaimPositionParams = {
   program: Program<BertStakingSc>;
   sdk: BertStakingSDK;
   authority: web3.PublicKey;
   owner: web3.PublicKey;
-  positionPda?: web3.PublicKey;
+  claimant?: web3.PublicKey; // Signer of the claim, defaults to the owner
+  positionPda?: web3.PublicKey; // Required for legacy positions, see findLegacyPositionPda
   tokenMint: web3.PublicKey;
   tokenAccount?: web3.PublicKey;
   collection?: web3.PublicKey;
@@ -21,6 +23,10 @@ export type ClaimPositionParams = {
   configId?: number; // ID for the config account
   positionId?: number; // ID for the position account
   poolIndex: number; // Index of the pool to use for claiming
+  receipt?: web3.PublicKey; // Receipt asset, for receipt-backed positions
+  receiptCollection?: web3.PublicKey; // Required with `receipt`
+  amount?: number | BN; // Withdraw only part of an unlocked position
+  emergency?: boolean; // Withdraw the principal without yield in emergency mode
 };
 
 /**
@@ -31,6 +37,7 @@ export async function claimTokenPositionInstruction({
   sdk,
   authority,
   owner,
+  claimant = owner,
   positionPda,
   tokenMint,
   tokenAccount,
@@ -39,6 +46,10 @@ export async function claimTokenPositionInstruction({
   configId = 0,
   positionId = 0,
   poolIndex,
+  receipt,
+  receiptCollection,
+  amount,
+  emergency = false,
 }: ClaimPositionParams): Promise<web3.TransactionInstruction> {
   // Get authority from config using the configId
   const [configPda] = sdk.pda.findConfigPda(authority, configId);
@@ -58,22 +69,22 @@ export async function claimTokenPositionInstruction({
     collectionKey = configAccount.collection;
   }
 
-  // Calculate position PDA if not provided
-  const positionAddress =
-    positionPda || sdk.pda.findPositionPda(owner, tokenMint, positionId)[0];
-
   // Find Pool PDA with the pool index
   const [poolPda] = sdk.pda.findPoolPda(configPda, poolIndex);
 
+  // Calculate position PDA if not provided
+  const positionAddress =
+    positionPda || sdk.pda.findPositionPda(owner, poolPda, positionId)[0];
+
   // Find User Pool Stats PDA
   const [userPoolStatsPda] = sdk.pda.findUserPoolStatsPda(owner, poolPda);
 
-  // Derive user token account if not provided
+  // Proceeds go to the claimant: the owner, or the holder of the receipt
   const userTokenAccount =
     tokenAccount ||
     getAssociatedTokenAddressSync(
       tokenMint,
-      owner,
+      claimant,
       true,
       TOKEN_PROGRAM_ID,
       ASSOCIATED_TOKEN_PROGRAM_ID
@@ -96,9 +107,16 @@ export async function claimTokenPositionInstruction({
     tokenMint
   )[0];
 
-  return program.methods
-    .claimPositionToken()
+  // A partial withdrawal leaves the rest of the position staked
+  const method = emergency
+    ? program.methods.emergencyWithdrawToken()
+    : amount === undefined
+    ? program.methods.claimPositionToken()
+    : program.methods.claimPositionTokenPartial(new BN(amount));
+
+  return method
     .accountsStrict({
+      claimant,
       owner,
       config: configPda,
       pool: poolPda,
@@ -110,6 +128,9 @@ export async function claimTokenPositionInstruction({
       tokenAccount: userTokenAccount,
       vault: vaultTokenAccount,
       authorityVault: authorityVaultAccount,
+      receipt: receipt ?? null,
+      receiptCollection: receipt ? receiptCollection : null,
+      coreProgram: receipt ? CORE_PROGRAM_ID : null,
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: web3.SystemProgram.programId,
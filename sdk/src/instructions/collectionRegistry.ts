@@ -0,0 +1,73 @@
+import { Program, web3, BN } from "@coral-xyz/anchor";
+import { BertStakingSc } from "../idl";
+import { BertStakingPda } from "../pda";
+
+export type AdminAddCollectionParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  collection: web3.PublicKey; // Core collection to accept for NFT staking
+  nftValueInTokens: number | BN; // Value of each NFT from the collection
+  nftsLimitPerUser: number; // Maximum NFTs from the collection per user
+  configId?: number;
+};
+
+/**
+ * Create an instruction to register a collection as stakeable
+ */
+export async function adminAddCollectionInstruction({
+  program,
+  pda,
+  authority,
+  collection,
+  nftValueInTokens,
+  nftsLimitPerUser,
+  configId = 0,
+}: AdminAddCollectionParams): Promise<web3.TransactionInstruction> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+
+  return program.methods
+    .adminAddCollection(new BN(nftValueInTokens), nftsLimitPerUser)
+    .accountsStrict({
+      authority,
+      config: configPda,
+      collection,
+      collectionEntry: pda.findCollectionEntryPda(configPda, collection)[0],
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .instruction();
+}
+
+export type AdminUpdateCollectionParams = AdminAddCollectionParams & {
+  isActive: boolean; // Whether new stakes from the collection are accepted
+};
+
+/**
+ * Create an instruction to update a registered collection. Staked positions
+ * keep the value they were staked at.
+ */
+export async function adminUpdateCollectionInstruction({
+  program,
+  pda,
+  authority,
+  collection,
+  nftValueInTokens,
+  nftsLimitPerUser,
+  isActive,
+  configId = 0,
+}: AdminUpdateCollectionParams): Promise<web3.TransactionInstruction> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+
+  return program.methods
+    .adminUpdateCollection(
+      new BN(nftValueInTokens),
+      nftsLimitPerUser,
+      isActive
+    )
+    .accountsStrict({
+      authority,
+      config: configPda,
+      collectionEntry: pda.findCollectionEntryPda(configPda, collection)[0],
+    })
+    .instruction();
+}
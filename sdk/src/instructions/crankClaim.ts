@@ -0,0 +1,93 @@
+import { Program, web3, BN } from "@coral-xyz/anchor";
+import { BertStakingSc } from "../idl";
+
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { BertStakingPda } from "../pda";
+
+export type CrankClaimParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  cranker: web3.PublicKey; // Anyone, paid the config's crank tip
+  owner: web3.PublicKey; // Owner of the matured position
+  tokenMint: web3.PublicKey;
+  poolIndex: number;
+  configId?: number;
+  positionId?: number;
+  positionPda?: web3.PublicKey; // Required for legacy positions, see findLegacyPositionPda
+};
+
+/**
+ * Create an instruction to settle a matured token position to its owner
+ */
+export async function crankClaimInstruction({
+  program,
+  pda,
+  authority,
+  cranker,
+  owner,
+  tokenMint,
+  poolIndex,
+  configId = 0,
+  positionId = 0,
+  positionPda,
+}: CrankClaimParams): Promise<web3.TransactionInstruction> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+  const [poolPda] = pda.findPoolPda(configPda, poolIndex);
+
+  const positionAddress =
+    positionPda || pda.findPositionPda(owner, poolPda, positionId)[0];
+
+  return program.methods
+    .crankClaim()
+    .accountsStrict({
+      cranker,
+      owner,
+      config: configPda,
+      pool: poolPda,
+      userAccount: pda.findUserAccountPda(owner, configPda)[0],
+      userPoolStats: pda.findUserPoolStatsPda(owner, poolPda)[0],
+      position: positionAddress,
+      mint: tokenMint,
+      tokenAccount: getAssociatedTokenAddressSync(tokenMint, owner, true),
+      vault: getAssociatedTokenAddressSync(tokenMint, configPda, true),
+      authorityVault: pda.findAuthorityVaultPda(configPda, tokenMint)[0],
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .instruction();
+}
+
+export type AdminSetCrankTipParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  tipLamports: number | BN; // Capped at a share of a position's rent
+  configId?: number;
+};
+
+/**
+ * Create an instruction to set the tip paid to crankers
+ */
+export async function adminSetCrankTipInstruction({
+  program,
+  pda,
+  authority,
+  tipLamports,
+  configId = 0,
+}: AdminSetCrankTipParams): Promise<web3.TransactionInstruction> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+
+  return program.methods
+    .adminSetCrankTip(new BN(tipLamports))
+    .accountsStrict({
+      authority,
+      config: configPda,
+    })
+    .instruction();
+}
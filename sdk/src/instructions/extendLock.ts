@@ -0,0 +1,114 @@
+import { Program, web3 } from "@coral-xyz/anchor";
+import { BertStakingSc } from "../idl";
+
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { BertStakingPda } from "../pda";
+import { fetchNextPositionIdRpc } from "../accounts";
+
+export type ExtendLockParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  owner: web3.PublicKey;
+  signer?: web3.PublicKey; // The owner or their delegate, defaults to the owner
+  tokenMint: web3.PublicKey;
+  poolIndex: number; // Pool the position currently belongs to
+  targetPoolIndex?: number; // Pool to relock into (defaults to the current pool)
+  configId?: number; // ID for the config account
+  positionId?: number; // ID for the position account
+  positionPda?: web3.PublicKey; // Required for legacy positions, see findLegacyPositionPda
+  lockPeriodDays?: number; // Lock period chosen by the staker (flexible pools only)
+  tokenAccount?: web3.PublicKey;
+};
+
+/**
+ * Create an instruction to relock a position into a longer lock
+ */
+export async function extendLockInstruction({
+  program,
+  pda,
+  authority,
+  owner,
+  signer = owner,
+  tokenMint,
+  poolIndex,
+  targetPoolIndex,
+  configId = 0,
+  positionId = 0,
+  positionPda,
+  lockPeriodDays,
+  tokenAccount,
+}: ExtendLockParams): Promise<web3.TransactionInstruction> {
+  // Find Config PDA with the provided ID
+  const [configPda] = pda.findConfigPda(authority, configId);
+
+  // Find the current pool and the owner's stats in it
+  const [poolPda] = pda.findPoolPda(configPda, poolIndex);
+  const [userPoolStatsPda] = pda.findUserPoolStatsPda(owner, poolPda);
+
+  // Moving to another pool also needs the owner's stats in that pool
+  const movesPool =
+    targetPoolIndex !== undefined && targetPoolIndex !== poolIndex;
+  const targetPoolPda = movesPool
+    ? pda.findPoolPda(configPda, targetPoolIndex)[0]
+    : null;
+  const targetUserPoolStatsPda = targetPoolPda
+    ? pda.findUserPoolStatsPda(owner, targetPoolPda)[0]
+    : null;
+
+  // Find User Account PDA
+  const [userAccountPda] = pda.findUserAccountPda(owner, configPda);
+
+  // Find Position PDA if not provided
+  const positionAddress =
+    positionPda || pda.findPositionPda(owner, poolPda, positionId)[0];
+
+  // A moved position is re-keyed under the target pool with the owner's next id
+  const newPositionPda = targetPoolPda
+    ? pda.findPositionPda(
+        owner,
+        targetPoolPda,
+        await fetchNextPositionIdRpc(userAccountPda, program)
+      )[0]
+    : null;
+
+  // Derive the token account if not provided
+  const userTokenAccount =
+    tokenAccount ||
+    getAssociatedTokenAddressSync(
+      tokenMint,
+      owner,
+      true,
+      TOKEN_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+  // Yield accrued so far is paid out of the authority vault
+  const [authorityVaultPda] = pda.findAuthorityVaultPda(configPda, tokenMint);
+
+  return program.methods
+    .extendLock(lockPeriodDays ?? null)
+    .accountsStrict({
+      authority: signer,
+      owner,
+      config: configPda,
+      pool: poolPda,
+      targetPool: targetPoolPda,
+      userAccount: userAccountPda,
+      userPoolStats: userPoolStatsPda,
+      targetUserPoolStats: targetUserPoolStatsPda,
+      position: positionAddress,
+      newPosition: newPositionPda,
+      mint: tokenMint,
+      tokenAccount: userTokenAccount,
+      authorityVault: authorityVaultPda,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .instruction();
+}
@@ -8,3 +8,17 @@ export * from "./claimTokenPosition";
 export * from "./claimNftPosition";
 export * from "./adminSetPoolConfig";
 export * from "./adminWithdrawToken";
+export * from "./extendLock";
+export * from "./transferPosition";
+export * from "./initializeReceiptCollection";
+export * from "./liquidPool";
+export * from "./requestUnstake";
+export * from "./splitPosition";
+export * from "./setDelegate";
+export * from "./crankClaim";
+export * from "./adminSetPositionLimit";
+export * from "./adminSetEmergency";
+export * from "./migratePositionSeeds";
+export * from "./migrateAccounts";
+export * from "./collectionRegistry";
+export * from "./views";
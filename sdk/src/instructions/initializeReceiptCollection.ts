@@ -0,0 +1,41 @@
+import { Program, web3 } from "@coral-xyz/anchor";
+import { BertStakingSc } from "../idl";
+import { BertStakingPda } from "../pda";
+import { CORE_PROGRAM_ID } from "../utils";
+
+export type InitializeReceiptCollectionParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  configId?: number;
+  receiptCollection: web3.PublicKey; // New collection asset, must co-sign
+  name: string;
+  uri: string;
+};
+
+/**
+ * Create an instruction to create the collection that position receipts are minted into
+ */
+export async function initializeReceiptCollectionInstruction({
+  program,
+  pda,
+  authority,
+  configId = 0,
+  receiptCollection,
+  name,
+  uri,
+}: InitializeReceiptCollectionParams): Promise<web3.TransactionInstruction> {
+  // Find Config PDA with the provided ID
+  const [configPda] = pda.findConfigPda(authority, configId);
+
+  return program.methods
+    .initializeReceiptCollection(name, uri)
+    .accountsStrict({
+      authority,
+      config: configPda,
+      receiptCollection,
+      coreProgram: CORE_PROGRAM_ID,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .instruction();
+}
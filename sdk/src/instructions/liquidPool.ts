@@ -0,0 +1,215 @@
+import { Program, web3, BN } from "@coral-xyz/anchor";
+import { BertStakingSc } from "../idl";
+
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { BertStakingPda } from "../pda";
+
+export type InitializeLiquidPoolParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  tokenMint: web3.PublicKey;
+  poolIndex: number; // Index of the empty pool to turn into a liquid pool
+  unbondingPeriodDays: number;
+  configId?: number;
+};
+
+/**
+ * Create an instruction to turn an empty pool into a liquid pool
+ */
+export async function initializeLiquidPoolInstruction({
+  program,
+  pda,
+  authority,
+  tokenMint,
+  poolIndex,
+  unbondingPeriodDays,
+  configId = 0,
+}: InitializeLiquidPoolParams): Promise<web3.TransactionInstruction> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+  const [poolPda] = pda.findPoolPda(configPda, poolIndex);
+
+  return program.methods
+    .initializeLiquidPool(unbondingPeriodDays)
+    .accountsStrict({
+      authority,
+      config: configPda,
+      pool: poolPda,
+      liquidPool: pda.findLiquidPoolPda(poolPda)[0],
+      shareMint: pda.findShareMintPda(poolPda)[0],
+      reserve: pda.findLiquidReservePda(poolPda)[0],
+      mint: tokenMint,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .instruction();
+}
+
+export type StakeLiquidParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  owner: web3.PublicKey;
+  tokenMint: web3.PublicKey;
+  poolIndex: number;
+  amount: number | BN;
+  configId?: number;
+  tokenAccount?: web3.PublicKey;
+};
+
+/**
+ * Create an instruction to deposit tokens into a liquid pool for shares
+ */
+export async function stakeLiquidInstruction({
+  program,
+  pda,
+  authority,
+  owner,
+  tokenMint,
+  poolIndex,
+  amount,
+  configId = 0,
+  tokenAccount,
+}: StakeLiquidParams): Promise<web3.TransactionInstruction> {
+  const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+
+  const [configPda] = pda.findConfigPda(authority, configId);
+  const [poolPda] = pda.findPoolPda(configPda, poolIndex);
+  const [shareMintPda] = pda.findShareMintPda(poolPda);
+
+  const userTokenAccount =
+    tokenAccount ||
+    getAssociatedTokenAddressSync(tokenMint, owner, true, TOKEN_PROGRAM_ID);
+
+  return program.methods
+    .stakeLiquid(amountBN)
+    .accountsStrict({
+      owner,
+      config: configPda,
+      pool: poolPda,
+      liquidPool: pda.findLiquidPoolPda(poolPda)[0],
+      shareMint: shareMintPda,
+      mint: tokenMint,
+      tokenAccount: userTokenAccount,
+      shareAccount: getAssociatedTokenAddressSync(shareMintPda, owner, true),
+      vault: getAssociatedTokenAddressSync(tokenMint, configPda, true),
+      reserve: pda.findLiquidReservePda(poolPda)[0],
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .instruction();
+}
+
+export type RequestRedeemParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  owner: web3.PublicKey;
+  tokenMint: web3.PublicKey;
+  poolIndex: number;
+  shares: number | BN;
+  configId?: number;
+  ticketId?: number; // Fetched from the liquid pool if not provided
+};
+
+/**
+ * Create an instruction to burn liquid pool shares for a redemption ticket
+ */
+export async function requestRedeemInstruction({
+  program,
+  pda,
+  authority,
+  owner,
+  tokenMint,
+  poolIndex,
+  shares,
+  configId = 0,
+  ticketId,
+}: RequestRedeemParams): Promise<web3.TransactionInstruction> {
+  const sharesBN = typeof shares === "number" ? new BN(shares) : shares;
+
+  const [configPda] = pda.findConfigPda(authority, configId);
+  const [poolPda] = pda.findPoolPda(configPda, poolIndex);
+  const [liquidPoolPda] = pda.findLiquidPoolPda(poolPda);
+  const [shareMintPda] = pda.findShareMintPda(poolPda);
+
+  // The program numbers tickets from the liquid pool's counter
+  let id = ticketId;
+  if (id === undefined) {
+    const liquidPool = await program.account.liquidPool.fetch(liquidPoolPda);
+    id = liquidPool.nextTicketId.toNumber();
+  }
+
+  return program.methods
+    .requestRedeem(sharesBN)
+    .accountsStrict({
+      owner,
+      config: configPda,
+      pool: poolPda,
+      liquidPool: liquidPoolPda,
+      shareMint: shareMintPda,
+      shareAccount: getAssociatedTokenAddressSync(shareMintPda, owner, true),
+      ticket: pda.findRedemptionTicketPda(owner, poolPda, id)[0],
+      mint: tokenMint,
+      vault: getAssociatedTokenAddressSync(tokenMint, configPda, true),
+      reserve: pda.findLiquidReservePda(poolPda)[0],
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .instruction();
+}
+
+export type RedeemParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  owner: web3.PublicKey;
+  tokenMint: web3.PublicKey;
+  poolIndex: number;
+  ticketId: number;
+  configId?: number;
+  tokenAccount?: web3.PublicKey;
+};
+
+/**
+ * Create an instruction to withdraw an unbonded redemption ticket
+ */
+export async function redeemInstruction({
+  program,
+  pda,
+  authority,
+  owner,
+  tokenMint,
+  poolIndex,
+  ticketId,
+  configId = 0,
+  tokenAccount,
+}: RedeemParams): Promise<web3.TransactionInstruction> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+  const [poolPda] = pda.findPoolPda(configPda, poolIndex);
+
+  const userTokenAccount =
+    tokenAccount ||
+    getAssociatedTokenAddressSync(tokenMint, owner, true, TOKEN_PROGRAM_ID);
+
+  return program.methods
+    .redeem()
+    .accountsStrict({
+      owner,
+      config: configPda,
+      pool: poolPda,
+      liquidPool: pda.findLiquidPoolPda(poolPda)[0],
+      ticket: pda.findRedemptionTicketPda(owner, poolPda, ticketId)[0],
+      mint: tokenMint,
+      tokenAccount: userTokenAccount,
+      vault: getAssociatedTokenAddressSync(tokenMint, configPda, true),
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+}
@@ -0,0 +1,120 @@
+import { Program, web3 } from "@coral-xyz/anchor";
+import { BertStakingSc } from "../idl";
+import { BertStakingPda } from "../pda";
+
+type MigrateAccountParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  payer: web3.PublicKey; // Pays for any space the current layout adds
+  configId?: number;
+};
+
+export type MigrateConfigParams = MigrateAccountParams;
+
+/**
+ * Create an instruction to bring the config up to the current layout version
+ */
+export async function migrateConfigInstruction({
+  program,
+  pda,
+  authority,
+  payer,
+  configId = 0,
+}: MigrateConfigParams): Promise<web3.TransactionInstruction> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+
+  return program.methods
+    .migrateConfig()
+    .accountsStrict({
+      payer,
+      account: configPda,
+      config: null,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .instruction();
+}
+
+export type MigratePoolParams = MigrateAccountParams & {
+  poolIndex: number;
+};
+
+/**
+ * Create an instruction to bring a pool up to the current layout version.
+ * The config has to be migrated first.
+ */
+export async function migratePoolInstruction({
+  program,
+  pda,
+  authority,
+  payer,
+  poolIndex,
+  configId = 0,
+}: MigratePoolParams): Promise<web3.TransactionInstruction> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+
+  return program.methods
+    .migratePool()
+    .accountsStrict({
+      payer,
+      account: pda.findPoolPda(configPda, poolIndex)[0],
+      config: configPda,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .instruction();
+}
+
+export type MigratePositionParams = Omit<
+  MigrateAccountParams,
+  "authority" | "configId"
+> & {
+  positionPda: web3.PublicKey;
+};
+
+/**
+ * Create an instruction to bring a position up to the current layout version
+ */
+export async function migratePositionInstruction({
+  program,
+  payer,
+  positionPda,
+}: MigratePositionParams): Promise<web3.TransactionInstruction> {
+  return program.methods
+    .migratePosition()
+    .accountsStrict({
+      payer,
+      account: positionPda,
+      config: null,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .instruction();
+}
+
+export type MigrateUserAccountParams = MigrateAccountParams & {
+  owner: web3.PublicKey;
+};
+
+/**
+ * Create an instruction to bring a user account up to the current layout
+ * version
+ */
+export async function migrateUserAccountInstruction({
+  program,
+  pda,
+  authority,
+  payer,
+  owner,
+  configId = 0,
+}: MigrateUserAccountParams): Promise<web3.TransactionInstruction> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+
+  return program.methods
+    .migrateUserAccount()
+    .accountsStrict({
+      payer,
+      account: pda.findUserAccountPda(owner, configPda)[0],
+      config: null,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .instruction();
+}
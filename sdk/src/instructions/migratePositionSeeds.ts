@@ -0,0 +1,51 @@
+import { Program, web3 } from "@coral-xyz/anchor";
+import { BertStakingSc } from "../idl";
+import { BertStakingPda } from "../pda";
+import { fetchNextPositionIdRpc } from "../accounts";
+
+export type MigratePositionSeedsParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  owner: web3.PublicKey;
+  tokenMint: web3.PublicKey;
+  poolIndex: number; // Pool the position belongs to
+  positionId: number; // Legacy ID of the position
+  asset?: web3.PublicKey; // Staked asset, for NFT positions
+  configId?: number;
+};
+
+/**
+ * Create an instruction to re-key a legacy position under the owner, pool
+ * and id seeds. The position gets the owner's next id.
+ */
+export async function migratePositionSeedsInstruction({
+  program,
+  pda,
+  authority,
+  owner,
+  tokenMint,
+  poolIndex,
+  positionId,
+  asset,
+  configId = 0,
+}: MigratePositionSeedsParams): Promise<web3.TransactionInstruction> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+  const [poolPda] = pda.findPoolPda(configPda, poolIndex);
+  const [userAccountPda] = pda.findUserAccountPda(owner, configPda);
+
+  const newId = await fetchNextPositionIdRpc(userAccountPda, program);
+
+  return program.methods
+    .migratePositionSeeds()
+    .accountsStrict({
+      owner,
+      config: configPda,
+      userAccount: userAccountPda,
+      position: pda.findLegacyPositionPda(owner, tokenMint, positionId, asset)[0],
+      newPosition: pda.findPositionPda(owner, poolPda, newId)[0],
+      mint: tokenMint,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .instruction();
+}
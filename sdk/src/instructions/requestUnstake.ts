@@ -0,0 +1,45 @@
+import { Program, web3 } from "@coral-xyz/anchor";
+import { BertStakingSc } from "../idl";
+import { BertStakingPda } from "../pda";
+
+export type RequestUnstakeParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  claimant: web3.PublicKey; // The owner or their delegate, or the receipt holder
+  owner: web3.PublicKey; // Owner of the position
+  position: web3.PublicKey;
+  poolIndex: number;
+  configId?: number;
+  receipt?: web3.PublicKey; // Receipt asset, for receipt-backed positions
+};
+
+/**
+ * Create an instruction to start the unstake cooldown of an unlocked position
+ */
+export async function requestUnstakeInstruction({
+  program,
+  pda,
+  authority,
+  claimant,
+  owner,
+  position,
+  poolIndex,
+  configId = 0,
+  receipt,
+}: RequestUnstakeParams): Promise<web3.TransactionInstruction> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+  const [poolPda] = pda.findPoolPda(configPda, poolIndex);
+
+  return program.methods
+    .requestUnstake()
+    .accountsStrict({
+      claimant,
+      config: configPda,
+      pool: poolPda,
+      userAccount: pda.findUserAccountPda(owner, configPda)[0],
+      position,
+      receipt: receipt ?? null,
+    })
+    .instruction();
+}
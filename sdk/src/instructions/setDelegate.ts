@@ -0,0 +1,35 @@
+import { Program, web3 } from "@coral-xyz/anchor";
+import { BertStakingSc } from "../idl";
+import { BertStakingPda } from "../pda";
+
+export type SetDelegateParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  owner: web3.PublicKey;
+  delegate: web3.PublicKey | null; // Pass null to revoke the delegate
+  configId?: number;
+};
+
+/**
+ * Create an instruction to approve or revoke the owner's claim delegate
+ */
+export async function setDelegateInstruction({
+  program,
+  pda,
+  authority,
+  owner,
+  delegate,
+  configId = 0,
+}: SetDelegateParams): Promise<web3.TransactionInstruction> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+
+  return program.methods
+    .setDelegate(delegate)
+    .accountsStrict({
+      owner,
+      config: configPda,
+      userAccount: pda.findUserAccountPda(owner, configPda)[0],
+    })
+    .instruction();
+}
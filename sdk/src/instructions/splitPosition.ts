@@ -0,0 +1,93 @@
+import { Program, web3, BN } from "@coral-xyz/anchor";
+import { BertStakingSc } from "../idl";
+import { BertStakingPda } from "../pda";
+import { fetchNextPositionIdRpc } from "../accounts";
+
+export type SplitPositionParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  owner: web3.PublicKey;
+  poolIndex: number; // Pool the position belongs to
+  amount: number | BN; // Amount moved into the new position
+  configId?: number;
+  positionId?: number; // ID of the position being split
+  newPositionId?: number; // ID the program assigns, read from the user account if omitted
+};
+
+/**
+ * Create an instruction to split part of a token position into a new position
+ */
+export async function splitPositionInstruction({
+  program,
+  pda,
+  authority,
+  owner,
+  poolIndex,
+  amount,
+  configId = 0,
+  positionId = 0,
+  newPositionId,
+}: SplitPositionParams): Promise<web3.TransactionInstruction> {
+  const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+
+  const [configPda] = pda.findConfigPda(authority, configId);
+  const [poolPda] = pda.findPoolPda(configPda, poolIndex);
+  const [userAccountPda] = pda.findUserAccountPda(owner, configPda);
+  const newId =
+    newPositionId ?? (await fetchNextPositionIdRpc(userAccountPda, program));
+
+  return program.methods
+    .splitPosition(amountBN)
+    .accountsStrict({
+      owner,
+      config: configPda,
+      pool: poolPda,
+      userAccount: userAccountPda,
+      position: pda.findPositionPda(owner, poolPda, positionId)[0],
+      newPosition: pda.findPositionPda(owner, poolPda, newId)[0],
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .instruction();
+}
+
+export type MergePositionsParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  owner: web3.PublicKey;
+  poolIndex: number; // Pool both positions belong to
+  configId?: number;
+  positionId: number; // Position that absorbs the other one
+  otherPositionId: number; // Position merged in and closed
+};
+
+/**
+ * Create an instruction to merge two token positions in the same pool
+ */
+export async function mergePositionsInstruction({
+  program,
+  pda,
+  authority,
+  owner,
+  poolIndex,
+  configId = 0,
+  positionId,
+  otherPositionId,
+}: MergePositionsParams): Promise<web3.TransactionInstruction> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+  const [poolPda] = pda.findPoolPda(configPda, poolIndex);
+  const [userAccountPda] = pda.findUserAccountPda(owner, configPda);
+
+  return program.methods
+    .mergePositions()
+    .accountsStrict({
+      owner,
+      config: configPda,
+      pool: poolPda,
+      userAccount: userAccountPda,
+      position: pda.findPositionPda(owner, poolPda, positionId)[0],
+      otherPosition: pda.findPositionPda(owner, poolPda, otherPositionId)[0],
+    })
+    .instruction();
+}
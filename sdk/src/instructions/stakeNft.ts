@@ -1,4 +1,4 @@
-import { Program, web3, BN } from "@coral-xyz/anchor";
+import { Program, web3 } from "@coral-xyz/anchor";
 import { BertStakingSc } from "../idl";
 
 import {
@@ -6,6 +6,8 @@ import {
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { BertStakingPda } from "../pda";
+import { fetchNextPositionIdRpc } from "../accounts";
+import { AllowlistProof } from "../types";
 import { CORE_PROGRAM_ID } from "../utils";
 
 export type StakeNftParams = {
@@ -18,7 +20,11 @@ export type StakeNftParams = {
   asset: web3.PublicKey;
   poolIndex: number; // Index of the pool config to use (determines lock period and yield)
   configId?: number; // ID for the config account
-  positionId?: number; // ID for the position account
+  positionId?: number; // ID the program assigns, read from the user account if omitted
+  lockPeriodDays?: number; // Lock period chosen by the staker (flexible pools only)
+  receipt?: web3.PublicKey; // New receipt asset to mint, must co-sign
+  receiptCollection?: web3.PublicKey; // Required with `receipt`
+  allowlist?: AllowlistProof; // Required by allowlisted pools
   nftsVault?: web3.PublicKey; // Optional NFTs vault owner, will be derived if not provided
   coreProgram?: web3.PublicKey;
 };
@@ -36,7 +42,11 @@ export async function stakeNftInstruction({
   asset,
   poolIndex,
   configId = 0,
-  positionId = 0,
+  positionId,
+  lockPeriodDays,
+  receipt,
+  receiptCollection,
+  allowlist,
   nftsVault,
   coreProgram = CORE_PROGRAM_ID,
 }: StakeNftParams): Promise<web3.TransactionInstruction> {
@@ -52,14 +62,23 @@ export async function stakeNftInstruction({
   // Find User Pool Stats PDA
   const [userPoolStatsPda] = pda.findUserPoolStatsPda(owner, poolPda);
 
-  // Find Position PDA with the asset
-  const [positionPda] = pda.findNftPositionPda(owner, mint, asset, positionId);
+  // Find Position PDA with the id the program will assign
+  const id =
+    positionId ?? (await fetchNextPositionIdRpc(userAccountPda, program));
+  const [positionPda] = pda.findPositionPda(owner, poolPda, id);
+
+  // The asset's collection must be registered under the config
+  const [collectionEntryPda] = pda.findCollectionEntryPda(configPda, collection);
+  const [userCollectionStatsPda] = pda.findUserCollectionStatsPda(
+    owner,
+    collectionEntryPda
+  );
 
   // Get NFTs vault if not provided (use the vault PDA from config and mint)
   const nftsVaultPda = nftsVault || pda.findNftsVaultPda(configPda, mint)[0];
 
   return program.methods
-    .stakeNft(new BN(positionId))
+    .stakeNft(lockPeriodDays ?? null, allowlist ?? null)
     .accountsStrict({
       owner,
       config: configPda,
@@ -70,6 +89,10 @@ export async function stakeNftInstruction({
       asset,
       nftVaultOwner: nftsVaultPda,
       collection,
+      collectionEntry: collectionEntryPda,
+      userCollectionStats: userCollectionStatsPda,
+      receipt: receipt ?? null,
+      receiptCollection: receipt ? receiptCollection : null,
       coreProgram,
       mint,
       tokenProgram: TOKEN_PROGRAM_ID,
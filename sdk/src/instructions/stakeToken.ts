@@ -7,6 +7,9 @@ import {
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { BertStakingPda } from "../pda";
+import { fetchNextPositionIdRpc } from "../accounts";
+import { AllowlistProof } from "../types";
+import { CORE_PROGRAM_ID } from "../utils";
 
 export type StakeTokenParams = {
   program: Program<BertStakingSc>;
@@ -17,7 +20,11 @@ export type StakeTokenParams = {
   amount: number | BN;
   poolIndex: number; // Index of the pool config to use (determines lock period and yield)
   configId?: number; // ID for the config account
-  positionId?: number; // ID for the position account
+  positionId?: number; // ID the program assigns, read from the user account if omitted
+  lockPeriodDays?: number; // Lock period chosen by the staker (flexible pools only)
+  receipt?: web3.PublicKey; // New receipt asset to mint, must co-sign
+  receiptCollection?: web3.PublicKey; // Required with `receipt`
+  allowlist?: AllowlistProof; // Required by allowlisted pools
   tokenAccount?: web3.PublicKey;
   vault?: web3.PublicKey;
 };
@@ -34,7 +41,11 @@ export async function stakeTokenInstruction({
   amount,
   poolIndex,
   configId = 0,
-  positionId = 0,
+  positionId,
+  lockPeriodDays,
+  receipt,
+  receiptCollection,
+  allowlist,
   tokenAccount,
   vault,
 }: StakeTokenParams): Promise<web3.TransactionInstruction> {
@@ -53,8 +64,10 @@ export async function stakeTokenInstruction({
   // Find User Pool Stats PDA
   const [userPoolStatsPda] = pda.findUserPoolStatsPda(owner, poolPda);
 
-  // Find Position PDA with the positionId
-  const [positionPda] = pda.findPositionPda(owner, tokenMint, positionId);
+  // Find Position PDA with the id the program will assign
+  const id =
+    positionId ?? (await fetchNextPositionIdRpc(userAccountPda, program));
+  const [positionPda] = pda.findPositionPda(owner, poolPda, id);
 
   // Derive the token account if not provided
   const userTokenAccount =
@@ -72,7 +85,7 @@ export async function stakeTokenInstruction({
     vault || getAssociatedTokenAddressSync(tokenMint, configPda, true);
 
   return program.methods
-    .stakeToken(new BN(positionId), amountBN)
+    .stakeToken(amountBN, lockPeriodDays ?? null, allowlist ?? null)
     .accountsStrict({
       owner,
       config: configPda,
@@ -83,6 +96,9 @@ export async function stakeTokenInstruction({
       mint: tokenMint,
       tokenAccount: userTokenAccount,
       vault: vaultAta,
+      receipt: receipt ?? null,
+      receiptCollection: receipt ? receiptCollection : null,
+      coreProgram: receipt ? CORE_PROGRAM_ID : null,
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: web3.SystemProgram.programId,
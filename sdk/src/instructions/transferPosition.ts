@@ -0,0 +1,89 @@
+import { Program, web3 } from "@coral-xyz/anchor";
+import { BertStakingSc } from "../idl";
+import { BertStakingPda } from "../pda";
+import { fetchNextPositionIdRpc } from "../accounts";
+
+export type TransferPositionParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  owner: web3.PublicKey;
+  newOwner: web3.PublicKey; // Must co-sign the transaction
+  poolIndex: number; // Index of the pool the position belongs to
+  configId?: number; // ID for the config account
+  positionId?: number; // ID of the position being transferred
+  newPositionId?: number; // ID the program assigns, read from the new owner's user account if omitted
+  positionPda?: web3.PublicKey; // Required for legacy positions, see findLegacyPositionPda
+  collection?: web3.PublicKey; // Collection of the staked asset, for NFT positions
+};
+
+/**
+ * Create an instruction to move a position to another wallet
+ */
+export async function transferPositionInstruction({
+  program,
+  pda,
+  authority,
+  owner,
+  newOwner,
+  poolIndex,
+  configId = 0,
+  positionId = 0,
+  newPositionId,
+  positionPda,
+  collection,
+}: TransferPositionParams): Promise<web3.TransactionInstruction> {
+  // Find Config PDA with the provided ID
+  const [configPda] = pda.findConfigPda(authority, configId);
+
+  // Find Pool PDA with the pool index
+  const [poolPda] = pda.findPoolPda(configPda, poolIndex);
+
+  // Both wallets need a user account and stats in the pool
+  const [userAccountPda] = pda.findUserAccountPda(owner, configPda);
+  const [newUserAccountPda] = pda.findUserAccountPda(newOwner, configPda);
+  const [userPoolStatsPda] = pda.findUserPoolStatsPda(owner, poolPda);
+  const [newUserPoolStatsPda] = pda.findUserPoolStatsPda(newOwner, poolPda);
+
+  // The position is re-keyed under the new owner's next id
+  const newId =
+    newPositionId ??
+    (await fetchNextPositionIdRpc(newUserAccountPda, program));
+  const positionAddress =
+    positionPda || pda.findPositionPda(owner, poolPda, positionId)[0];
+  const [newPositionPda] = pda.findPositionPda(newOwner, poolPda, newId);
+
+  // NFTs counted in their collection's registry entry move to the new
+  // owner's count
+  const position = await program.account.positionV4.fetch(positionAddress);
+  const isNft = "nft" in position.positionType;
+  const collectionEntryPda =
+    isNft && position.collectionCounted && collection
+      ? pda.findCollectionEntryPda(configPda, collection)[0]
+      : null;
+
+  return program.methods
+    .transferPosition()
+    .accountsStrict({
+      owner,
+      newOwner,
+      config: configPda,
+      pool: poolPda,
+      userAccount: userAccountPda,
+      newUserAccount: newUserAccountPda,
+      userPoolStats: userPoolStatsPda,
+      newUserPoolStats: newUserPoolStatsPda,
+      position: positionAddress,
+      newPosition: newPositionPda,
+      asset: isNft ? position.asset : null,
+      collectionEntry: collectionEntryPda,
+      userCollectionStats: collectionEntryPda
+        ? pda.findUserCollectionStatsPda(owner, collectionEntryPda)[0]
+        : null,
+      newUserCollectionStats: collectionEntryPda
+        ? pda.findUserCollectionStatsPda(newOwner, collectionEntryPda)[0]
+        : null,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .instruction();
+}
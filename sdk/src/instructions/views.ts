@@ -0,0 +1,118 @@
+import { Program, web3 } from "@coral-xyz/anchor";
+import { BertStakingSc } from "../idl";
+
+import { BertStakingPda } from "../pda";
+import {
+  PoolCapacityView,
+  PositionYieldView,
+  UserCapacityView,
+} from "../types";
+
+export type ViewPositionYieldParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  owner: web3.PublicKey;
+  positionPda: web3.PublicKey;
+  poolIndex: number;
+  configId?: number;
+};
+
+/**
+ * Simulate a projection of a position's pending and unlock yield
+ */
+export async function viewPositionYield({
+  program,
+  pda,
+  authority,
+  owner,
+  positionPda,
+  poolIndex,
+  configId = 0,
+}: ViewPositionYieldParams): Promise<PositionYieldView> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+  const [poolPda] = pda.findPoolPda(configPda, poolIndex);
+
+  return program.methods
+    .viewPositionYield()
+    .accountsStrict({
+      config: configPda,
+      pool: poolPda,
+      userPoolStats: pda.findUserPoolStatsPda(owner, poolPda)[0],
+      position: positionPda,
+    })
+    .view();
+}
+
+export type ViewPoolCapacityParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  poolIndex: number;
+  configId?: number;
+};
+
+/**
+ * Simulate a report of the room left under a pool's value cap
+ */
+export async function viewPoolCapacity({
+  program,
+  pda,
+  authority,
+  poolIndex,
+  configId = 0,
+}: ViewPoolCapacityParams): Promise<PoolCapacityView> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+
+  return program.methods
+    .viewPoolCapacity()
+    .accountsStrict({
+      config: configPda,
+      pool: pda.findPoolPda(configPda, poolIndex)[0],
+    })
+    .view();
+}
+
+export type ViewUserCapacityParams = {
+  program: Program<BertStakingSc>;
+  pda: BertStakingPda;
+  authority: web3.PublicKey;
+  owner: web3.PublicKey;
+  poolIndex: number;
+  configId?: number;
+  collection?: web3.PublicKey; // Also report the per-collection NFT limit
+};
+
+/**
+ * Simulate a report of the room left for a user in a pool
+ */
+export async function viewUserCapacity({
+  program,
+  pda,
+  authority,
+  owner,
+  poolIndex,
+  configId = 0,
+  collection,
+}: ViewUserCapacityParams): Promise<UserCapacityView> {
+  const [configPda] = pda.findConfigPda(authority, configId);
+  const [poolPda] = pda.findPoolPda(configPda, poolIndex);
+  const collectionEntry = collection
+    ? pda.findCollectionEntryPda(configPda, collection)[0]
+    : null;
+
+  return program.methods
+    .viewUserCapacity()
+    .accountsStrict({
+      owner,
+      config: configPda,
+      pool: poolPda,
+      userAccount: pda.findUserAccountPda(owner, configPda)[0],
+      userPoolStats: pda.findUserPoolStatsPda(owner, poolPda)[0],
+      collectionEntry,
+      userCollectionStats: collectionEntry
+        ? pda.findUserCollectionStatsPda(owner, collectionEntry)[0]
+        : null,
+    })
+    .view();
+}
@@ -51,22 +51,22 @@ export class BertStakingPda {
   }
 
   /**
-   * Find the Position PDA for a given owner, mint, and ID
+   * Find the Position PDA for a given owner, pool, and ID
    * @param owner The owner public key
-   * @param mint The token mint public key
-   * @param id ID for the position (defaults to 0)
+   * @param pool The pool public key
+   * @param id ID the program assigned to the position
    * @returns The Position PDA and bump
    */
   findPositionPda(
     owner: PublicKey,
-    mint: PublicKey,
+    pool: PublicKey,
     id: number
   ): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
       [
         Buffer.from("position"),
         owner.toBuffer(),
-        mint.toBuffer(),
+        pool.toBuffer(),
         new BN(id).toArrayLike(Buffer, "le", 8),
       ],
       this.programId
@@ -74,20 +74,21 @@ export class BertStakingPda {
   }
 
   /**
-   * Find the NFT Position PDA for a given owner, mint, and ID
+   * Find the PDA of a position created before positions were keyed by pool.
+   * NFT positions were also keyed by their asset.
    */
-  findNftPositionPda(
+  findLegacyPositionPda(
     owner: PublicKey,
     mint: PublicKey,
-    asset: PublicKey,
-    id: number
+    id: number,
+    asset?: PublicKey
   ): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
       [
         Buffer.from("position"),
         owner.toBuffer(),
         mint.toBuffer(),
-        asset.toBuffer(),
+        ...(asset ? [asset.toBuffer()] : []),
         new BN(id).toArrayLike(Buffer, "le", 8),
       ],
       this.programId
@@ -126,4 +127,83 @@ export class BertStakingPda {
       this.programId
     );
   }
+
+  /**
+   * Find the Liquid Pool PDA backing a liquid pool
+   */
+  findLiquidPoolPda(pool: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("liquid_pool"), pool.toBuffer()],
+      this.programId
+    );
+  }
+
+  /**
+   * Find the share mint PDA of a liquid pool
+   */
+  findShareMintPda(pool: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("share_mint"), pool.toBuffer()],
+      this.programId
+    );
+  }
+
+  /**
+   * Find the yield reserve PDA of a liquid pool
+   */
+  findLiquidReservePda(pool: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("liquid_reserve"), pool.toBuffer()],
+      this.programId
+    );
+  }
+
+  /**
+   * Find the Redemption Ticket PDA for a given owner, pool, and ticket ID
+   */
+  findRedemptionTicketPda(
+    owner: PublicKey,
+    pool: PublicKey,
+    id: number
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("redemption"),
+        owner.toBuffer(),
+        pool.toBuffer(),
+        new BN(id).toArrayLike(Buffer, "le", 8),
+      ],
+      this.programId
+    );
+  }
+
+  /**
+   * Find the registry entry PDA of a collection under a config
+   */
+  findCollectionEntryPda(
+    config: PublicKey,
+    collection: PublicKey
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("collection"), config.toBuffer(), collection.toBuffer()],
+      this.programId
+    );
+  }
+
+  /**
+   * Find the PDA of a user's NFT count in a registered collection
+   */
+  findUserCollectionStatsPda(
+    user: PublicKey,
+    collectionEntry: PublicKey
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("user_collection_stats"),
+        user.toBuffer(),
+        collectionEntry.toBuffer(),
+      ],
+      this.programId
+    );
+  }
 }
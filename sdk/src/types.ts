@@ -16,6 +16,21 @@ export enum PositionType {
 export enum PositionStatus {
   Unclaimed = 0,
   Claimed = 1,
+  Unbonding = 2,
+}
+
+/**
+ * Lock mode of a pool: a fixed lock period or one chosen by the staker
+ */
+export type LockMode = { fixed: {} } | { flexible: {} };
+
+/**
+ * Proof that a staker is on a pool's allowlist. Leaves are
+ * keccak256(owner || cap as u64 LE), and pairs are hashed in sorted order.
+ */
+export interface AllowlistProof {
+  cap: BN; // Per-user cap on staked value in the pool, zero for none
+  proof: number[][]; // Sibling hashes from the leaf up to the root
 }
 
 /**
@@ -27,6 +42,15 @@ export interface PoolConfigArgs {
   maxNftsCap: number;
   maxTokensCap: number | BN;
   maxValueCap: number | BN;
+  lockMode?: LockMode; // Defaults to a fixed lock period
+  minLockDays?: number; // Only used by flexible pools
+  maxLockDays?: number; // Only used by flexible pools
+  maxBoostBps?: number; // Only used by flexible pools (10_000 = 1x)
+  nftBoostBpsPerNft?: number; // Extra token yield per staked NFT, in bps
+  maxNftBoostBps?: number; // Cap on the NFT holding boost, in bps
+  unbondingPeriodDays?: number; // Unstake cooldown in days, zero to release at unlock
+  minStakeAmount?: number | BN; // Minimum amount per token position
+  maxStakePerPosition?: number | BN; // Maximum amount per token position, zero for none
 }
 
 /**
@@ -62,6 +86,10 @@ export type UserAccountIdl = IdlAccounts<BertStakingSc>["userAccountV3"];
 export type PositionIdl = IdlAccounts<BertStakingSc>["positionV4"];
 export type UserPoolStatsIdl = IdlTypes<BertStakingSc>["userPoolStatsAccount"];
 export type PoolIdl = IdlTypes<BertStakingSc>["pool"];
+export type LiquidPoolIdl = IdlAccounts<BertStakingSc>["liquidPool"];
+export type PositionYieldView = IdlTypes<BertStakingSc>["positionYieldView"];
+export type PoolCapacityView = IdlTypes<BertStakingSc>["poolCapacityView"];
+export type UserCapacityView = IdlTypes<BertStakingSc>["userCapacityView"];
 
 /**
  * Lock period yield mapping structure
@@ -72,8 +72,8 @@ describe("bert-staking-sc", () => {
 
   // Global IDs for configs and positions
   const configId = 1; // Using 1 instead of 0 to test non-default ID
-  const positionId = 42; // Using arbitrary ID for positions
-  const nftPositionId = positionId + 100;
+  const positionId = 0; // Position ids are assigned on-chain from zero
+  const nftPositionId = positionId + 1;
 
   // Test parameters for initialization
   const maxCap = 1_000_000_000 * 10 ** 6; // 1 billion tokens
@@ -169,10 +169,20 @@ describe("bert-staking-sc", () => {
         tokenMint,
       });
 
+      // NFTs can only be staked from registered collections
+      const addCollectionIx = await sdk.adminAddCollection({
+        authority: authority.publicKey,
+        configId,
+        collection: toWeb3JsPublicKey(collectionSigner.publicKey),
+        nftValueInTokens,
+        nftsLimitPerUser,
+      });
+
       // Execute both instructions
       await createAndProcessTransaction(client, authority, [
         initializeIx,
         initializeAuthVaultIx,
+        addCollectionIx,
       ]);
       console.log("Program and authority vault initialized successfully");
 
@@ -416,7 +426,7 @@ describe("bert-staking-sc", () => {
     // Setup test parameters
     const stakeAmount = 500 * 10 ** decimals; // 500 tokens
     const mintAmount = 1_000 * 10 ** decimals; // 1,000 tokens previously minted to user
-    const positionId = 42; // Arbitrary position ID for test
+    const positionId = 0; // First id the program assigns to this user
     const poolIndex = 0; // Use the 1-day lock period with 3% yield
 
     // Get account addresses
@@ -425,12 +435,12 @@ describe("bert-staking-sc", () => {
       payer.publicKey,
       configPda
     );
+    const [poolPda] = sdk.pda.findPoolPda(configPda, poolIndex);
     const [positionPda] = sdk.pda.findPositionPda(
       payer.publicKey,
-      tokenMint,
+      poolPda,
       positionId
     );
-    const [poolPda] = sdk.pda.findPoolPda(configPda, poolIndex);
     const [userPoolStatsPda] = sdk.pda.findUserPoolStatsPda(
       payer.publicKey,
       poolPda
@@ -496,8 +506,8 @@ describe("bert-staking-sc", () => {
     // 1. Check the position was created and initialized correctly
     const position = await sdk.fetchPosition(
       payer.publicKey,
-      positionId,
-      tokenMint
+      poolPda,
+      positionId
     );
     expect(position).to.not.be.null;
     expect(position.owner.toString()).to.equal(payer.publicKey.toString());
@@ -601,7 +611,7 @@ describe("bert-staking-sc", () => {
 
   it("Claims tokens after lock period with correct yield calculation and state updates", async () => {
     // Use the same position ID that was created in the previous test
-    const positionId = 42;
+    const positionId = 0;
     const poolIndex = 0; // The 1-day lock period we used in the previous test
 
     // Get the account addresses
@@ -610,12 +620,12 @@ describe("bert-staking-sc", () => {
       payer.publicKey,
       configPda
     );
+    const [poolPda] = sdk.pda.findPoolPda(configPda, poolIndex);
     const [positionPda] = sdk.pda.findPositionPda(
       payer.publicKey,
-      tokenMint,
+      poolPda,
       positionId
     );
-    const [poolPda] = sdk.pda.findPoolPda(configPda, poolIndex);
     const [userPoolStatsPda] = sdk.pda.findUserPoolStatsPda(
       payer.publicKey,
       poolPda
@@ -637,8 +647,8 @@ describe("bert-staking-sc", () => {
     // First, let's fetch the current position to confirm it exists
     const position = await sdk.fetchPosition(
       payer.publicKey,
-      positionId,
-      tokenMint
+      poolPda,
+      positionId
     );
 
     console.log("\n---- Position Before Claiming ----");
@@ -803,8 +813,8 @@ describe("bert-staking-sc", () => {
     // 4. Check position status has been updated to claimed
     const positionAfter = await sdk.fetchPosition(
       payer.publicKey,
-      positionId,
-      tokenMint
+      poolPda,
+      positionId
     );
     expect(positionAfter.status).to.deep.equal({ claimed: {} });
 
@@ -878,7 +888,7 @@ describe("bert-staking-sc", () => {
     const assetSigner = assets[0];
 
     const poolIndex = 2;
-    const nftPositionId = 200; // Use a specific position ID for NFT positions
+    const nftPositionId = 1; // Next id after the token position
 
     // Get the Config PDA and account data with our configId
     const [configPda] = sdk.pda.findConfigPda(payer.publicKey, configId);
@@ -918,12 +928,11 @@ describe("bert-staking-sc", () => {
       userAccountPda
     );
 
-    // Find the Position PDA for this owner, mint, asset and position ID
+    // Find the Position PDA for this owner, pool and position ID
     const asset = toWeb3JsPublicKey(assetSigner.publicKey);
-    const [positionPda] = sdk.pda.findNftPositionPda(
+    const [positionPda] = sdk.pda.findPositionPda(
       payer.publicKey,
-      tokenMint,
-      asset,
+      poolPda,
       nftPositionId
     );
     console.log("Position PDA:", positionPda.toString());
@@ -954,9 +963,8 @@ describe("bert-staking-sc", () => {
     // Verify the position was created and updated correctly
     const position = await sdk.fetchPosition(
       payer.publicKey,
-      nftPositionId,
-      tokenMint,
-      asset
+      poolPda,
+      nftPositionId
     );
 
     expect(position).to.not.be.null;
@@ -1077,13 +1085,12 @@ describe("bert-staking-sc", () => {
     // Get the asset public key from the previous test
     const asset = toWeb3JsPublicKey(assetSigner.publicKey);
 
-    const nftPositionId = 200; // Use the same position ID from previous test
+    const nftPositionId = 1; // Use the same position ID from previous test
 
-    // Find the Position PDA for this owner, mint, asset and position ID
-    const [positionPda] = sdk.pda.findNftPositionPda(
+    // Find the Position PDA for this owner, pool and position ID
+    const [positionPda] = sdk.pda.findPositionPda(
       payer.publicKey,
-      tokenMint,
-      asset,
+      poolPda,
       nftPositionId
     );
     console.log("Position PDA:", positionPda.toString());
@@ -1091,9 +1098,8 @@ describe("bert-staking-sc", () => {
     // Get the position account to check if it's locked
     const position = await sdk.fetchPosition(
       payer.publicKey,
-      nftPositionId,
-      tokenMint,
-      asset
+      poolPda,
+      nftPositionId
     );
 
     console.log("Position before claiming:");
@@ -1238,9 +1244,8 @@ describe("bert-staking-sc", () => {
     // Verify the position was updated correctly
     const positionAfter = await sdk.fetchPosition(
       payer.publicKey,
-      nftPositionId,
-      tokenMint,
-      asset
+      poolPda,
+      nftPositionId
     );
 
     console.log("Position after claiming:");
@@ -1395,10 +1400,20 @@ describe("bert-staking-sc", () => {
         tokenMint,
       });
 
+      // NFTs can only be staked from registered collections
+      const addCollectionIx = await sdk.adminAddCollection({
+        authority: payer.publicKey,
+        configId: testConfigId,
+        collection: toWeb3JsPublicKey(collectionSigner.publicKey),
+        nftValueInTokens,
+        nftsLimitPerUser,
+      });
+
       // Execute initialization instructions
       await createAndProcessTransaction(client, payer, [
         initializeIx,
         initializeAuthVaultIx,
+        addCollectionIx,
       ]);
       console.log("Base config initialized successfully");
 
@@ -1491,7 +1506,6 @@ describe("bert-staking-sc", () => {
         owner: payer.publicKey,
         tokenMint,
         configId: testConfigId,
-        positionId: 1000, // Use a specific position ID
         amount: firstStakeAmount,
         poolIndex,
         tokenAccount: userTokenAccount,
@@ -1529,7 +1543,6 @@ describe("bert-staking-sc", () => {
         owner: payer.publicKey,
         tokenMint,
         configId: testConfigId,
-        positionId: 1001, // Different position ID
         amount: exceedingAmount,
         poolIndex,
         tokenAccount: userTokenAccount,
@@ -1560,7 +1573,6 @@ describe("bert-staking-sc", () => {
         owner: payer.publicKey,
         tokenMint,
         configId: testConfigId,
-        positionId: 1002, // Different position ID
         amount: stakeAmount,
         poolIndex: otherPoolIndex,
         tokenAccount: userTokenAccount,
@@ -1651,10 +1663,20 @@ describe("bert-staking-sc", () => {
         tokenMint,
       });
 
+      // NFTs can only be staked from registered collections
+      const addCollectionIx = await sdk.adminAddCollection({
+        authority: payer.publicKey,
+        configId: testConfigId,
+        collection: toWeb3JsPublicKey(collectionSigner.publicKey),
+        nftValueInTokens,
+        nftsLimitPerUser,
+      });
+
       // Execute initialization instructions
       await createAndProcessTransaction(client, payer, [
         initializeIx,
         initializeAuthVaultIx,
+        addCollectionIx,
       ]);
       console.log("Base config initialized successfully");
 
@@ -1745,7 +1767,6 @@ describe("bert-staking-sc", () => {
         asset: toWeb3JsPublicKey(asset.publicKey),
         configId: testConfigId,
         poolIndex,
-        positionId: 2000 + i, // Use specific position IDs
         nftsVault: nftsVaultPda,
       });
 
@@ -1782,7 +1803,6 @@ describe("bert-staking-sc", () => {
         asset: toWeb3JsPublicKey(extraAsset.publicKey),
         configId: testConfigId,
         poolIndex,
-        positionId: 2000 + smallNftLimit, // Next position ID
         nftsVault: nftsVaultPda,
       });
 
@@ -1814,7 +1834,6 @@ describe("bert-staking-sc", () => {
         asset: toWeb3JsPublicKey(extraAsset.publicKey),
         configId: testConfigId,
         poolIndex: otherPoolIndex,
-        positionId: 3000, // Different position ID for other pool
         nftsVault: nftsVaultPda,
       });
 
@@ -1903,10 +1922,20 @@ describe("bert-staking-sc", () => {
         tokenMint,
       });
 
+      // NFTs can only be staked from registered collections
+      const addCollectionIx = await sdk.adminAddCollection({
+        authority: authority.publicKey,
+        configId: testConfigId,
+        collection: toWeb3JsPublicKey(collectionSigner.publicKey),
+        nftValueInTokens,
+        nftsLimitPerUser,
+      });
+
       // Execute both instructions
       await createAndProcessTransaction(client, authority, [
         initializeIx,
         initializeAuthVaultIx,
+        addCollectionIx,
       ]);
       console.log("Program and authority vault initialized successfully");
 
@@ -1966,7 +1995,6 @@ describe("bert-staking-sc", () => {
         owner: payer.publicKey,
         tokenMint,
         configId: testConfigId,
-        positionId: 5000, // Unique position ID
         amount: firstStakeAmount,
         poolIndex,
         tokenAccount: userTokenAccount,
@@ -1999,7 +2027,6 @@ describe("bert-staking-sc", () => {
         owner: payer.publicKey,
         tokenMint,
         configId: testConfigId,
-        positionId: 5001, // Different position ID
         amount: secondStakeAmount,
         poolIndex,
         tokenAccount: userTokenAccount,
@@ -2085,7 +2112,6 @@ describe("bert-staking-sc", () => {
         owner: payer.publicKey,
         tokenMint,
         configId: testConfigId,
-        positionId: 5002, // Different position ID
         amount: secondStakeAmount,
         poolIndex,
         tokenAccount: userTokenAccount,
@@ -2163,10 +2189,20 @@ describe("bert-staking-sc", () => {
         tokenMint,
       });
 
+      // NFTs can only be staked from registered collections
+      const addCollectionIx = await sdk.adminAddCollection({
+        authority: payer.publicKey,
+        configId: testConfigId,
+        collection: toWeb3JsPublicKey(collectionSigner.publicKey),
+        nftValueInTokens,
+        nftsLimitPerUser: globalNftLimit,
+      });
+
       // Execute both instructions
       await createAndProcessTransaction(client, payer, [
         initializeIx,
         initializeAuthVaultIx,
+        addCollectionIx,
       ]);
       console.log("Base config initialized successfully");
 
@@ -2257,7 +2293,6 @@ describe("bert-staking-sc", () => {
         asset: firstAsset,
         configId: testConfigId,
         poolIndex: 0,
-        positionId: 4000,
         nftsVault: nftsVaultPda,
       });
 
@@ -2272,9 +2307,8 @@ describe("bert-staking-sc", () => {
       // Verify position was created
       const position1 = await sdk.fetchPosition(
         payer.publicKey,
-        4000,
-        tokenMint,
-        firstAsset
+        sdk.pda.findPoolPda(testConfigPda, 0)[0],
+        0
       );
       expect(position1.positionType).to.deep.equal({ nft: {} });
       expect(position1.asset.toString()).to.equal(firstAsset.toString());
@@ -2296,7 +2330,6 @@ describe("bert-staking-sc", () => {
         asset: secondAsset,
         configId: testConfigId,
         poolIndex: 1,
-        positionId: 4001,
         nftsVault: nftsVaultPda,
       });
 
@@ -2306,9 +2339,8 @@ describe("bert-staking-sc", () => {
       // Verify position was created
       const position2 = await sdk.fetchPosition(
         payer.publicKey,
-        4001,
-        tokenMint,
-        secondAsset
+        sdk.pda.findPoolPda(testConfigPda, 1)[0],
+        1
       );
       expect(position2.positionType).to.deep.equal({ nft: {} });
       expect(position2.asset.toString()).to.equal(secondAsset.toString());
@@ -2341,7 +2373,6 @@ describe("bert-staking-sc", () => {
         asset: thirdAsset,
         configId: testConfigId,
         poolIndex: 0, // Pool 0 has capacity (only 1 NFT staked), but global limit is reached
-        positionId: 4002,
         nftsVault: nftsVaultPda,
       });
 
@@ -2373,7 +2404,6 @@ describe("bert-staking-sc", () => {
         asset: fourthAsset,
         configId: testConfigId,
         poolIndex: 1, // Pool 1 has capacity (only 1 NFT staked), but global limit is reached
-        positionId: 4003,
         nftsVault: nftsVaultPda,
       });
 